@@ -0,0 +1,167 @@
+use core::ops::{Add, AddAssign, Neg, Sub, SubAssign};
+
+#[cfg(feature = "float")]
+use crate::{degrees_to_raw, raw_to_degrees, Degrees};
+
+/// An angle in the raw representation of inclination outputs, where 2<sup>14</sup> corresponds to 90°.
+///
+/// A full turn corresponds to 2<sup>16</sup>, so arithmetic wraps around at 0°/360° like the angle itself.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Angle(u16);
+
+impl Angle {
+  /// 0°
+  pub const ZERO: Self = Self(0);
+  /// 90°
+  pub const RIGHT: Self = Self(1 << 14);
+  /// 180°
+  pub const STRAIGHT: Self = Self(1 << 15);
+
+  /// Create an angle from a raw inclination value.
+  #[inline(always)]
+  pub const fn from_raw(raw: u16) -> Self {
+    Self(raw)
+  }
+
+  /// Get the raw value, i.e. the angle in the range [0°, 360°).
+  #[inline(always)]
+  pub const fn raw(self) -> u16 {
+    self.0
+  }
+
+  /// Get the raw value of the angle wrapped to the range [-180°, 180°).
+  #[inline(always)]
+  pub const fn wrap(self) -> i16 {
+    self.0 as i16
+  }
+
+  /// Get the absolute difference to `other` along the shorter direction, in the range [0°, 180°].
+  #[inline]
+  pub const fn abs_diff(self, other: Self) -> Self {
+    Self((self.0.wrapping_sub(other.0) as i16).unsigned_abs())
+  }
+
+  /// Create an angle from degrees, rounded to the nearest raw value.
+  #[cfg(feature = "float")]
+  #[inline]
+  pub const fn from_degrees(degrees: Degrees) -> Self {
+    Self(degrees_to_raw(degrees))
+  }
+
+  /// Get the angle in degrees in the range [0°, 360°).
+  #[cfg(feature = "float")]
+  #[inline]
+  pub const fn degrees(self) -> Degrees {
+    raw_to_degrees(self.0)
+  }
+
+  /// Get the angle in degrees wrapped to the range [-180°, 180°).
+  #[cfg(feature = "float")]
+  #[inline]
+  pub const fn wrapped_degrees(self) -> Degrees {
+    Degrees(self.wrap() as f32 / (1 << 14) as f32 * 90.0)
+  }
+
+  /// Get the angle in radians wrapped to the range [-π, π).
+  #[cfg(feature = "float")]
+  #[inline]
+  pub const fn radians(self) -> f32 {
+    self.wrap() as f32 / (1 << 15) as f32 * core::f32::consts::PI
+  }
+
+  /// Get the sine of the angle.
+  #[cfg(all(feature = "libm", feature = "float"))]
+  #[inline]
+  pub fn sin(self) -> f32 {
+    libm::sinf(self.radians())
+  }
+
+  /// Get the cosine of the angle.
+  #[cfg(all(feature = "libm", feature = "float"))]
+  #[inline]
+  pub fn cos(self) -> f32 {
+    libm::cosf(self.radians())
+  }
+}
+
+impl From<Angle> for u16 {
+  #[inline(always)]
+  fn from(angle: Angle) -> Self {
+    angle.0
+  }
+}
+
+impl Add for Angle {
+  type Output = Self;
+
+  #[inline]
+  fn add(self, rhs: Self) -> Self {
+    Self(self.0.wrapping_add(rhs.0))
+  }
+}
+
+impl AddAssign for Angle {
+  #[inline]
+  fn add_assign(&mut self, rhs: Self) {
+    *self = *self + rhs;
+  }
+}
+
+impl Sub for Angle {
+  type Output = Self;
+
+  #[inline]
+  fn sub(self, rhs: Self) -> Self {
+    Self(self.0.wrapping_sub(rhs.0))
+  }
+}
+
+impl SubAssign for Angle {
+  #[inline]
+  fn sub_assign(&mut self, rhs: Self) {
+    *self = *self - rhs;
+  }
+}
+
+impl Neg for Angle {
+  type Output = Self;
+
+  #[inline]
+  fn neg(self) -> Self {
+    Self(self.0.wrapping_neg())
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_wrapping() {
+    let a = Angle::from_raw(0xF000);
+    assert_eq!(a + Angle::RIGHT, Angle::from_raw(0x3000));
+    assert_eq!(-Angle::RIGHT, Angle::from_raw(0xC000));
+    assert_eq!(Angle::from_raw(0xC000).wrap(), -0x4000);
+    assert_eq!(a.abs_diff(Angle::from_raw(0x1000)), Angle::from_raw(0x2000));
+    assert_eq!(Angle::ZERO.abs_diff(Angle::STRAIGHT), Angle::STRAIGHT);
+  }
+
+  #[cfg(feature = "float")]
+  #[test]
+  fn test_degrees() {
+    let a = Angle::from_degrees(Degrees(-90.0));
+    assert_eq!(a.degrees(), Degrees(270.0));
+    assert_eq!(a.wrapped_degrees(), Degrees(-90.0));
+    assert_eq!(Angle::STRAIGHT.radians(), -core::f32::consts::PI);
+  }
+
+  #[cfg(all(feature = "libm", feature = "float"))]
+  #[test]
+  fn test_trig() {
+    let a = Angle::from_degrees(Degrees(30.0));
+    assert!((a.sin() - 0.5).abs() < 1e-4);
+    assert!((a.cos() - 0.866_025_4).abs() < 1e-4);
+    assert!(Angle::RIGHT.cos().abs() < 1e-6);
+  }
+}
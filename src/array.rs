@@ -0,0 +1,188 @@
+use embedded_hal::spi::SpiDevice;
+
+use crate::{Axis, Degrees, Error, Inclination, Normal, OffFrameRead, Scl3300, StartupConfig, MAX_OUTPUTS};
+
+/// An error returned by an [`Scl3300Array`].
+#[derive(Debug)]
+pub enum ArrayError<E> {
+  /// An error occurred on a single device.
+  Device {
+    /// The index of the device in the array.
+    index: usize,
+    /// The device error.
+    error: Error<E>,
+  },
+  /// The devices disagree by more than the allowed threshold.
+  Disagreement {
+    /// The axis with the largest disagreement.
+    axis: Axis,
+    /// The largest disagreement between any two devices.
+    degrees: Degrees,
+  },
+}
+
+/// An error returned by [`Scl3300Array::start_up`].
+///
+/// The SPI devices are returned, so starting up can be retried or the buses can be released.
+#[derive(Debug)]
+pub struct ArrayStartUpError<SPI, E, const N: usize> {
+  /// The index of the device which failed to start up. Devices after it were not started.
+  pub index: usize,
+  /// The device error.
+  pub error: Error<E>,
+  /// The SPI devices of all devices in the array.
+  pub spis: [SPI; N],
+}
+
+/// An array of redundant SCL3300 inclinometers, each on a separate SPI device.
+///
+/// Safety applications can use this to cross-check the inclination measured by multiple devices.
+#[derive(Debug)]
+pub struct Scl3300Array<SPI, const N: usize> {
+  devices: [Scl3300<SPI, Normal>; N],
+  max_disagreement: u16,
+}
+
+impl<SPI, E, const N: usize> Scl3300Array<SPI, N>
+where
+  SPI: SpiDevice<u8, Error = E>,
+{
+  /// Start all devices with the given [`StartupConfig`] or [`MeasurementMode`](crate::MeasurementMode).
+  ///
+  /// Inclination readings differing by more than `max_disagreement` on any axis are rejected
+  /// by [`read_inclination`](Scl3300Array::read_inclination).
+  ///
+  /// Devices are started in order, stopping at the first error.
+  pub fn start_up(
+    spis: [SPI; N],
+    config: impl Into<StartupConfig>,
+    max_disagreement: Degrees,
+  ) -> Result<Self, ArrayStartUpError<SPI, E, N>> {
+    let config = config.into();
+
+    let mut devices = spis.map(Scl3300::new);
+    for (index, scl) in devices.iter_mut().enumerate() {
+      if let Err(error) = scl.start_up_in_place(config) {
+        return Err(ArrayStartUpError { index, error, spis: devices.map(Scl3300::release) })
+      }
+    }
+    let devices = devices.map(|scl| scl.into_mode(Normal { mode: config.mode, angles_enabled: true }));
+
+    let max_disagreement = (max_disagreement.get().abs() * Inclination::FACTOR / 90.0) as u16;
+    Ok(Self { devices, max_disagreement })
+  }
+
+  /// Read a value from every device.
+  ///
+  /// Devices are read in order, stopping at the first error, which contains the index of the failed device.
+  pub fn read<V>(&mut self) -> Result<[V; N], ArrayError<E>>
+  where
+    V: OffFrameRead,
+  {
+    let mut index = 0;
+    let mut failed = None;
+
+    let values = self.devices.each_mut().map(|scl| {
+      if failed.is_none() {
+        match scl.read() {
+          Ok(value) => {
+            index += 1;
+            return value
+          },
+          Err(error) => failed = Some(ArrayError::Device { index, error }),
+        }
+      }
+
+      // Only completes the array, which is discarded after an error.
+      V::decode(&[0; MAX_OUTPUTS], scl.mode.mode)
+    });
+
+    match failed {
+      Some(error) => Err(error),
+      None => Ok(values),
+    }
+  }
+
+  /// Read the inclination from every device and cross-check them.
+  ///
+  /// Angles are compared wrap-aware using [`Inclination::delta`], so 359° and 1° differ by 2°.
+  pub fn read_inclination(&mut self) -> Result<[Inclination; N], ArrayError<E>> {
+    let inclinations = self.read::<Inclination>()?;
+
+    let mut max = (Axis::X, 0);
+    for (i, a) in inclinations.iter().enumerate() {
+      for b in &inclinations[(i + 1)..] {
+        let (axis, diff) = a.delta(b).max_abs_delta_raw();
+        if diff > max.1 {
+          max = (axis, diff);
+        }
+      }
+    }
+
+    let (axis, diff) = max;
+    if diff > self.max_disagreement {
+      return Err(ArrayError::Disagreement { axis, degrees: Degrees(diff as f32 / Inclination::FACTOR * 90.0) })
+    }
+
+    Ok(inclinations)
+  }
+
+  /// Get the devices in the array.
+  pub fn devices(&self) -> &[Scl3300<SPI, Normal>; N] {
+    &self.devices
+  }
+
+  /// Get mutable access to the devices in the array.
+  pub fn devices_mut(&mut self) -> &mut [Scl3300<SPI, Normal>; N] {
+    &mut self.devices
+  }
+
+  /// Release the contained SPI peripherals.
+  pub fn release(self) -> [SPI; N] {
+    self.devices.map(Scl3300::release)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  use crate::{sim::Scl3300Sim, MeasurementMode, Status};
+
+  #[test]
+  fn test_disagreement() {
+    let mut sims = [Scl3300Sim::new(), Scl3300Sim::new()];
+    sims[0].set_inclination(359.5, 0.0, 90.0);
+    sims[1].set_inclination(0.5, 3.0, 87.0);
+
+    let [a, b] = &mut sims;
+    let mut array = Scl3300Array::start_up([a, b], MeasurementMode::Inclination, Degrees(1.0)).unwrap();
+
+    match array.read_inclination() {
+      Err(ArrayError::Disagreement { axis: Axis::Y, degrees }) => assert_eq!(degrees.get().round(), 3.0),
+      res => panic!("unexpected result: {res:?}"),
+    }
+  }
+
+  #[test]
+  fn test_device_error() {
+    let mut sims = [Scl3300Sim::new(), Scl3300Sim::new()];
+    sims[1].set_component_id(0);
+
+    let [a, b] = &mut sims;
+    let err = Scl3300Array::start_up([a, b], MeasurementMode::Inclination, Degrees(1.0)).unwrap_err();
+    assert_eq!(err.index, 1);
+    assert!(matches!(err.error, Error::WrongDevice { found: 0 }));
+
+    let spis = err.spis;
+    spis[1].set_component_id(0xC1);
+    let mut array = Scl3300Array::start_up(spis, MeasurementMode::Inclination, Degrees(1.0)).unwrap();
+    assert!(array.read_inclination().is_ok());
+
+    array.devices_mut()[1].spi_mut().raise_status(Status::DIGI1);
+    assert!(matches!(
+      array.read::<Inclination>(),
+      Err(ArrayError::Device { index: 1, error: Error::ReturnStatus { .. } })
+    ));
+  }
+}
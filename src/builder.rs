@@ -0,0 +1,259 @@
+use embedded_hal::spi::SpiDevice;
+
+#[cfg(feature = "test-support")]
+use crate::FaultInjector;
+use crate::{
+  Angle, AxisMapping, Calibration, CheckDepth, DriverState, Error, MeasurementMode, Normal, Scl3300, StartupConfig,
+  Timings, Uninitialized,
+};
+#[cfg(feature = "observer")]
+use crate::{FrameObserver, ObserverRef};
+
+/// A builder for an [`Scl3300`], created using [`Scl3300::builder`].
+#[derive(Debug, Clone)]
+pub struct Scl3300Builder<SPI> {
+  spi: SPI,
+  config: StartupConfig,
+  state: DriverState,
+}
+
+impl<SPI> Scl3300Builder<SPI> {
+  pub(crate) const fn new(spi: SPI) -> Self {
+    Self { spi, config: StartupConfig::new(MeasurementMode::new()), state: DriverState::new() }
+  }
+
+  /// Set the [`MeasurementMode`] to start in.
+  pub fn mode(mut self, mode: MeasurementMode) -> Self {
+    self.config.mode = mode;
+    self
+  }
+
+  /// Set how many times start-up and reads are retried after a transient error.
+  ///
+  /// Start-up is retried after any error, reads are retried after [`Error::Crc`]
+  /// and [`Error::ProtocolDesync`]. By default, nothing is retried.
+  pub fn retries(mut self, retries: u8) -> Self {
+    self.state.retries = retries;
+    self
+  }
+
+  /// Check the `STATUS` register before every `interval`-th read.
+  ///
+  /// If any [`Status::CRITICAL`](crate::Status::CRITICAL) flags are set, the read returns
+  /// [`Error::CriticalStatus`]. Reading the status clears all other flags. By default,
+  /// or if `interval` is `0`, the status is not checked.
+  pub fn status_check_interval(mut self, interval: u8) -> Self {
+    self.state.status_check_interval = interval;
+    self
+  }
+
+  /// Set whether to defer the final frame of a read to the next read.
+  ///
+  /// Every read ends with a bank switch frame, which only returns the last output value.
+  /// When enabled, this frame is omitted for reads which only access bank 0, and the first frame of the
+  /// next identical read returns the value instead, saving one frame per read when sampling continuously.
+  /// Note that the last output value is then up to one read older than the other values. By default,
+  /// the flush is not deferred.
+  pub fn deferred_flush(mut self, deferred_flush: bool) -> Self {
+    self.state.deferred_flush = deferred_flush;
+    self
+  }
+
+  /// Set whether to read the `STATUS` register automatically when a response reports an error return status.
+  ///
+  /// The decoded status is attached to the returned [`Error::ReturnStatus`], which also clears the error.
+  /// If any [`Status::CRITICAL`](crate::Status::CRITICAL) flags are set, [`Error::CriticalStatus`] is
+  /// returned instead. By default, the status is not read.
+  pub fn read_status_on_error(mut self, read_status_on_error: bool) -> Self {
+    self.state.read_status_on_error = read_status_on_error;
+    self
+  }
+
+  /// Declare the frequency the SPI bus is configured with.
+  ///
+  /// Start-up fails with [`Error::BusConfig`] if the frequency is outside of
+  /// [`SPI_FREQUENCY_RANGE_HZ`](crate::SPI_FREQUENCY_RANGE_HZ). By default, the frequency is not checked.
+  ///
+  /// The frequency is used to estimate bus time, see [`Scl3300::estimated_read_time_ns`]. The delay between
+  /// frames is not shortened at lower frequencies, since it is the minimum time chip select stays high.
+  pub fn bus_frequency_hz(mut self, frequency: u32) -> Self {
+    self.state.bus_frequency_hz = Some(frequency);
+    self
+  }
+
+  /// Set the [`Timings`] used when communicating with the inclinometer.
+  ///
+  /// By default, the datasheet timings are used.
+  pub fn timings(mut self, timings: Timings) -> Self {
+    self.state.timings = timings;
+    self
+  }
+
+  /// Set the [`AxisMapping`] applied to [`Acceleration`](crate::Acceleration)
+  /// and [`Inclination`](crate::Inclination) values.
+  pub fn axis_mapping(mut self, axis_mapping: AxisMapping) -> Self {
+    self.state.calibration.axis_mapping = axis_mapping;
+    self
+  }
+
+  /// Set the raw offsets subtracted from [`Acceleration`](crate::Acceleration) values.
+  pub fn acceleration_offset(mut self, offset: [i16; 3]) -> Self {
+    self.state.calibration.acceleration_offset = offset;
+    self
+  }
+
+  /// Set the raw offsets subtracted from [`Inclination`](crate::Inclination) values.
+  pub fn inclination_offset(mut self, offset: [i16; 3]) -> Self {
+    self.state.calibration.inclination_offset = offset;
+    self
+  }
+
+  /// Set the inclination offsets subtracted from [`Inclination`](crate::Inclination) values as angles.
+  pub fn inclination_offset_angles(mut self, offset: [Angle; 3]) -> Self {
+    self.state.calibration = self.state.calibration.with_inclination_offset_angles(offset);
+    self
+  }
+
+  /// Set the whole [`Calibration`] at once.
+  pub fn calibration(mut self, calibration: Calibration) -> Self {
+    self.state.calibration = calibration;
+    self
+  }
+
+  /// Set whether to verify the `WHOAMI` register after start-up.
+  pub fn verify_whoami(mut self, verify_whoami: bool) -> Self {
+    self.config.verify_whoami = verify_whoami;
+    self
+  }
+
+  /// Set whether to check that every response echoes the previous request.
+  pub fn strict_validation(mut self, strict_validation: bool) -> Self {
+    self.config.strict_validation = strict_validation;
+    self
+  }
+
+  /// Set how thoroughly the inclinometer is checked during start-up.
+  pub fn check_depth(mut self, check_depth: CheckDepth) -> Self {
+    self.config.check_depth = check_depth;
+    self
+  }
+
+  /// Set how many times the return status is polled during start-up while it is still in progress.
+  ///
+  /// See [`StartupConfig::with_start_up_attempts`].
+  pub fn start_up_attempts(mut self, attempts: u8) -> Self {
+    self.config.start_up_attempts = Some(attempts);
+    self
+  }
+
+  /// Set an observer which is notified about every SPI frame.
+  #[cfg(feature = "observer")]
+  pub fn frame_observer(mut self, observer: &'static dyn FrameObserver) -> Self {
+    self.state.observer = Some(ObserverRef(observer));
+    self
+  }
+
+  /// Set a fault injector which corrupts or drops frames.
+  #[cfg(feature = "test-support")]
+  pub fn fault_injector(mut self, fault_injector: FaultInjector) -> Self {
+    self.state.fault_injector = Some(fault_injector);
+    self
+  }
+
+  /// Build the driver without starting the inclinometer.
+  ///
+  /// Options only used during start-up must be passed to [`start_up`](Scl3300::start_up) again.
+  pub fn build(self) -> Scl3300<SPI, Uninitialized> {
+    Scl3300 { spi: self.spi, mode: Uninitialized { _0: core::marker::PhantomData }, state: self.state }
+  }
+
+  /// Build the driver and start the inclinometer.
+  pub fn build_and_start<E>(self) -> Result<Scl3300<SPI, Normal>, Error<E>>
+  where
+    SPI: SpiDevice<u8, Error = E>,
+  {
+    let config = self.config;
+    self.build().start_up(config)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  use crate::{sim::Scl3300Sim, Acceleration, Status};
+  #[cfg(feature = "float")]
+  use crate::{Gs, SignedAxis};
+
+  #[cfg(feature = "float")]
+  #[test]
+  fn test_builder() {
+    let mut sim = Scl3300Sim::new();
+    sim.set_acceleration(0.5, 0.25, 1.0);
+
+    let mapping = AxisMapping::new(SignedAxis::PosY, SignedAxis::NegX, SignedAxis::PosZ).unwrap();
+    let mut scl = Scl3300::builder(&mut sim)
+      .mode(MeasurementMode::FullScale12)
+      .axis_mapping(mapping)
+      .acceleration_offset([0, 0, 600])
+      .build_and_start()
+      .unwrap();
+
+    let acc: Acceleration = scl.read().unwrap();
+    assert_eq!((acc.x_g(), acc.y_g(), acc.z_g()), (Gs(0.25), Gs(-0.5), Gs(0.9)));
+  }
+
+  #[test]
+  fn test_status_check_interval() {
+    let mut sim = Scl3300Sim::new();
+
+    let mut scl = Scl3300::builder(&mut sim).status_check_interval(2).build_and_start().unwrap();
+    assert!(scl.read::<Acceleration>().is_ok());
+
+    scl.spi_mut().raise_status(Status::CLK);
+    assert!(matches!(scl.read::<Acceleration>(), Err(Error::CriticalStatus { status }) if status == Status::CLK));
+    assert!(scl.read::<Acceleration>().is_ok());
+
+    // Non-critical flags are cleared by the check.
+    scl.spi_mut().raise_status(Status::MODE_CHANGE);
+    assert!(scl.read::<Acceleration>().is_ok());
+  }
+
+  #[test]
+  fn test_bus_frequency() {
+    let mut sim = Scl3300Sim::new();
+
+    let res = Scl3300::builder(&mut sim).bus_frequency_hz(8_000_000).build_and_start();
+    assert!(matches!(res, Err(Error::BusConfig { frequency_hz: 8_000_000 })));
+
+    assert!(Scl3300::builder(&mut sim).bus_frequency_hz(4_000_000).build_and_start().is_ok());
+  }
+
+  #[test]
+  fn test_timings() {
+    let mut sim = Scl3300Sim::new();
+
+    let timings = Timings::new().with_settling_ns(MeasurementMode::Inclination, 0);
+    let res = Scl3300::builder(&mut sim)
+      .mode(MeasurementMode::Inclination)
+      .timings(timings)
+      .start_up_attempts(0)
+      .build_and_start();
+    assert!(matches!(res, Err(Error::Startup)));
+
+    let timings = Timings { inter_frame_ns: 50_000, ..Timings::new() };
+    let res = Scl3300::builder(&mut sim).mode(MeasurementMode::Inclination).timings(timings).build_and_start();
+    assert!(res.is_ok());
+  }
+
+  #[test]
+  fn test_retries() {
+    let mut sim = Scl3300Sim::new();
+
+    let mut scl = Scl3300::builder(&mut sim).strict_validation(true).retries(1).build_and_start().unwrap();
+
+    // Simulate a transfer the driver does not know about.
+    embedded_hal::spi::SpiDevice::transfer_in_place(scl.spi_mut(), &mut [0x14, 0x00, 0x00, 0xEF]).unwrap();
+    assert!(scl.read::<Acceleration>().is_ok());
+  }
+}
@@ -0,0 +1,176 @@
+use core::fmt;
+
+use crate::{Angle, Axes, Axis};
+
+/// A device axis with a sign, used in an [`AxisMapping`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum SignedAxis {
+  /// Positive X-axis
+  PosX,
+  /// Negative X-axis
+  NegX,
+  /// Positive Y-axis
+  PosY,
+  /// Negative Y-axis
+  NegY,
+  /// Positive Z-axis
+  PosZ,
+  /// Negative Z-axis
+  NegZ,
+}
+
+impl SignedAxis {
+  /// Get the axis without the sign.
+  pub const fn axis(self) -> Axis {
+    match self {
+      Self::PosX | Self::NegX => Axis::X,
+      Self::PosY | Self::NegY => Axis::Y,
+      Self::PosZ | Self::NegZ => Axis::Z,
+    }
+  }
+
+  /// Check whether the axis is negated.
+  pub const fn is_negative(self) -> bool {
+    matches!(self, Self::NegX | Self::NegY | Self::NegZ)
+  }
+}
+
+/// A mapping from device axes to application axes, e.g. to account for the mounting orientation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(
+  feature = "serde",
+  derive(serde::Serialize, serde::Deserialize),
+  serde(try_from = "[SignedAxis; 3]", into = "[SignedAxis; 3]")
+)]
+pub struct AxisMapping {
+  axes: [SignedAxis; 3],
+}
+
+impl Default for AxisMapping {
+  fn default() -> Self {
+    Self::IDENTITY
+  }
+}
+
+impl AxisMapping {
+  /// The identity mapping.
+  pub const IDENTITY: Self = Self { axes: [SignedAxis::PosX, SignedAxis::PosY, SignedAxis::PosZ] };
+
+  /// Create a mapping where the application X, Y and Z-axes are the given device axes.
+  ///
+  /// Returns `None` if a device axis is used more than once.
+  pub const fn new(x: SignedAxis, y: SignedAxis, z: SignedAxis) -> Option<Self> {
+    let (a, b, c) = (x.axis() as u8, y.axis() as u8, z.axis() as u8);
+    if a == b || b == c || a == c {
+      return None
+    }
+
+    Some(Self { axes: [x, y, z] })
+  }
+
+  /// Get the device axes for the application X, Y and Z-axes.
+  pub const fn axes(&self) -> [SignedAxis; 3] {
+    self.axes
+  }
+
+  /// Apply the mapping to raw axis values.
+  pub fn apply(&self, raw: [u16; 3]) -> [u16; 3] {
+    self.axes.map(|axis| {
+      let value = raw[axis.axis() as usize] as i16;
+      (if axis.is_negative() { value.wrapping_neg() } else { value }) as u16
+    })
+  }
+}
+
+/// An axis mapping uses a device axis more than once.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct InvalidAxisMapping(pub [SignedAxis; 3]);
+
+impl fmt::Display for InvalidAxisMapping {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(f, "invalid axis mapping {:?}", self.0)
+  }
+}
+
+impl From<AxisMapping> for [SignedAxis; 3] {
+  #[inline]
+  fn from(mapping: AxisMapping) -> Self {
+    mapping.axes
+  }
+}
+
+impl TryFrom<[SignedAxis; 3]> for AxisMapping {
+  type Error = InvalidAxisMapping;
+
+  /// Create a mapping where the application X, Y and Z-axes are the given device axes.
+  #[inline]
+  fn try_from(axes @ [x, y, z]: [SignedAxis; 3]) -> Result<Self, Self::Error> {
+    Self::new(x, y, z).ok_or(InvalidAxisMapping(axes))
+  }
+}
+
+/// Calibration applied to measurements read by the driver.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Calibration {
+  /// The axis mapping, applied first.
+  pub axis_mapping: AxisMapping,
+  /// Raw acceleration offsets, subtracted after applying the axis mapping.
+  pub acceleration_offset: [i16; 3],
+  /// Raw inclination offsets, subtracted after applying the axis mapping.
+  pub inclination_offset: [i16; 3],
+}
+
+impl Calibration {
+  /// Create a calibration which does not change any values.
+  pub const fn new() -> Self {
+    Self { axis_mapping: AxisMapping::IDENTITY, acceleration_offset: [0; 3], inclination_offset: [0; 3] }
+  }
+
+  /// Set the inclination offsets from angles.
+  pub const fn with_inclination_offset_angles(mut self, [x, y, z]: [Angle; 3]) -> Self {
+    self.inclination_offset = [x.wrap(), y.wrap(), z.wrap()];
+    self
+  }
+
+  /// Get the inclination offsets as angles.
+  pub fn inclination_offset_angles(&self) -> [Angle; 3] {
+    self.inclination_offset.map(|offset| Angle::from_raw(offset as u16))
+  }
+
+  pub(crate) fn apply<T: Axes>(&self, value: &T, offset: [i16; 3]) -> T {
+    let raw = self.axis_mapping.apply(value.raw_axes());
+    value.with_raw_axes(core::array::from_fn(|i| (raw[i] as i16).wrapping_sub(offset[i]) as u16))
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_axis_mapping() {
+    let mapping = AxisMapping::new(SignedAxis::PosY, SignedAxis::NegX, SignedAxis::NegZ).unwrap();
+    assert_eq!(mapping.apply([1, 2, 3]), [2, (-1i16) as u16, (-3i16) as u16]);
+
+    assert_eq!(AxisMapping::new(SignedAxis::PosX, SignedAxis::NegX, SignedAxis::PosZ), None);
+  }
+
+  #[test]
+  fn test_axis_mapping_try_from() {
+    let axes = [SignedAxis::PosY, SignedAxis::NegX, SignedAxis::NegZ];
+    assert_eq!(AxisMapping::try_from(axes).map(<[SignedAxis; 3]>::from), Ok(axes));
+
+    let axes = [SignedAxis::PosX, SignedAxis::NegX, SignedAxis::PosZ];
+    assert_eq!(AxisMapping::try_from(axes), Err(InvalidAxisMapping(axes)));
+  }
+
+  #[test]
+  fn test_inclination_offset_angles() {
+    let angles = [Angle::RIGHT, -Angle::RIGHT, Angle::ZERO];
+    let calibration = Calibration::new().with_inclination_offset_angles(angles);
+    assert_eq!(calibration.inclination_offset, [0x4000, -0x4000, 0]);
+    assert_eq!(calibration.inclination_offset_angles(), angles);
+  }
+}
@@ -0,0 +1,176 @@
+use embedded_hal::spi::SpiDevice;
+
+use crate::{Error, FlagSummary, Normal, OffFrameRead, Scl3300, SelfTest, Status};
+
+/// The quality of a reading, derived from the self-test output read alongside it.
+///
+/// The self-test output (`STO`) leaves its threshold range when external vibration exceeds the measurement range,
+/// so readings taken at the same time may be saturated.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DataQuality {
+  /// The self-test output is within its thresholds.
+  Good,
+  /// The self-test output is outside of its thresholds, the reading may be saturated.
+  Degraded,
+}
+
+impl DataQuality {
+  /// Get the data quality indicated by the given self-test reading.
+  pub fn from_self_test(self_test: &SelfTest) -> Self {
+    if self_test.is_within_thresholds() {
+      Self::Good
+    } else {
+      Self::Degraded
+    }
+  }
+
+  /// Check whether the quality is [`Good`](DataQuality::Good).
+  pub fn is_good(&self) -> bool {
+    *self == Self::Good
+  }
+}
+
+/// The quality of a single reading, derived from the return status of its response frames, the self-test output
+/// and, if flags are set, the `STATUS` register.
+///
+/// Variants are ordered by increasing severity, so filters can e.g. reject samples worse than
+/// [`StartupTail`](Quality::StartupTail) or weight samples by quality.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Quality {
+  /// All responses reported normal operation and the self-test output is within its thresholds.
+  Good,
+  /// Responses reported that start-up is still in progress, e.g. the tail of the settling time after a mode change.
+  StartupTail,
+  /// The self-test output is outside of its thresholds or the `SAT` flag is set, the reading may be saturated.
+  SaturationSuspected,
+  /// Responses reported error flags in the `STATUS` register other than saturation.
+  StatusFlagged,
+}
+
+impl Quality {
+  /// Compute the quality from the flags of a lenient read, the self-test output read alongside it and
+  /// the `STATUS` register, if it was sampled.
+  ///
+  /// If the `STATUS` register was not sampled, any error return status is assumed to be caused by flags
+  /// other than saturation.
+  pub fn new(flags: &FlagSummary, self_test: &SelfTest, status: Option<Status>) -> Self {
+    let flagged = match status {
+      Some(status) => !status.difference(Status::SAT).is_empty(),
+      None => flags.has_errors(),
+    };
+    let saturated = !self_test.is_within_thresholds() || status.is_some_and(|status| status.contains(Status::SAT));
+
+    if flagged {
+      Self::StatusFlagged
+    } else if saturated {
+      Self::SaturationSuspected
+    } else if flags.in_startup() {
+      Self::StartupTail
+    } else {
+      Self::Good
+    }
+  }
+
+  /// Check whether the quality is [`Good`](Quality::Good).
+  pub fn is_good(&self) -> bool {
+    *self == Self::Good
+  }
+}
+
+impl From<DataQuality> for Quality {
+  fn from(quality: DataQuality) -> Self {
+    match quality {
+      DataQuality::Good => Self::Good,
+      DataQuality::Degraded => Self::SaturationSuspected,
+    }
+  }
+}
+
+impl<SPI, E> Scl3300<SPI, Normal>
+where
+  SPI: SpiDevice<u8, Error = E>,
+{
+  /// Read the given values together with the self-test output, and return the resulting [`Quality`].
+  ///
+  /// Thanks to the off-frame protocol, this only needs a single additional frame. Responses reporting
+  /// an error return status do not abort the read; instead, the `STATUS` register is sampled, which
+  /// also clears its flags.
+  pub fn read_with_quality<V>(&mut self) -> Result<(V, Quality), Error<E>>
+  where
+    V: OffFrameRead,
+    (V, SelfTest): OffFrameRead,
+  {
+    let ((value, self_test), flags): ((V, SelfTest), _) = self.read_lenient()?;
+
+    let status = if flags.has_errors() { Some(self.read_lenient::<Status>()?.0) } else { None };
+
+    Ok((value, Quality::new(&flags, &self_test, status)))
+  }
+}
+
+#[cfg(all(test, feature = "float"))]
+mod tests {
+  use super::*;
+
+  use crate::{sim::Scl3300Sim, Acceleration, CheckDepth, Gs, MeasurementMode, StartupConfig};
+
+  #[test]
+  fn test_read_with_quality() {
+    let mut sim = Scl3300Sim::new();
+    sim.set_acceleration(0.0, 0.0, 1.0);
+
+    let mut scl = Scl3300::new(&mut sim).start_up(MeasurementMode::FullScale12).unwrap();
+
+    let (acc, quality) = scl.read_with_quality::<Acceleration>().unwrap();
+    assert_eq!(acc.z_g(), Gs(1.0));
+    assert_eq!(quality, Quality::Good);
+
+    scl.spi_mut().set_self_test(2000);
+    let (_, quality) = scl.read_with_quality::<Acceleration>().unwrap();
+    assert_eq!(quality, Quality::SaturationSuspected);
+    assert_eq!(quality, DataQuality::Degraded.into());
+  }
+
+  #[test]
+  fn test_read_with_quality_flags() {
+    let mut sim = Scl3300Sim::new();
+
+    let mut scl = Scl3300::new(&mut sim).start_up(MeasurementMode::FullScale12).unwrap();
+
+    scl.spi_mut().raise_status(Status::SAT);
+    let (_, quality) = scl.read_with_quality::<Acceleration>().unwrap();
+    assert_eq!(quality, Quality::SaturationSuspected);
+
+    scl.spi_mut().raise_status(Status::DIGI1);
+    let (_, quality) = scl.read_with_quality::<Acceleration>().unwrap();
+    assert_eq!(quality, Quality::StatusFlagged);
+
+    let (_, quality) = scl.read_with_quality::<Acceleration>().unwrap();
+    assert_eq!(quality, Quality::Good);
+
+    scl.spi_mut().raise_status(Status::SAT | Status::DIGI1);
+    let (_, quality) = scl.read_with_quality::<Acceleration>().unwrap();
+    assert_eq!(quality, Quality::StatusFlagged);
+
+    scl.spi_mut().set_self_test(2000);
+    scl.spi_mut().raise_status(Status::DIGI1);
+    let (_, quality) = scl.read_with_quality::<Acceleration>().unwrap();
+    assert_eq!(quality, Quality::StatusFlagged);
+    scl.spi_mut().set_self_test(0);
+
+    assert!(Quality::StartupTail < Quality::StatusFlagged);
+  }
+
+  #[test]
+  fn test_read_with_quality_startup_tail() {
+    let mut sim = Scl3300Sim::new();
+    sim.set_start_up_delay_ns(50_000_000);
+
+    let config =
+      StartupConfig::new(MeasurementMode::FullScale12).with_check_depth(CheckDepth::Minimal).with_verify_whoami(false);
+    let mut scl = Scl3300::new(&mut sim).start_up(config).unwrap();
+
+    let (_, quality) = scl.read_with_quality::<Acceleration>().unwrap();
+    assert_eq!(quality, Quality::StartupTail);
+  }
+}
@@ -0,0 +1,95 @@
+use crate::{operation::Output, Error1, Error2, MeasurementMode, OffFrameRead, OutputList, Status};
+
+/// An action recommended to recover from the errors in a [`DiagnosticReport`].
+///
+/// Actions are ordered by severity.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Action {
+  /// No action needed.
+  None,
+  /// The condition is transient or expected, read the status again.
+  RereadStatus,
+  /// Perform a software reset, e.g. using [`Scl3300::reset`](crate::Scl3300::reset), and start up again.
+  SoftwareReset,
+  /// Cycle the power supply of the component.
+  PowerCycle,
+  /// The component or its external connections are likely faulty.
+  ComponentFailure,
+}
+
+/// The contents of the `STATUS`, `ERR_FLAG1` and `ERR_FLAG2` registers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DiagnosticReport {
+  /// The `STATUS` register.
+  pub status: Status,
+  /// The `ERR_FLAG1` register.
+  pub error1: Error1,
+  /// The `ERR_FLAG2` register.
+  pub error2: Error2,
+}
+
+impl DiagnosticReport {
+  /// Get the most severe action recommended for the flags in this report, according to the datasheet's
+  /// error source and required action descriptions.
+  ///
+  /// - External connection errors are reported as [`Action::ComponentFailure`].
+  /// - Internal connection and supply voltage errors require an [`Action::PowerCycle`].
+  /// - Digital block, clock, memory and start-up errors require an [`Action::SoftwareReset`].
+  /// - Saturation, mode change and power down flags are transient, so [`Action::RereadStatus`] is recommended.
+  pub fn recommended_action(&self) -> Action {
+    let Self { status, error1, error2 } = *self;
+
+    if error2.intersects(Error2::D_EXT_C | Error2::A_EXT_C | Error2::AGND) {
+      Action::ComponentFailure
+    } else if status.contains(Status::PIN_CONTINUITY)
+      || error2.intersects(Error2::VDD | Error2::APWR | Error2::APWR_2 | Error2::VREF)
+    {
+      Action::PowerCycle
+    } else if status.intersects(Status::DIGI1 | Status::DIGI2 | Status::CLK | Status::PWR | Status::MEM)
+      || error1.contains(Error1::MEM)
+      || error2.intersects(Error2::MEMORY_CRC | Error2::DPWR | Error2::CLK)
+    {
+      Action::SoftwareReset
+    } else if !status.is_empty() || !error1.is_empty() || !error2.is_empty() {
+      Action::RereadStatus
+    } else {
+      Action::None
+    }
+  }
+}
+
+impl OffFrameRead for DiagnosticReport {
+  const OUTPUTS: OutputList = OutputList::new(&[Output::Status, Output::Error1, Output::Error2]);
+
+  fn decode(values: &[u16], _mode: MeasurementMode) -> Self {
+    Self {
+      status: Status::from_bits_retain(values[0]),
+      error1: Error1::from_bits_retain(values[1]),
+      error2: Error2::from_bits_retain(values[2]),
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn report(status: Status, error1: Error1, error2: Error2) -> DiagnosticReport {
+    DiagnosticReport { status, error1, error2 }
+  }
+
+  #[test]
+  fn test_recommended_action() {
+    assert_eq!(report(Status::empty(), Error1::empty(), Error2::empty()).recommended_action(), Action::None);
+    assert_eq!(report(Status::SAT, Error1::ADC_SAT, Error2::empty()).recommended_action(), Action::RereadStatus);
+    assert_eq!(
+      report(Status::SAT | Status::CLK, Error1::empty(), Error2::CLK).recommended_action(),
+      Action::SoftwareReset
+    );
+    assert_eq!(
+      report(Status::PIN_CONTINUITY, Error1::empty(), Error2::empty()).recommended_action(),
+      Action::PowerCycle
+    );
+    assert_eq!(report(Status::MEM, Error1::MEM, Error2::A_EXT_C).recommended_action(), Action::ComponentFailure);
+  }
+}
@@ -0,0 +1,161 @@
+use embedded_hal::spi::SpiDevice;
+
+use crate::{
+  check_bus_frequency, Calibration, Error, MeasurementMode, Normal, Scl3300, StartupConfig, Timings, Uninitialized,
+};
+
+/// A snapshot of the driver configuration.
+///
+/// This can be stored, e.g. in flash, to restore identical behavior after deep sleep or reboot
+/// using [`Scl3300::restore`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DriverConfig {
+  /// The measurement mode.
+  pub mode: MeasurementMode,
+  /// Whether angle outputs are enabled.
+  pub angle_outputs_enabled: bool,
+  /// The calibration applied to read values.
+  pub calibration: Calibration,
+  /// How many times start-up and reads are retried after a transient error.
+  pub retries: u8,
+  /// Whether every response is checked to echo the previous request.
+  pub strict_validation: bool,
+  /// After how many reads the `STATUS` register is checked, `0` if disabled.
+  pub status_check_interval: u8,
+  /// Whether the final frame of a read is deferred to the next read.
+  #[cfg_attr(feature = "serde", serde(default))]
+  pub deferred_flush: bool,
+  /// Whether the `STATUS` register is read automatically after an error return status.
+  #[cfg_attr(feature = "serde", serde(default))]
+  pub read_status_on_error: bool,
+  /// The declared SPI bus frequency in Hz, if any.
+  pub bus_frequency_hz: Option<u32>,
+  /// The timings used when communicating with the inclinometer.
+  pub timings: Timings,
+}
+
+impl<SPI, E> Scl3300<SPI, Uninitialized>
+where
+  SPI: SpiDevice<u8, Error = E>,
+{
+  /// Start the inclinometer with the given configuration snapshot.
+  ///
+  /// Returns [`Error::BusConfig`] if the declared bus frequency is out of range.
+  pub fn restore(mut self, config: DriverConfig) -> Result<Scl3300<SPI, Normal>, Error<E>> {
+    check_bus_frequency(config.bus_frequency_hz)?;
+
+    self.state.calibration = config.calibration;
+    self.state.retries = config.retries;
+    self.state.status_check_interval = config.status_check_interval;
+    self.state.deferred_flush = config.deferred_flush;
+    self.state.read_status_on_error = config.read_status_on_error;
+    self.state.bus_frequency_hz = config.bus_frequency_hz;
+    self.state.timings = config.timings;
+
+    let mut scl = self.start_up(StartupConfig::new(config.mode).with_strict_validation(config.strict_validation))?;
+    if !config.angle_outputs_enabled {
+      scl.disable_angle_outputs()?;
+    }
+
+    Ok(scl)
+  }
+}
+
+impl<SPI, E> Scl3300<SPI, Normal>
+where
+  SPI: SpiDevice<u8, Error = E>,
+{
+  /// Get a snapshot of the current configuration.
+  pub fn config(&self) -> DriverConfig {
+    DriverConfig {
+      mode: self.mode.mode,
+      angle_outputs_enabled: self.mode.angles_enabled,
+      calibration: self.state.calibration,
+      retries: self.state.retries,
+      strict_validation: self.state.strict,
+      status_check_interval: self.state.status_check_interval,
+      deferred_flush: self.state.deferred_flush,
+      read_status_on_error: self.state.read_status_on_error,
+      bus_frequency_hz: self.state.bus_frequency_hz,
+      timings: self.state.timings,
+    }
+  }
+
+  /// Apply the given configuration snapshot.
+  ///
+  /// The measurement mode and angle outputs are only written if they differ from the current ones.
+  /// Returns [`Error::BusConfig`] without applying the snapshot if the declared bus frequency is out of range.
+  pub fn restore(&mut self, config: DriverConfig) -> Result<(), Error<E>> {
+    check_bus_frequency(config.bus_frequency_hz)?;
+
+    self.state.calibration = config.calibration;
+    self.state.retries = config.retries;
+    self.state.strict = config.strict_validation;
+    self.state.status_check_interval = config.status_check_interval;
+    self.state.deferred_flush = config.deferred_flush;
+    self.state.read_status_on_error = config.read_status_on_error;
+    self.state.bus_frequency_hz = config.bus_frequency_hz;
+    self.state.timings = config.timings;
+
+    if config.mode != self.mode.mode {
+      self.configure(config.mode, config.angle_outputs_enabled)?;
+      self.mode = Normal { mode: config.mode, angles_enabled: config.angle_outputs_enabled };
+    } else if config.angle_outputs_enabled != self.mode.angles_enabled {
+      if config.angle_outputs_enabled {
+        self.enable_angle_outputs()?;
+      } else {
+        self.disable_angle_outputs()?;
+      }
+    }
+
+    Ok(())
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  use crate::{sim::Scl3300Sim, AxisMapping, SignedAxis};
+
+  #[test]
+  fn test_config_restore() {
+    let mut sim = Scl3300Sim::new();
+
+    let mapping = AxisMapping::new(SignedAxis::NegX, SignedAxis::PosY, SignedAxis::PosZ).unwrap();
+    let mut scl = Scl3300::builder(&mut sim)
+      .mode(MeasurementMode::Inclination)
+      .axis_mapping(mapping)
+      .inclination_offset([1, 2, 3])
+      .retries(2)
+      .status_check_interval(10)
+      .deferred_flush(true)
+      .read_status_on_error(true)
+      .bus_frequency_hz(1_000_000)
+      .build_and_start()
+      .unwrap();
+    scl.disable_angle_outputs().unwrap();
+    let config = scl.config();
+
+    let mut scl = Scl3300::new(scl.release()).restore(config).unwrap();
+    assert_eq!(scl.config(), config);
+    assert!(!scl.spi.angle_outputs_enabled());
+
+    let mut other = config;
+    other.mode = MeasurementMode::FullScale24;
+    other.angle_outputs_enabled = true;
+    scl.restore(other).unwrap();
+    assert_eq!(scl.config(), other);
+    assert_eq!(scl.spi.mode(), MeasurementMode::FullScale24);
+    assert!(scl.spi.angle_outputs_enabled());
+
+    let mut invalid = config;
+    invalid.bus_frequency_hz = Some(8_000_000);
+    assert!(matches!(scl.restore(invalid), Err(Error::BusConfig { frequency_hz: 8_000_000 })));
+    assert_eq!(scl.config(), other);
+
+    let res = Scl3300::new(scl.release()).restore(invalid);
+    assert!(matches!(res, Err(Error::BusConfig { frequency_hz: 8_000_000 })));
+  }
+}
@@ -0,0 +1,193 @@
+use embedded_hal::{delay::DelayNs, spi::SpiDevice};
+
+use crate::{
+  operation::Operation, Error, MeasurementMode, Normal, OffFrameRead, PowerDown, Scl3300, WAKE_UP_DISCARD_SAMPLES,
+};
+
+/// Low-power duty-cycle controller.
+///
+/// Keeps the inclinometer in power down mode between samples and only wakes it up
+/// once per period, which is useful for battery-powered data loggers.
+///
+/// The period is measured using the delay only, starting after the previous sample was read. The time needed to
+/// transfer the frames of a sample is not included, so the actual period is slightly longer than configured and
+/// samples drift relative to a wall clock.
+#[derive(Debug)]
+pub struct DutyCycle<SPI, D> {
+  scl: Scl3300<SPI, Normal>,
+  delay: D,
+  period_ns: u64,
+  awake: bool,
+}
+
+impl<SPI, E, D> DutyCycle<SPI, D>
+where
+  SPI: SpiDevice<u8, Error = E>,
+  D: DelayNs,
+{
+  /// Create a new duty-cycle controller sampling in the given [`MeasurementMode`](enum.MeasurementMode.html)
+  /// once every `period_ns` nanoseconds.
+  ///
+  /// Angle outputs are enabled or disabled as they were before the inclinometer was powered down.
+  pub fn new(scl: Scl3300<SPI, PowerDown>, delay: D, mode: MeasurementMode, period_ns: u64) -> Self {
+    let angles_enabled = scl.mode.angles_enabled;
+    Self { scl: scl.into_mode(Normal { mode, angles_enabled }), delay, period_ns, awake: false }
+  }
+
+  /// Create a new duty-cycle controller sampling in the given [`MeasurementMode`](enum.MeasurementMode.html)
+  /// once every `period`.
+  ///
+  /// Panics if the period exceeds `u64::MAX` nanoseconds.
+  #[cfg(feature = "fugit")]
+  pub fn with_period<const NOM: u32, const DENOM: u32>(
+    scl: Scl3300<SPI, PowerDown>,
+    delay: D,
+    mode: MeasurementMode,
+    period: fugit::Duration<u64, NOM, DENOM>,
+  ) -> Self {
+    let period_ns = match period.const_try_into::<1, 1_000_000_000>() {
+      Some(period) => period.ticks(),
+      None => panic!("duration exceeds u64::MAX nanoseconds"),
+    };
+    Self::new(scl, delay, mode, period_ns)
+  }
+
+  /// Get the time the inclinometer needs to wake up and settle, see [`wake_up_time_ns`](DutyCycle::wake_up_time_ns).
+  #[cfg(feature = "fugit")]
+  pub fn wake_up_time(&self) -> fugit::NanosDurationU32 {
+    fugit::NanosDurationU32::from_ticks(self.wake_up_time_ns())
+  }
+
+  /// Get the time in nanoseconds the inclinometer needs to wake up and settle,
+  /// including the samples discarded after waking up.
+  ///
+  /// This is included in the sample period.
+  pub fn wake_up_time_ns(&self) -> u32 {
+    let mode = self.scl.mode.mode;
+    let timings = &self.scl.state.timings;
+    timings.wake_up_ns + timings.settling_ns(mode) + WAKE_UP_DISCARD_SAMPLES as u32 * mode.sample_period_ns()
+  }
+
+  /// Wait for the rest of the current period, then wake the inclinometer up,
+  /// read a value and put it back into power down mode.
+  pub fn sample<V>(&mut self) -> Result<V, Error<E>>
+  where
+    V: OffFrameRead,
+  {
+    let wait_ns = self.period_ns.saturating_sub(self.wake_up_time_ns() as u64);
+    let mut wait_ms = wait_ns / 1_000_000;
+    while wait_ms > 0 {
+      let ms = wait_ms.min(u32::MAX as u64) as u32;
+      self.delay.delay_ms(ms);
+      wait_ms -= ms as u64;
+    }
+    self.delay.delay_ns((wait_ns % 1_000_000) as u32);
+
+    self.awake = true;
+    let Normal { mode, angles_enabled } = self.scl.mode;
+    self.scl.wake_up_into(mode, angles_enabled)?;
+
+    let value = self.scl.read()?;
+
+    self.scl.transfer(Operation::PowerDown, None)?;
+    self.awake = false;
+
+    Ok(value)
+  }
+
+  /// Get an iterator which yields a new sample every period.
+  ///
+  /// The iterator ends after the first error.
+  pub fn samples<V>(&mut self) -> impl Iterator<Item = Result<V, Error<E>>> + '_
+  where
+    V: OffFrameRead,
+  {
+    let mut failed = false;
+    core::iter::from_fn(move || {
+      if failed {
+        return None
+      }
+
+      let sample = self.sample();
+      failed = sample.is_err();
+      Some(sample)
+    })
+  }
+
+  /// Release the inclinometer and the delay.
+  ///
+  /// If a previous sample failed while the inclinometer was awake, it is put back into power down mode first.
+  pub fn release(mut self) -> Result<(Scl3300<SPI, PowerDown>, D), Error<E>> {
+    if self.awake {
+      self.scl.transfer(Operation::PowerDown, None)?;
+    }
+
+    Ok((self.scl.into_power_down(), self.delay))
+  }
+}
+
+#[cfg(all(test, feature = "float"))]
+mod tests {
+  use super::*;
+
+  use crate::{sim::Scl3300Sim, Acceleration, Degrees, Inclination, PlanError};
+
+  #[derive(Debug, Default)]
+  struct Clock {
+    elapsed_ns: u64,
+  }
+
+  impl DelayNs for &mut Clock {
+    fn delay_ns(&mut self, ns: u32) {
+      self.elapsed_ns += ns as u64;
+    }
+  }
+
+  #[test]
+  fn test_duty_cycle() {
+    let mut sim = Scl3300Sim::new();
+    sim.set_inclination(0.0, 45.0, 90.0);
+    let mut clock = Clock::default();
+
+    let scl = Scl3300::new(&mut sim).start_up(MeasurementMode::Inclination).unwrap().power_down().unwrap();
+    let mut duty_cycle = DutyCycle::new(scl, &mut clock, MeasurementMode::Inclination, 10_000_000_000);
+
+    for inc in duty_cycle.samples::<Inclination>().take(3) {
+      assert_eq!(inc.unwrap().y_degrees(), Degrees(45.0));
+    }
+
+    duty_cycle.release().unwrap();
+    assert!(sim.is_powered_down());
+    assert_eq!(clock.elapsed_ns, 3 * (10_000_000_000 - 101_500_000));
+  }
+
+  #[test]
+  fn test_angles_disabled() {
+    let mut sim = Scl3300Sim::new();
+    let mut clock = Clock::default();
+
+    let mut scl = Scl3300::new(&mut sim).start_up(MeasurementMode::Inclination).unwrap();
+    scl.disable_angle_outputs().unwrap();
+    let scl = scl.power_down().unwrap();
+    let mut duty_cycle = DutyCycle::new(scl, &mut clock, MeasurementMode::Inclination, 10_000_000_000);
+
+    assert!(duty_cycle.sample::<Acceleration>().is_ok());
+    assert!(matches!(duty_cycle.sample::<Inclination>(), Err(Error::Plan(PlanError::AnglesDisabled))));
+
+    let (scl, _) = duty_cycle.release().unwrap();
+    assert!(!scl.spi().angle_outputs_enabled());
+  }
+
+  #[test]
+  fn test_samples_stop_after_error() {
+    let mut sim = Scl3300Sim::new();
+    let mut clock = Clock::default();
+
+    let mut scl = Scl3300::new(&mut sim).start_up(MeasurementMode::Inclination).unwrap().power_down().unwrap();
+    scl.spi_mut().set_start_up_delay_ns(1_000_000_000);
+    let mut duty_cycle = DutyCycle::new(scl, &mut clock, MeasurementMode::Inclination, 1_000_000_000);
+
+    let samples = duty_cycle.samples::<Inclination>().take(3).collect::<Vec<_>>();
+    assert!(matches!(samples[..], [Err(Error::NeverLeftStartup { .. })]), "{samples:?}");
+  }
+}
@@ -0,0 +1,130 @@
+use core::fmt;
+
+use embedded_hal::spi::{self, ErrorKind, ErrorType, Operation, SpiDevice};
+use embedded_hal_0_2::blocking::{delay::DelayUs, spi::Transfer};
+
+use crate::Scl3300;
+
+/// An error returned by an [`Eh0Device`].
+#[derive(Debug)]
+pub struct Eh0Error<E>(pub E);
+
+impl<E: fmt::Debug> spi::Error for Eh0Error<E> {
+  fn kind(&self) -> ErrorKind {
+    ErrorKind::Other
+  }
+}
+
+/// An [`SpiDevice`] backed by an `embedded-hal` 0.2 SPI and delay implementation.
+///
+/// The SPI implementation has to handle chip select itself, i.e. every call to
+/// [`Transfer::transfer`] has to be a single transaction.
+#[derive(Debug)]
+pub struct Eh0Device<SPI, D> {
+  spi: SPI,
+  delay: D,
+}
+
+impl<SPI, D> Eh0Device<SPI, D> {
+  /// Create a new device using the given `spi` and `delay`.
+  pub const fn new(spi: SPI, delay: D) -> Self {
+    Self { spi, delay }
+  }
+
+  /// Release the SPI and the delay.
+  pub fn release(self) -> (SPI, D) {
+    (self.spi, self.delay)
+  }
+}
+
+impl<SPI, D, E> ErrorType for Eh0Device<SPI, D>
+where
+  SPI: Transfer<u8, Error = E>,
+  E: fmt::Debug,
+{
+  type Error = Eh0Error<E>;
+}
+
+impl<SPI, D, E> SpiDevice<u8> for Eh0Device<SPI, D>
+where
+  SPI: Transfer<u8, Error = E>,
+  D: DelayUs<u32>,
+  E: fmt::Debug,
+{
+  fn transaction(&mut self, operations: &mut [Operation<'_, u8>]) -> Result<(), Self::Error> {
+    for operation in operations {
+      match operation {
+        Operation::Read(words) => {
+          words.fill(0);
+          self.spi.transfer(words).map_err(Eh0Error)?;
+        },
+        Operation::Write(words) => {
+          for chunk in words.chunks(4) {
+            let mut buf = [0; 4];
+            let buf = &mut buf[..chunk.len()];
+            buf.copy_from_slice(chunk);
+            self.spi.transfer(buf).map_err(Eh0Error)?;
+          }
+        },
+        Operation::Transfer(read, write) => {
+          let len = read.len().min(write.len());
+          read[..len].copy_from_slice(&write[..len]);
+          read[len..].fill(0);
+          self.spi.transfer(read).map_err(Eh0Error)?;
+        },
+        Operation::TransferInPlace(words) => {
+          self.spi.transfer(words).map_err(Eh0Error)?;
+        },
+        Operation::DelayNs(ns) => self.delay.delay_us(ns.div_ceil(1000)),
+      }
+    }
+
+    Ok(())
+  }
+}
+
+impl<SPI, D> Scl3300<Eh0Device<SPI, D>> {
+  /// Create a new `Scl3300` using an `embedded-hal` 0.2 SPI and delay implementation.
+  ///
+  /// See [`Eh0Device`] for details.
+  pub const fn new_eh0(spi: SPI, delay: D) -> Self {
+    Scl3300::new(Eh0Device::new(spi, delay))
+  }
+}
+
+#[cfg(all(test, feature = "float"))]
+mod tests {
+  use core::{cell::RefCell, convert::Infallible};
+
+  use super::*;
+
+  use crate::{sim::Scl3300Sim, Inclination, MeasurementMode};
+
+  struct Eh0Spi<'a>(&'a RefCell<Scl3300Sim>);
+
+  impl Transfer<u8> for Eh0Spi<'_> {
+    type Error = Infallible;
+
+    fn transfer<'w>(&mut self, words: &'w mut [u8]) -> Result<&'w [u8], Self::Error> {
+      self.0.borrow_mut().transfer_in_place(words)?;
+      Ok(words)
+    }
+  }
+
+  struct Eh0Delay<'a>(&'a RefCell<Scl3300Sim>);
+
+  impl DelayUs<u32> for Eh0Delay<'_> {
+    fn delay_us(&mut self, us: u32) {
+      self.0.borrow_mut().transaction(&mut [Operation::DelayNs(us * 1000)]).unwrap();
+    }
+  }
+
+  #[test]
+  fn test_eh0() {
+    let sim = RefCell::new(Scl3300Sim::new());
+    sim.borrow_mut().set_inclination(0.0, 15.0, 75.0);
+
+    let mut scl = Scl3300::new_eh0(Eh0Spi(&sim), Eh0Delay(&sim)).start_up(MeasurementMode::Inclination).unwrap();
+    assert_eq!(scl.read::<Inclination>().unwrap().y_degrees().get().round(), 15.0);
+  }
+}
@@ -1,12 +1,164 @@
+use crate::{DiagnosticReport, PlanError, ProbableCause, SelfTest, Status};
+
 /// An SCL3300 error.
-#[derive(Debug)]
+///
+/// Errors can be cloned and compared if the SPI error type `E` can.
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum Error<E> {
   /// Startup error
   Startup,
   /// ReturnStatus error
-  ReturnStatus,
+  ReturnStatus {
+    /// The complete response frame.
+    frame: [u8; 4],
+    /// The `RS` bits of the response frame.
+    rs: u8,
+    /// The `STATUS` register, if it was read automatically after the error.
+    status: Option<Status>,
+  },
   /// CRC checksum mismatch
   Crc,
+  /// Start-up was still in progress after polling the return status
+  NeverLeftStartup {
+    /// The number of times the return status was polled.
+    attempts: u8,
+  },
+  /// Unexpected component ID
+  WrongDevice {
+    /// The component ID found in the `WHOAMI` register.
+    found: u8,
+  },
+  /// Response does not correspond to the previous request
+  ProtocolDesync,
+  /// Critical flags are set in the `STATUS` register
+  CriticalStatus {
+    /// The `STATUS` register.
+    status: Status,
+  },
+  /// The self-test output is outside of its thresholds during an extended start-up check
+  SelfTestFailed {
+    /// The self-test reading.
+    self_test: SelfTest,
+  },
+  /// Error flags are set during an extended start-up check
+  Diagnostics {
+    /// The `STATUS`, `ERR_FLAG1` and `ERR_FLAG2` registers.
+    report: DiagnosticReport,
+  },
+  /// Angle outputs could not be enabled
+  AngleEnableFailed,
+  /// Buffer size does not match the number of frames
+  BufferSize,
+  /// The declared SPI bus frequency is out of range
+  BusConfig {
+    /// The declared frequency in Hz.
+    frequency_hz: u32,
+  },
+  /// A request frame has a CRC mismatch or non-zero `RS` bits, or writes a register tracked by the driver
+  InvalidRequest {
+    /// The complete request frame.
+    frame: [u8; 4],
+  },
+  /// The response to a probe could not be decoded
+  BusMisconfigured(ProbableCause),
+  /// A read plan is invalid
+  Plan(PlanError),
   /// SPI error
   Spi(E),
 }
+
+impl<E> From<PlanError> for Error<E> {
+  fn from(err: PlanError) -> Self {
+    Self::Plan(err)
+  }
+}
+
+/// The severity of an [`Error`], i.e. what is needed to recover from it.
+///
+/// Severities are ordered, a higher severity requires a more drastic recovery.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Severity {
+  /// The operation can simply be retried.
+  Retry,
+  /// The `STATUS` register needs to be read to clear the error.
+  ReadStatus,
+  /// The sensor needs to be reset.
+  Reset,
+  /// The error cannot be recovered from.
+  Fatal,
+}
+
+impl<E> Error<E>
+where
+  E: embedded_hal::spi::Error,
+{
+  /// Classify this error by what is needed to recover from it.
+  ///
+  /// - CRC, start-up and protocol desynchronization errors can be retried.
+  /// - Return status errors require reading the `STATUS` register, unless it was already read automatically.
+  /// - Critical status flags, start-up not finishing, failing to enable angle outputs and failed start-up checks
+  ///   require a reset.
+  /// - SPI errors are classified by their [`ErrorKind`](embedded_hal::spi::ErrorKind): overruns and chip
+  ///   select faults can be retried, all other kinds are fatal.
+  /// - All other errors are fatal.
+  pub fn severity(&self) -> Severity {
+    use embedded_hal::spi::ErrorKind;
+
+    match self {
+      Self::Startup | Self::Crc | Self::ProtocolDesync => Severity::Retry,
+      Self::ReturnStatus { status: Some(_), .. } => Severity::Retry,
+      Self::ReturnStatus { status: None, .. } => Severity::ReadStatus,
+      Self::CriticalStatus { .. }
+      | Self::NeverLeftStartup { .. }
+      | Self::AngleEnableFailed
+      | Self::SelfTestFailed { .. }
+      | Self::Diagnostics { .. } => Severity::Reset,
+      Self::WrongDevice { .. }
+      | Self::BufferSize
+      | Self::BusConfig { .. }
+      | Self::InvalidRequest { .. }
+      | Self::BusMisconfigured(_)
+      | Self::Plan(_) => Severity::Fatal,
+      Self::Spi(err) => match err.kind() {
+        ErrorKind::Overrun | ErrorKind::ChipSelectFault => Severity::Retry,
+        _ => Severity::Fatal,
+      },
+    }
+  }
+
+  /// Check whether the operation causing this error can simply be retried.
+  pub fn is_transient(&self) -> bool {
+    self.severity() == Severity::Retry
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  use embedded_hal::spi::ErrorKind;
+
+  #[test]
+  fn test_severity() {
+    assert!(Error::<ErrorKind>::Crc.is_transient());
+    assert!(Error::<ErrorKind>::Startup.is_transient());
+    assert_eq!(
+      Error::<ErrorKind>::ReturnStatus { frame: [0; 4], rs: 0b11, status: None }.severity(),
+      Severity::ReadStatus
+    );
+    assert!(Error::<ErrorKind>::ReturnStatus { frame: [0; 4], rs: 0b11, status: Some(Status::SAT) }.is_transient());
+    assert_eq!(Error::<ErrorKind>::CriticalStatus { status: Status::CLK }.severity(), Severity::Reset);
+    assert_eq!(Error::<ErrorKind>::WrongDevice { found: 0 }.severity(), Severity::Fatal);
+    assert!(Error::Spi(ErrorKind::Overrun).is_transient());
+    assert_eq!(Error::Spi(ErrorKind::ModeFault).severity(), Severity::Fatal);
+  }
+
+  #[test]
+  fn test_eq() {
+    let err = Error::<ErrorKind>::ReturnStatus { frame: [0x1B, 0, 0, 0], rs: 0b11, status: Some(Status::SAT) };
+    assert_eq!(err.clone(), err);
+    assert_ne!(err, Error::ReturnStatus { frame: [0x1B, 0, 0, 0], rs: 0b11, status: None });
+    assert_eq!(Error::Spi(ErrorKind::Overrun), Error::Spi(ErrorKind::Overrun));
+    assert_ne!(Error::Spi(ErrorKind::Overrun), Error::Crc);
+  }
+}
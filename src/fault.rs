@@ -0,0 +1,188 @@
+use crate::frame::crc8;
+
+/// The number of faults injected by a [`FaultInjector`], per kind.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct FaultCounts {
+  /// Responses whose CRC was corrupted.
+  pub crc: u32,
+  /// Responses whose return status was flipped to an error.
+  pub return_status: u32,
+  /// Frames which were dropped.
+  pub dropped: u32,
+}
+
+/// Injects frame-level faults into the driver's transfer path.
+///
+/// Each kind of fault is injected with a rate of one in `n` frames, `0` disables it. Faults are chosen
+/// by a deterministic pseudo-random sequence derived from the seed, so a failing test can be reproduced.
+///
+/// - A corrupted CRC is detected as [`Error::Crc`](crate::Error::Crc).
+/// - A flipped return status has a valid CRC and is detected as
+///   [`Error::ReturnStatus`](crate::Error::ReturnStatus).
+/// - A dropped frame is never sent to the inclinometer and receives the previous response again, so
+///   the next response does not echo its request. With strict validation, this is detected as
+///   [`Error::ProtocolDesync`](crate::Error::ProtocolDesync).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FaultInjector {
+  crc_rate: u32,
+  return_status_rate: u32,
+  drop_rate: u32,
+  state: u32,
+  last_response: Option<[u8; 4]>,
+  counts: FaultCounts,
+}
+
+impl FaultInjector {
+  /// Create a fault injector with the given seed which does not inject any faults.
+  pub const fn new(seed: u32) -> Self {
+    Self {
+      crc_rate: 0,
+      return_status_rate: 0,
+      drop_rate: 0,
+      state: if seed == 0 { 0x9E37_79B9 } else { seed },
+      last_response: None,
+      counts: FaultCounts { crc: 0, return_status: 0, dropped: 0 },
+    }
+  }
+
+  /// Corrupt the CRC of one in `n` responses.
+  pub const fn with_crc_errors(mut self, n: u32) -> Self {
+    self.crc_rate = n;
+    self
+  }
+
+  /// Flip the return status of one in `n` responses to an error.
+  pub const fn with_return_status_errors(mut self, n: u32) -> Self {
+    self.return_status_rate = n;
+    self
+  }
+
+  /// Drop one in `n` frames.
+  pub const fn with_dropped_frames(mut self, n: u32) -> Self {
+    self.drop_rate = n;
+    self
+  }
+
+  /// Get the number of faults injected so far.
+  pub const fn counts(&self) -> FaultCounts {
+    self.counts
+  }
+
+  fn chance(&mut self, n: u32) -> bool {
+    if n == 0 {
+      return false
+    }
+
+    // xorshift32
+    self.state ^= self.state << 13;
+    self.state ^= self.state >> 17;
+    self.state ^= self.state << 5;
+
+    self.state.is_multiple_of(n)
+  }
+
+  /// Decide whether to drop the next frame, returning the response it receives instead.
+  ///
+  /// Frames are only dropped once a response has been received.
+  pub(crate) fn drop_frame(&mut self) -> Option<[u8; 4]> {
+    let last_response = self.last_response?;
+
+    if self.chance(self.drop_rate) {
+      self.counts.dropped += 1;
+      Some(last_response)
+    } else {
+      None
+    }
+  }
+
+  /// Corrupt a response received from the inclinometer.
+  pub(crate) fn corrupt(&mut self, response: &mut [u8; 4]) {
+    self.last_response = Some(*response);
+
+    if self.chance(self.return_status_rate) {
+      self.counts.return_status += 1;
+      response[0] ^= 0b10;
+      response[3] = crc8([response[0], response[1], response[2]]);
+    }
+
+    if self.chance(self.crc_rate) {
+      self.counts.crc += 1;
+      response[3] = !response[3];
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  use crate::{sim::Scl3300Sim, Error, MeasurementMode, Scl3300, Serial, StartupConfig, Temperature};
+
+  #[test]
+  fn test_crc_errors() {
+    let mut sim = Scl3300Sim::new();
+
+    let mut scl = Scl3300::new(&mut sim).start_up(MeasurementMode::Inclination).unwrap();
+    scl.set_fault_injector(Some(FaultInjector::new(1).with_crc_errors(1)));
+    assert!(matches!(scl.read::<Temperature>(), Err(Error::Crc)));
+    assert_eq!(scl.fault_injector().unwrap().counts().crc, 1);
+
+    scl.set_fault_injector(None);
+    scl.read::<Temperature>().unwrap();
+  }
+
+  #[test]
+  fn test_return_status_errors() {
+    let mut sim = Scl3300Sim::new();
+
+    let mut scl = Scl3300::new(&mut sim).start_up(MeasurementMode::Inclination).unwrap();
+    scl.set_fault_injector(Some(FaultInjector::new(1).with_return_status_errors(1)));
+    assert!(matches!(scl.read::<Temperature>(), Err(Error::ReturnStatus { rs: 0b11, .. })));
+  }
+
+  #[test]
+  fn test_dropped_frames() {
+    let mut sim = Scl3300Sim::new();
+
+    let mut scl = Scl3300::new(&mut sim)
+      .start_up(StartupConfig::new(MeasurementMode::Inclination).with_strict_validation(true))
+      .unwrap();
+    scl.set_fault_injector(Some(FaultInjector::new(1).with_dropped_frames(1)));
+    assert!(matches!(scl.read::<Temperature>(), Err(Error::ProtocolDesync)));
+    assert_eq!(scl.fault_injector().unwrap().counts().dropped, 1);
+  }
+
+  #[test]
+  fn test_retries_recover() {
+    let mut sim = Scl3300Sim::new();
+
+    let mut scl = Scl3300::builder(&mut sim)
+      .mode(MeasurementMode::Inclination)
+      .retries(8)
+      .fault_injector(FaultInjector::new(42).with_crc_errors(4))
+      .build_and_start()
+      .unwrap();
+
+    for _ in 0..16 {
+      scl.read::<Temperature>().unwrap();
+    }
+    assert!(scl.fault_injector().unwrap().counts().crc > 0);
+  }
+
+  #[test]
+  fn test_retry_after_bank_switch() {
+    let mut sim = Scl3300Sim::new();
+    sim.set_serial(1021704154);
+
+    let mut scl = Scl3300::builder(&mut sim).mode(MeasurementMode::Inclination).retries(1).build_and_start().unwrap();
+    let (temp, _) = scl.read::<(Temperature, Serial)>().unwrap();
+
+    // Corrupt the first response received in bank 1.
+    scl.set_fault_injector(Some(FaultInjector::new(13).with_crc_errors(4)));
+    let (retried, serial) = scl.read::<(Temperature, Serial)>().unwrap();
+    assert_eq!(scl.fault_injector().unwrap().counts().crc, 1);
+    assert_eq!(retried, temp);
+    assert_eq!(serial.to_u32(), 1021704154);
+    assert_eq!(scl.spi().bank(), 0);
+  }
+}
@@ -0,0 +1,187 @@
+//! Filters for smoothing [`Acceleration`](crate::Acceleration) and [`Inclination`](crate::Inclination) measurements.
+//!
+//! All filters operate on raw values interpreted as `i16`, so no floating point arithmetic is needed.
+//! For inclination, this means angles are treated as ranging from -180° to 180°.
+//!
+//! ```rust
+//! use scl3300::filter::{Filter, MovingAverage};
+//!
+//! let mut filter = MovingAverage::<4>::new();
+//! filter.update(10);
+//! assert_eq!(filter.update(20), 15);
+//! ```
+//!
+//! To filter all axes of a measurement at once, use an [`AxisFilter`].
+
+use crate::Axes;
+
+/// A filter for a single channel of raw values.
+pub trait Filter {
+  /// Add a new sample and get the filtered value.
+  fn update(&mut self, sample: i16) -> i16;
+
+  /// Reset the filter to its initial state.
+  fn reset(&mut self);
+}
+
+/// Divide, rounding to the nearest integer.
+pub(crate) const fn div_round(dividend: i32, divisor: i32) -> i32 {
+  if dividend < 0 {
+    (dividend - divisor / 2) / divisor
+  } else {
+    (dividend + divisor / 2) / divisor
+  }
+}
+
+/// A moving average over the last `N` samples.
+#[derive(Debug, Clone)]
+pub struct MovingAverage<const N: usize> {
+  samples: [i16; N],
+  index: usize,
+  len: usize,
+  sum: i32,
+}
+
+impl<const N: usize> Default for MovingAverage<N> {
+  fn default() -> Self {
+    Self::new()
+  }
+}
+
+impl<const N: usize> MovingAverage<N> {
+  /// Create a new moving average filter.
+  pub const fn new() -> Self {
+    assert!(N > 0, "moving average window must not be empty");
+    Self { samples: [0; N], index: 0, len: 0, sum: 0 }
+  }
+}
+
+impl<const N: usize> Filter for MovingAverage<N> {
+  fn update(&mut self, sample: i16) -> i16 {
+    if self.len == N {
+      self.sum -= self.samples[self.index] as i32;
+    } else {
+      self.len += 1;
+    }
+
+    self.samples[self.index] = sample;
+    self.sum += sample as i32;
+    self.index = (self.index + 1) % N;
+
+    div_round(self.sum, self.len as i32) as i16
+  }
+
+  fn reset(&mut self) {
+    *self = Self::new();
+  }
+}
+
+/// A first-order IIR low-pass filter.
+///
+/// Each new sample is weighted with `1 / 2^shift`, i.e. `y += (x - y) / 2^shift`.
+#[derive(Debug, Clone)]
+pub struct Iir {
+  shift: u8,
+  state: Option<i32>,
+}
+
+impl Iir {
+  /// Create a new IIR filter with the given `shift`.
+  ///
+  /// The `shift` must be at most 15.
+  pub const fn new(shift: u8) -> Self {
+    assert!(shift <= 15, "IIR shift must be at most 15");
+    Self { shift, state: None }
+  }
+}
+
+impl Filter for Iir {
+  fn update(&mut self, sample: i16) -> i16 {
+    let state = match self.state {
+      Some(state) => state - div_round(state, 1 << self.shift) + sample as i32,
+      None => (sample as i32) << self.shift,
+    };
+    self.state = Some(state);
+
+    div_round(state, 1 << self.shift) as i16
+  }
+
+  fn reset(&mut self) {
+    self.state = None;
+  }
+}
+
+/// Applies one filter per axis to [`Axes`] measurements.
+#[derive(Debug, Clone)]
+pub struct AxisFilter<F> {
+  filters: [F; 3],
+}
+
+impl<F: Filter> AxisFilter<F> {
+  /// Create a new filter using separate filters for the X, Y and Z axis.
+  pub const fn new(x: F, y: F, z: F) -> Self {
+    Self { filters: [x, y, z] }
+  }
+
+  /// Create a new filter using the same filter for all axes.
+  pub fn splat(filter: F) -> Self
+  where
+    F: Clone,
+  {
+    Self::new(filter.clone(), filter.clone(), filter)
+  }
+
+  /// Add a new measurement and get the filtered measurement.
+  pub fn update<T: Axes>(&mut self, value: &T) -> T {
+    let raw = value.raw_axes();
+    let [x, y, z] = &mut self.filters;
+
+    value.with_raw_axes([
+      x.update(raw[0] as i16) as u16,
+      y.update(raw[1] as i16) as u16,
+      z.update(raw[2] as i16) as u16,
+    ])
+  }
+
+  /// Reset all filters to their initial state.
+  pub fn reset(&mut self) {
+    self.filters.iter_mut().for_each(F::reset)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  use crate::Inclination;
+
+  #[test]
+  fn test_moving_average() {
+    let mut filter = MovingAverage::<3>::new();
+    assert_eq!(filter.update(3), 3);
+    assert_eq!(filter.update(6), 5);
+    assert_eq!(filter.update(-3), 2);
+    assert_eq!(filter.update(-6), -1);
+    assert_eq!(filter.update(-9), -6);
+
+    filter.reset();
+    assert_eq!(filter.update(100), 100);
+  }
+
+  #[test]
+  fn test_iir() {
+    let mut filter = Iir::new(1);
+    assert_eq!(filter.update(100), 100);
+    assert_eq!(filter.update(0), 50);
+    assert_eq!(filter.update(0), 25);
+    assert_eq!(filter.update(-100), -38);
+  }
+
+  #[test]
+  fn test_axis_filter_wraps_around_zero() {
+    let mut filter = AxisFilter::splat(MovingAverage::<2>::new());
+    filter.update(&Inclination { x: 10, y: 0, z: 0, ..Default::default() });
+    let inc = filter.update(&Inclination { x: (-30i16) as u16, y: 0, z: 0, ..Default::default() });
+    assert_eq!(inc.x_raw(), (-10i16) as u16);
+  }
+}
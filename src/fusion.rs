@@ -0,0 +1,98 @@
+use crate::{Acceleration, Axes, Axis, Inclination};
+
+/// Raw inclination units per degree.
+const RAW_PER_DEGREE: f32 = Inclination::FACTOR / 90.0;
+
+/// The angle outputs diverged from the inclination derived from acceleration.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Divergence {
+  /// The axis with the largest divergence.
+  pub axis: Axis,
+  /// The divergence in degrees.
+  pub degrees: f32,
+}
+
+/// Combines the angle outputs with inclination derived from acceleration.
+///
+/// Vibration can corrupt the angle outputs, so cross-checking them against
+/// [`Acceleration::to_inclination`] helps detecting unusable readings.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AngleFusion {
+  weight: f32,
+  max_divergence: f32,
+}
+
+impl AngleFusion {
+  /// Create a new fusion helper.
+  ///
+  /// The `weight` in the range `0.0..=1.0` determines how much the acceleration-derived inclination
+  /// contributes to the fused inclination. Readings diverging by more than `max_divergence` degrees on
+  /// any axis are rejected.
+  pub fn new(weight: f32, max_divergence: f32) -> Self {
+    Self { weight: weight.clamp(0.0, 1.0), max_divergence }
+  }
+
+  /// Get the divergence between the angle outputs and the inclination derived from acceleration.
+  pub fn divergence(&self, acceleration: &Acceleration, inclination: &Inclination) -> Divergence {
+    let (axis, diff) = raw_differences(acceleration, inclination)
+      .into_iter()
+      .zip(Axis::ALL)
+      .map(|(diff, axis)| (axis, diff))
+      .max_by_key(|(_, diff)| diff.unsigned_abs())
+      .unwrap_or((Axis::X, 0));
+
+    Divergence { axis, degrees: diff.unsigned_abs() as f32 / RAW_PER_DEGREE }
+  }
+
+  /// Blend the angle outputs with the inclination derived from acceleration.
+  ///
+  /// Returns an error if the divergence on any axis exceeds the configured maximum.
+  pub fn fuse(&self, acceleration: &Acceleration, inclination: &Inclination) -> Result<Inclination, Divergence> {
+    let divergence = self.divergence(acceleration, inclination);
+    if divergence.degrees > self.max_divergence {
+      return Err(divergence)
+    }
+
+    let raw = inclination.raw_axes();
+    let diffs = raw_differences(acceleration, inclination);
+
+    Ok(inclination.with_raw_axes(core::array::from_fn(|i| {
+      let correction = libm::roundf(diffs[i] as f32 * self.weight) as i16;
+      (raw[i] as i16).wrapping_add(correction) as u16
+    })))
+  }
+}
+
+/// Wrap-aware per-axis differences between acceleration-derived inclination and angle outputs.
+fn raw_differences(acceleration: &Acceleration, inclination: &Inclination) -> [i16; 3] {
+  let derived = acceleration.to_inclination().raw_axes();
+  let raw = inclination.raw_axes();
+
+  core::array::from_fn(|i| (derived[i] as i16).wrapping_sub(raw[i] as i16))
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  use crate::MeasurementMode;
+
+  #[test]
+  fn test_fuse() {
+    let fusion = AngleFusion::new(0.5, 1.0);
+    let acc = Acceleration { x: 0, y: 0, z: 12000, mode: MeasurementMode::Inclination };
+
+    let inc = Inclination { x: (-100i16) as u16, y: 100, z: 0x4000, ..Default::default() };
+    let fused = fusion.fuse(&acc, &inc).unwrap();
+    assert_eq!(fused.raw_axes(), [(-50i16) as u16, 50, 0x4000]);
+  }
+
+  #[test]
+  fn test_divergence() {
+    let fusion = AngleFusion::new(0.5, 1.0);
+    let acc = Acceleration { x: 0, y: 0, z: 12000, mode: MeasurementMode::Inclination };
+
+    let inc = Inclination { x: 0, y: (-364i16) as u16, z: 0x4000, ..Default::default() };
+    assert_eq!(fusion.fuse(&acc, &inc), Err(Divergence { axis: Axis::Y, degrees: 364.0 / RAW_PER_DEGREE }));
+  }
+}
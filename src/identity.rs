@@ -0,0 +1,74 @@
+use embedded_hal::spi::SpiDevice;
+
+use crate::{
+  operation::Output, Command, ComponentId, Error, MeasurementMode, Normal, OffFrameRead, OutputList, Scl3300, Serial,
+};
+
+/// The identity of an inclinometer, e.g. for provisioning or asset logs, see [`Scl3300::identify`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Identity {
+  /// The component ID read from the `WHOAMI` register.
+  pub component_id: ComponentId,
+  /// The serial number.
+  pub serial: Serial,
+  /// The measurement mode read from the `MODE` register.
+  pub mode: MeasurementMode,
+}
+
+impl Identity {
+  /// Format the serial number as a [`heapless::String`].
+  #[cfg(feature = "heapless")]
+  pub fn serial_string(&self) -> heapless::String<{ Serial::STRING_LEN }> {
+    self.serial.to_heapless_string()
+  }
+}
+
+impl OffFrameRead for Identity {
+  const OUTPUTS: OutputList = OutputList::new(&[Output::WhoAmI, Output::Command, Output::Serial1, Output::Serial2]);
+
+  fn decode(values: &[u16], mode: MeasurementMode) -> Self {
+    Identity {
+      component_id: ComponentId::decode(&values[0..1], mode),
+      serial: Serial::decode(&values[2..4], mode),
+      mode: Command::from_bits(values[1]).mode,
+    }
+  }
+}
+
+impl<SPI, E> Scl3300<SPI, Normal>
+where
+  SPI: SpiDevice<u8, Error = E>,
+{
+  /// Read the component ID, serial number and measurement mode.
+  ///
+  /// All registers are read in a single pass, with one round-trip to bank 1 for the serial number.
+  pub fn identify(&mut self) -> Result<Identity, Error<E>> {
+    self.read()
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  use crate::{sim::Scl3300Sim, ReadPlan};
+
+  #[test]
+  fn test_identify() {
+    const PLAN: ReadPlan<Identity> = ReadPlan::new();
+    assert_eq!(PLAN.buffer_len(), 6 * 4);
+
+    let mut sim = Scl3300Sim::new();
+    sim.set_serial(1021704154);
+
+    let mut scl = Scl3300::new(&mut sim).start_up(MeasurementMode::Inclination).unwrap();
+    let identity = scl.identify().unwrap();
+    assert!(identity.component_id.is_correct());
+    assert_eq!(identity.serial.to_u32(), 1021704154);
+    assert_eq!(identity.mode, MeasurementMode::Inclination);
+    assert_eq!(scl.spi().bank(), 0);
+
+    #[cfg(feature = "heapless")]
+    assert_eq!(identity.serial_string(), "1021704154B33");
+  }
+}
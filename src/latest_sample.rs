@@ -0,0 +1,137 @@
+use core::{cell::UnsafeCell, fmt, mem::MaybeUninit, ptr};
+
+use portable_atomic::{fence, AtomicU32, Ordering};
+
+/// A lock-free cell holding the latest sample, e.g. written by an acquisition task or interrupt and read
+/// by any number of consumers.
+///
+/// This is a sequence lock: the sequence number is odd while a sample is being written, and readers
+/// discard values which were read while the sequence number was odd or changed. Writing never blocks,
+/// so this can be used from interrupt handlers.
+pub struct LatestSample<V> {
+  sequence: AtomicU32,
+  value: UnsafeCell<MaybeUninit<V>>,
+}
+
+// SAFETY: The value is only written while holding the odd sequence number and only returned
+//         by readers if the sequence number did not change while it was copied.
+unsafe impl<V: Copy + Send> Sync for LatestSample<V> {}
+
+impl<V: Copy> LatestSample<V> {
+  /// Create an empty cell.
+  pub const fn new() -> Self {
+    Self { sequence: AtomicU32::new(0), value: UnsafeCell::new(MaybeUninit::uninit()) }
+  }
+
+  /// Publish a new sample, replacing the previous one.
+  ///
+  /// Returns `false` if another sample is being published concurrently, in which case this sample is dropped.
+  pub fn publish(&self, value: V) -> bool {
+    let sequence = self.sequence.load(Ordering::Relaxed);
+    if sequence % 2 == 1
+      || self
+        .sequence
+        .compare_exchange(sequence, sequence.wrapping_add(1), Ordering::Acquire, Ordering::Relaxed)
+        .is_err()
+    {
+      return false
+    }
+    fence(Ordering::Release);
+
+    // SAFETY: Only one writer can hold the odd sequence number.
+    unsafe { ptr::write_volatile(self.value.get(), MaybeUninit::new(value)) };
+
+    // Skip `0` on overflow, which marks an empty cell.
+    let next = match sequence.wrapping_add(2) {
+      0 => 2,
+      next => next,
+    };
+    self.sequence.store(next, Ordering::Release);
+    true
+  }
+
+  /// Get the latest sample, or `None` if no sample was published yet or a sample is currently being published.
+  pub fn try_latest(&self) -> Option<V> {
+    let sequence = self.sequence.load(Ordering::Acquire);
+    if sequence == 0 || sequence % 2 == 1 {
+      return None
+    }
+
+    // SAFETY: A sample was published before, so the value is initialized. A torn copy is discarded below.
+    let value = unsafe { ptr::read_volatile(self.value.get()) };
+    fence(Ordering::Acquire);
+
+    if self.sequence.load(Ordering::Relaxed) != sequence {
+      return None
+    }
+
+    // SAFETY: The sequence number did not change, so the copy is not torn.
+    Some(unsafe { value.assume_init() })
+  }
+
+  /// Get the latest sample, or `None` if no sample was published yet.
+  ///
+  /// This spins while a sample is being published, so it must not be called from a context which
+  /// preempts the writer, e.g. an interrupt handler with a higher priority than the acquisition task.
+  pub fn latest(&self) -> Option<V> {
+    loop {
+      if let Some(value) = self.try_latest() {
+        return Some(value)
+      }
+
+      if self.sequence.load(Ordering::Relaxed) == 0 {
+        return None
+      }
+
+      core::hint::spin_loop();
+    }
+  }
+
+  /// Get the number of samples published so far, wrapping around on overflow.
+  pub fn published(&self) -> u32 {
+    self.sequence.load(Ordering::Relaxed) / 2
+  }
+}
+
+impl<V: Copy> Default for LatestSample<V> {
+  fn default() -> Self {
+    Self::new()
+  }
+}
+
+impl<V> fmt::Debug for LatestSample<V> {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    f.debug_struct("LatestSample").field("sequence", &self.sequence.load(Ordering::Relaxed)).finish_non_exhaustive()
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  use crate::{sim::Scl3300Sim, Acceleration, MeasurementMode, Scl3300};
+
+  #[test]
+  fn test_latest_sample() {
+    static LATEST: LatestSample<Acceleration> = LatestSample::new();
+    assert_eq!(LATEST.latest(), None);
+
+    let mut scl = Scl3300::new(Scl3300Sim::new()).start_up(MeasurementMode::FullScale12).unwrap();
+    let acceleration: Acceleration = scl.read().unwrap();
+    assert!(LATEST.publish(acceleration));
+    assert_eq!(LATEST.try_latest(), Some(acceleration));
+
+    let reader = std::thread::spawn(|| {
+      for _ in 0..1000 {
+        let acceleration = LATEST.latest().unwrap();
+        assert_eq!(acceleration.x_raw(), acceleration.y_raw());
+      }
+    });
+    for i in 0..1000 {
+      LATEST.publish(Acceleration::from_raw(i, i, i, MeasurementMode::FullScale12));
+    }
+    reader.join().unwrap();
+
+    assert_eq!(LATEST.published(), 1001);
+  }
+}
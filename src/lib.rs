@@ -37,9 +37,27 @@
 //! #   SpiTransaction::delay(10000),
 //! #   SpiTransaction::transaction_end(),
 //! #
-//! #   // Read status.
+//! #   // Read angle control.
+//! #   SpiTransaction::transaction_start(),
+//! #   SpiTransaction::transfer_in_place(vec![0x30, 0x00, 0x00, 0xD9], vec![25, 0, 18, 157]),
+//! #   SpiTransaction::delay(10000),
+//! #   SpiTransaction::transaction_end(),
+//! #
+//! #   // Read angle control.
+//! #   SpiTransaction::transaction_start(),
+//! #   SpiTransaction::transfer_in_place(vec![0x30, 0x00, 0x00, 0xD9], vec![49, 0, 31, 32]),
+//! #   SpiTransaction::delay(10000),
+//! #   SpiTransaction::transaction_end(),
+//! #
+//! #   // Read WHOAMI.
 //! #   SpiTransaction::transaction_start(),
-//! #   SpiTransaction::transfer_in_place(vec![0x18, 0x00, 0x00, 0xE5], vec![25, 0, 18, 157]),
+//! #   SpiTransaction::transfer_in_place(vec![0x40, 0x00, 0x00, 0x91], vec![49, 0, 31, 32]),
+//! #   SpiTransaction::delay(10000),
+//! #   SpiTransaction::transaction_end(),
+//! #
+//! #   // Switch to bank 0.
+//! #   SpiTransaction::transaction_start(),
+//! #   SpiTransaction::transfer_in_place(vec![0xFC, 0x00, 0x00, 0x73], vec![65, 0, 193, 54]),
 //! #   SpiTransaction::delay(10000),
 //! #   SpiTransaction::transaction_end(),
 //! #
@@ -123,18 +141,20 @@
 //! // Read acceleration, inclination and temperature.
 //! let (acc, inc, temp): (Acceleration, Inclination, Temperature) = inclinometer.read()?;
 //!
-//! # assert_eq!(acc.x_g(), -0.0021666666);
-//! # assert_eq!(acc.y_g(), 0.01175);
-//! # assert_eq!(acc.z_g(), 0.9906667);
-//! println!("Acceleration: {}g, {}g, {}g", acc.x_g(), acc.y_g(), acc.z_g());
+//! # #[cfg(feature = "float")] {
+//! # assert_eq!(acc.x_g().get(), -0.0021666666);
+//! # assert_eq!(acc.y_g().get(), 0.01175);
+//! # assert_eq!(acc.z_g().get(), 0.9906667);
+//! println!("Acceleration: {}, {}, {}", acc.x_g(), acc.y_g(), acc.z_g());
 //! #
-//! # assert_eq!(inc.x_degrees(), 359.87366);
-//! # assert_eq!(inc.y_degrees(), 0.6756592);
-//! # assert_eq!(inc.z_degrees(), 89.30237);
-//! println!("Inclination: {}°, {}°, {}°", inc.x_degrees(), inc.y_degrees(), inc.z_degrees());
+//! # assert_eq!(inc.x_degrees().get(), 359.87366);
+//! # assert_eq!(inc.y_degrees().get(), 0.6756592);
+//! # assert_eq!(inc.z_degrees().get(), 89.30237);
+//! println!("Inclination: {}, {}, {}", inc.x_degrees(), inc.y_degrees(), inc.z_degrees());
 //! #
-//! # assert_eq!(temp.degrees_celsius(), 26.047638);
-//! println!("Temperature: {}°C", temp.degrees_celsius());
+//! # assert_eq!(temp.degrees_celsius().get(), 26.047638);
+//! println!("Temperature: {}", temp.degrees_celsius());
+//! # }
 //!
 //! // Switch to power-down mode.
 //! let inclinometer = inclinometer.power_down()?;
@@ -147,26 +167,149 @@
 //! # Ok(())
 //! # }
 //! ```
-#![cfg_attr(not(test), no_std)]
+#![cfg_attr(not(any(test, feature = "std")), no_std)]
 #![warn(missing_debug_implementations)]
 #![warn(missing_docs)]
 
-use core::{marker::PhantomData, num::NonZeroU32};
+use core::{marker::PhantomData, num::NonZeroU32, ops::RangeInclusive};
 
 use embedded_hal::spi::{Operation as SpiOperation, SpiDevice};
 
 mod error;
 pub use error::*;
-mod frame;
 use frame::*;
+pub mod protocol;
+use protocol::{frame, operation};
+mod state;
+use state::*;
+#[cfg(feature = "stats")]
+mod stats;
+#[cfg(feature = "stats")]
+pub use stats::*;
+mod angle;
+pub use angle::*;
+mod builder;
+pub use builder::*;
+mod calibration;
+pub use calibration::*;
+mod driver_config;
+pub use driver_config::*;
+#[cfg(feature = "test-support")]
+mod fault;
+#[cfg(feature = "test-support")]
+pub use fault::*;
+#[cfg(feature = "observer")]
+mod observer;
+#[cfg(feature = "observer")]
+pub use observer::*;
 pub mod output;
 pub use output::*;
+#[cfg(feature = "float")]
+pub mod units;
+#[cfg(feature = "float")]
+pub use units::*;
 mod measurement_mode;
 pub use measurement_mode::*;
-mod operation;
 use operation::*;
+pub use operation::{Bank, Output};
+pub use protocol::{Command, Register};
+mod startup_config;
+pub use startup_config::*;
 mod off_frame_read;
 pub use off_frame_read::*;
+mod read_plan;
+pub use read_plan::*;
+mod mixed_rate;
+pub use mixed_rate::*;
+mod register;
+pub use register::*;
+mod reader;
+pub use reader::*;
+#[cfg(feature = "float")]
+mod array;
+#[cfg(feature = "float")]
+pub use array::*;
+#[cfg(feature = "async")]
+mod stream;
+#[cfg(feature = "async")]
+pub use stream::*;
+#[cfg(feature = "linux")]
+mod spidev;
+#[cfg(feature = "linux")]
+pub use self::spidev::*;
+mod duty_cycle;
+pub use duty_cycle::*;
+mod sample_timer;
+pub use sample_timer::*;
+#[cfg(feature = "float")]
+mod sensor;
+#[cfg(feature = "float")]
+pub use sensor::*;
+mod split;
+pub use split::*;
+#[cfg(feature = "eh0")]
+mod eh0;
+#[cfg(feature = "eh0")]
+pub use eh0::*;
+pub mod filter;
+#[cfg(all(feature = "libm", feature = "float"))]
+mod fusion;
+#[cfg(feature = "embassy-sync")]
+mod shared;
+#[cfg(feature = "shared-bus")]
+mod shared_bus;
+#[cfg(all(feature = "libm", feature = "float"))]
+pub use fusion::*;
+#[cfg(feature = "embassy-sync")]
+pub use shared::*;
+#[cfg(feature = "portable-atomic")]
+mod latest_sample;
+#[cfg(feature = "portable-atomic")]
+pub use latest_sample::*;
+#[cfg(feature = "float")]
+mod stable;
+mod tick;
+pub use tick::*;
+mod timestamp;
+pub use timestamp::*;
+#[cfg(feature = "float")]
+mod statistics;
+#[cfg(feature = "float")]
+pub use statistics::*;
+mod status;
+pub use status::*;
+mod data_quality;
+pub use data_quality::*;
+mod diagnostics;
+pub use diagnostics::*;
+#[cfg(all(feature = "libm", feature = "float"))]
+mod orientation;
+#[cfg(all(feature = "libm", feature = "float"))]
+pub use orientation::*;
+mod periodic_self_test;
+pub use periodic_self_test::*;
+#[cfg(feature = "float")]
+mod temp_compensation;
+#[cfg(feature = "float")]
+pub use temp_compensation::*;
+mod timings;
+pub use timings::*;
+mod power_down;
+pub use power_down::*;
+mod probe;
+pub use probe::*;
+mod identity;
+pub use identity::*;
+mod token;
+pub use token::*;
+#[cfg(feature = "float")]
+mod tilt_alarm;
+#[cfg(feature = "float")]
+pub use tilt_alarm::*;
+mod vibration;
+pub use vibration::*;
+#[cfg(any(test, feature = "sim"))]
+pub mod sim;
 
 /// [`Scl3300`](crate::Scl3300) operation modes.
 pub mod mode {
@@ -182,40 +325,75 @@ pub mod mode {
   #[derive(Debug)]
   pub struct Normal {
     pub(crate) mode: MeasurementMode,
+    pub(crate) angles_enabled: bool,
   }
 
   /// Marker type for a [`Scl3300`](crate::Scl3300) in power down mode.
   #[derive(Debug)]
   pub struct PowerDown {
-    pub(crate) _0: PhantomData<()>,
+    pub(crate) mode: MeasurementMode,
+    pub(crate) angles_enabled: bool,
+  }
+
+  mod sealed {
+    pub trait Sealed {}
+  }
+
+  /// A [`Scl3300`](crate::Scl3300) operation mode.
+  pub trait Mode: sealed::Sealed {
+    /// The name of the mode, e.g. for logging.
+    const NAME: &'static str;
+  }
+
+  impl sealed::Sealed for Uninitialized {}
+  impl Mode for Uninitialized {
+    const NAME: &'static str = "Uninitialized";
+  }
+
+  impl sealed::Sealed for Normal {}
+  impl Mode for Normal {
+    const NAME: &'static str = "Normal";
+  }
+
+  impl sealed::Sealed for PowerDown {}
+  impl Mode for PowerDown {
+    const NAME: &'static str = "PowerDown";
   }
 }
 pub use mode::*;
 
-const MIN_WAIT_TIME_NS: NonZeroU32 = match NonZeroU32::new(10_000) {
-  Some(v) => v,
-  None => unreachable!(),
-};
-const WAKE_UP_TIME_NS: NonZeroU32 = match NonZeroU32::new(1_000_000) {
-  Some(v) => v,
-  None => unreachable!(),
-};
-const RESET_TIME_NS: NonZeroU32 = match NonZeroU32::new(1_000_000) {
-  Some(v) => v,
-  None => unreachable!(),
-};
+/// The SPI clock frequencies in Hz supported by the inclinometer.
+pub const SPI_FREQUENCY_RANGE_HZ: RangeInclusive<u32> = 100_000..=4_000_000;
+/// The number of samples discarded after waking up from power down mode, since they are not valid yet.
+pub(crate) const WAKE_UP_DISCARD_SAMPLES: u8 = 1;
+/// The `ANG_CTRL` value enabling all angle outputs.
+const ANGLE_OUTPUTS_ENABLED: u16 = 0x1F;
+
+/// Check that the declared SPI bus frequency, if any, is within [`SPI_FREQUENCY_RANGE_HZ`].
+pub(crate) fn check_bus_frequency<E>(bus_frequency_hz: Option<u32>) -> Result<(), Error<E>> {
+  match bus_frequency_hz {
+    Some(frequency_hz) if !SPI_FREQUENCY_RANGE_HZ.contains(&frequency_hz) => Err(Error::BusConfig { frequency_hz }),
+    _ => Ok(()),
+  }
+}
 
 /// An SCL3300 inclinometer.
 #[derive(Debug, Clone)]
 pub struct Scl3300<SPI, MODE = Uninitialized> {
   pub(crate) spi: SPI,
   pub(crate) mode: MODE,
+  pub(crate) state: DriverState,
 }
 
 impl<SPI> Scl3300<SPI> {
   /// Create a new `Scl3300` with the given `SPI` instance.
   pub const fn new(spi: SPI) -> Self {
-    Scl3300 { spi, mode: Uninitialized { _0: PhantomData } }
+    Scl3300 { spi, mode: Uninitialized { _0: PhantomData }, state: DriverState::new() }
+  }
+
+  /// Create a [`Scl3300Builder`] with the given `SPI` instance.
+  pub const fn builder(spi: SPI) -> Scl3300Builder<SPI> {
+    Scl3300Builder::new(spi)
   }
 }
 
@@ -223,56 +401,288 @@ impl<SPI, E, MODE> Scl3300<SPI, MODE>
 where
   SPI: SpiDevice<u8, Error = E>,
 {
-  /// Start the inclinometer in the given [`MeasurementMode`](enum.MeasurementMode.html).
-  fn start_up_inner(mut self, mode: MeasurementMode) -> Result<Scl3300<SPI, Normal>, Error<E>> {
+  /// Start the inclinometer with the given [`StartupConfig`].
+  fn start_up_inner(mut self, config: StartupConfig) -> Result<Scl3300<SPI, Normal>, Error<E>>
+  where
+    MODE: Mode,
+  {
+    self.start_up_in_place(config)?;
+    Ok(self.into_mode(Normal { mode: config.mode, angles_enabled: true }))
+  }
+
+  /// Start the inclinometer with the given [`StartupConfig`] without changing the typestate, so the
+  /// driver is kept after an error.
+  pub(crate) fn start_up_in_place(&mut self, config: StartupConfig) -> Result<(), Error<E>> {
+    self.state.strict = config.strict_validation;
+    self.state.startup_config = config;
+
+    check_bus_frequency(self.state.bus_frequency_hz)?;
+
+    let mut retries = self.state.retries;
+    loop {
+      match self.try_start_up(config) {
+        Ok(()) => return Ok(()),
+        Err(_) if retries > 0 => {
+          retries -= 1;
+          #[cfg(feature = "stats")]
+          {
+            self.state.stats.retries = self.state.stats.retries.wrapping_add(1);
+          }
+        },
+        Err(err) => return Err(err),
+      }
+    }
+  }
+
+  fn try_start_up(&mut self, config: StartupConfig) -> Result<(), Error<E>> {
+    let start_up_attempts = match config.check_depth {
+      CheckDepth::Minimal => None,
+      CheckDepth::Standard | CheckDepth::Extended => Some(config.start_up_attempts()),
+    };
+    self.initialize(config.mode, start_up_attempts)?;
+
+    if config.check_depth == CheckDepth::Extended {
+      let plan: &ReadPlan<(ComponentId, SelfTest, DiagnosticReport, Serial)> = const { &ReadPlan::new() };
+      let (id, self_test, report, _) = plan.execute_raw(self, config.mode)?;
+      if !id.is_correct() {
+        return Err(Error::WrongDevice { found: id.raw() })
+      }
+      if !self_test.is_within_thresholds() {
+        return Err(Error::SelfTestFailed { self_test })
+      }
+      if report.recommended_action() > Action::RereadStatus {
+        return Err(Error::Diagnostics { report })
+      }
+    } else if config.verify_whoami {
+      let plan: &ReadPlan<ComponentId> = const { &ReadPlan::new() };
+      let id = plan.execute_raw(self, config.mode)?;
+      if !id.is_correct() {
+        return Err(Error::WrongDevice { found: id.raw() })
+      }
+    }
+
+    Ok(())
+  }
+
+  /// Software reset the inclinometer without starting it up again.
+  ///
+  /// This can be used to quiesce the inclinometer, e.g. before entering a bootloader.
+  pub fn reset(mut self) -> Result<Scl3300<SPI, Uninitialized>, Error<E>>
+  where
+    MODE: Mode,
+  {
+    self.write(Operation::Reset, NonZeroU32::new(self.state.timings.reset_ns))?;
+    Ok(self.into_mode(Uninitialized { _0: PhantomData }))
+  }
+
+  /// Reset the inclinometer and initialize it in the given [`MeasurementMode`](enum.MeasurementMode.html).
+  ///
+  /// See [`configure_inner`](Scl3300::configure_inner) for `start_up_attempts`.
+  pub(crate) fn initialize(&mut self, mode: MeasurementMode, start_up_attempts: Option<u8>) -> Result<(), Error<E>> {
     // Software reset the device.
-    self.write(Operation::Reset, Some(RESET_TIME_NS))?;
+    self.write(Operation::Reset, NonZeroU32::new(self.state.timings.reset_ns))?;
 
+    self.configure_inner(mode, true, start_up_attempts)
+  }
+
+  /// Select the operation mode and angle outputs, then wait for the inclinometer to settle.
+  pub(crate) fn configure(&mut self, mode: MeasurementMode, angles_enabled: bool) -> Result<(), Error<E>> {
+    self.configure_inner(mode, angles_enabled, Some(mode.start_up_attempts()))
+  }
+
+  /// Select the operation mode and angle outputs, then wait for the inclinometer to settle.
+  ///
+  /// If `start_up_attempts` is given, verify that start-up succeeded, polling up to this many times
+  /// while it is still in progress.
+  fn configure_inner(
+    &mut self,
+    mode: MeasurementMode,
+    angles_enabled: bool,
+    start_up_attempts: Option<u8>,
+  ) -> Result<(), Error<E>> {
     // Select operation mode.
     self.write(Operation::ChangeMode(mode), None)?;
-    // Enable angle outputs.
-    self.write(Operation::EnableAngleOutputs, Some(mode.start_up_wait_time_ns()))?;
+    // Enable or disable angle outputs.
+    let angle_outputs = if angles_enabled { Operation::EnableAngleOutputs } else { Operation::DisableAngleOutputs };
+    self.write(angle_outputs, NonZeroU32::new(self.state.timings.settling_ns(mode)))?;
 
     // Clear status summary.
     self.write(Operation::Read(Output::Status), None)?;
     // Read status summary.
     self.write(Operation::Read(Output::Status), None)?;
-    // Ensure successful start-up.
-    self.transfer(Operation::Read(Output::Status), None)?;
 
-    Ok(Scl3300 { spi: self.spi, mode: Normal { mode } })
+    let Some(attempts) = start_up_attempts else { return Ok(()) };
+
+    if angles_enabled {
+      // Ensure successful start-up and that angle outputs are enabled.
+      self.verify_angle_outputs(attempts)
+    } else {
+      // Ensure successful start-up.
+      self.transfer_after_start_up(Operation::Read(Output::Status), attempts)?;
+      Ok(())
+    }
+  }
+
+  /// Transfer the given operation, repeating it up to `attempts` times while start-up is still in progress.
+  fn transfer_after_start_up(&mut self, operation: Operation, attempts: u8) -> Result<Frame, Error<E>> {
+    let mut res = self.transfer(operation, None);
+
+    for _ in 0..attempts {
+      if !matches!(res, Err(Error::Startup)) {
+        return res
+      }
+
+      res = self.transfer(operation, NonZeroU32::new(START_UP_POLL_INTERVAL_NS));
+    }
+
+    match res {
+      Err(Error::Startup) if attempts > 0 => Err(Error::NeverLeftStartup { attempts }),
+      res => res,
+    }
+  }
+
+  /// Read back the `ANG_CTRL` register and ensure angle outputs are enabled, polling up to `start_up_attempts`
+  /// times while start-up is still in progress.
+  fn verify_angle_outputs(&mut self, start_up_attempts: u8) -> Result<(), Error<E>> {
+    self.transfer_after_start_up(Operation::Read(Output::AngleControl), start_up_attempts)?;
+    let frame = self.transfer(Operation::Read(Output::AngleControl), None)?;
+
+    if frame.data() != ANGLE_OUTPUTS_ENABLED {
+      return Err(Error::AngleEnableFailed)
+    }
+
+    Ok(())
   }
 
   #[inline]
-  fn write(&mut self, operation: Operation, wait_us: Option<NonZeroU32>) -> Result<(), Error<E>> {
-    self.transfer_inner(operation, wait_us)?;
+  pub(crate) fn write(&mut self, operation: Operation, wait_us: Option<NonZeroU32>) -> Result<(), Error<E>> {
+    self.transfer_inner(operation.to_frame(), wait_us)?;
     Ok(())
   }
 
   #[inline]
-  fn transfer(&mut self, operation: Operation, wait_us: Option<NonZeroU32>) -> Result<Frame, Error<E>> {
-    let frame = self.transfer_inner(operation, wait_us)?;
+  pub(crate) fn transfer(&mut self, operation: Operation, wait_us: Option<NonZeroU32>) -> Result<Frame, Error<E>> {
+    self.transfer_frame(operation.to_frame(), wait_us)
+  }
+
+  #[inline]
+  pub(crate) fn transfer_frame(&mut self, frame: Frame, wait_us: Option<NonZeroU32>) -> Result<Frame, Error<E>> {
+    let expected_echo = self.state.expected_echo;
+
+    let frame = self.transfer_inner(frame, wait_us)?;
+    let mut res = frame.check();
+    #[cfg(feature = "stats")]
+    self.state.stats.record(&res);
+    if let Err(Error::ReturnStatus { status, .. }) = &mut res {
+      if self.state.read_status_on_error {
+        let read_status = self.read_status_after_error()?;
+        if read_status.intersects(Status::CRITICAL) {
+          return Err(Error::CriticalStatus { status: read_status })
+        }
+        *status = Some(read_status);
+      }
+    }
+    res?;
+
+    if self.state.strict {
+      if let Some(expected_echo) = expected_echo {
+        if frame.echo() != expected_echo {
+          return Err(Error::ProtocolDesync)
+        }
+      }
+    }
+
+    Ok(frame)
+  }
+
+  /// Read the `STATUS` register after an error return status, which also clears the error.
+  ///
+  /// Only the CRC of the responses is checked, since the first response may still report the error.
+  fn read_status_after_error(&mut self) -> Result<Status, Error<E>> {
+    self.restore_bank_zero()?;
+
+    self.transfer_inner(Operation::Read(Output::Status).to_frame(), None)?.check_crc()?;
+    let frame = self.transfer_inner(Operation::Read(Output::Status).to_frame(), None)?;
     frame.check_crc()?;
 
-    match frame.return_status() {
-      ReturnStatus::StartupInProgress => Err(Error::Startup),
-      ReturnStatus::Error => Err(Error::ReturnStatus),
-      ReturnStatus::NormalOperation => Ok(frame),
+    Ok(Status::from_bits_retain(frame.data()))
+  }
+
+  /// Switch back to bank 0 if an aborted transfer left the inclinometer in another bank.
+  ///
+  /// Only the CRC of the response is checked, since it belongs to the aborted request.
+  pub(crate) fn restore_bank_zero(&mut self) -> Result<(), Error<E>> {
+    if self.state.bank != Bank::Zero {
+      self.transfer_inner(Operation::SwitchBank(Bank::Zero).to_frame(), None)?.check_crc()?;
     }
+
+    Ok(())
   }
 
   #[inline]
-  fn transfer_inner(&mut self, operation: Operation, wait_us: Option<NonZeroU32>) -> Result<Frame, Error<E>> {
-    let mut frame = operation.to_frame();
+  fn transfer_inner(&mut self, mut frame: Frame, wait_us: Option<NonZeroU32>) -> Result<Frame, Error<E>> {
+    self.state.pending_read = None;
+
+    // The response to a reset does not echo the reset request.
+    self.state.expected_echo = if frame.is_reset_request() { None } else { Some(frame.echo()) };
+
+    #[cfg(feature = "log")]
+    log::trace!("request {:02X?}: {:?}", frame.bytes, protocol::decode_request(frame.bytes));
+
+    #[cfg(any(feature = "stats", feature = "log"))]
+    let bank = self.state.bank;
+
+    if frame.bytes == Operation::SwitchBank(Bank::One).to_frame().bytes {
+      self.state.bank = Bank::One;
+    } else if frame.bytes == Operation::SwitchBank(Bank::Zero).to_frame().bytes || frame.is_reset_request() {
+      self.state.bank = Bank::Zero;
+    }
+
+    #[cfg(feature = "log")]
+    if self.state.bank != bank {
+      log::trace!("bank {:?} -> {:?}", bank, self.state.bank);
+    }
+
+    #[cfg(feature = "stats")]
+    if self.state.bank != bank {
+      self.state.stats.bank_switches = self.state.stats.bank_switches.wrapping_add(1);
+    }
+
+    #[cfg(feature = "observer")]
+    let request = frame.bytes;
+
+    #[cfg(feature = "test-support")]
+    if let Some(response) = self.state.fault_injector.as_mut().and_then(|injector| injector.drop_frame()) {
+      frame.bytes = response;
+      return Ok(frame)
+    }
 
     let res = self.spi.transaction(&mut [
       SpiOperation::TransferInPlace(frame.as_bytes_mut()),
-      SpiOperation::DelayNs(wait_us.unwrap_or(MIN_WAIT_TIME_NS).get()),
+      SpiOperation::DelayNs(self.state.timings.delay_ns(wait_us)),
     ]);
     if let Err(err) = res {
       return Err(Error::Spi(err))
     }
 
+    #[cfg(feature = "stats")]
+    {
+      self.state.stats.frames = self.state.stats.frames.wrapping_add(1);
+    }
+
+    #[cfg(feature = "test-support")]
+    if let Some(injector) = &mut self.state.fault_injector {
+      injector.corrupt(&mut frame.bytes);
+    }
+
+    #[cfg(feature = "log")]
+    log::trace!("response {:02X?}: {:?}", frame.bytes, protocol::decode(frame.bytes));
+
+    #[cfg(feature = "observer")]
+    if let Some(observer) = self.state.observer {
+      observer.0.on_transfer(&request, &frame.bytes);
+    }
+
     Ok(frame)
   }
 }
@@ -281,12 +691,27 @@ impl<SPI, E> Scl3300<SPI, Uninitialized>
 where
   SPI: SpiDevice<u8, Error = E>,
 {
-  /// Start the inclinometer in the given [`MeasurementMode`](enum.MeasurementMode.html).
+  /// Start the inclinometer with the given [`StartupConfig`] or [`MeasurementMode`](enum.MeasurementMode.html).
+  ///
+  /// By default, the `WHOAMI` register is verified after start-up and [`Error::WrongDevice`]
+  /// is returned if it does not match.
   ///
   /// When the inclinometer is in power down mode, use [`wake_up`](Scl3300::wake_up) instead.
   #[inline(always)]
-  pub fn start_up(self, mode: MeasurementMode) -> Result<Scl3300<SPI, Normal>, Error<E>> {
-    self.start_up_inner(mode)
+  pub fn start_up(self, config: impl Into<StartupConfig>) -> Result<Scl3300<SPI, Normal>, Error<E>> {
+    self.start_up_inner(config.into())
+  }
+
+  /// Put the inclinometer into power down mode without starting it up first.
+  ///
+  /// This is useful when the state of the inclinometer is unknown, e.g. after a firmware restart.
+  /// The inclinometer is woken up first in case it already is in power down mode, so it is
+  /// afterwards in [`MeasurementMode::FullScale12`] with angle outputs disabled. Responses are not checked.
+  pub fn power_down(mut self) -> Result<Scl3300<SPI, PowerDown>, Error<E>> {
+    self.write(Operation::WakeUp, NonZeroU32::new(self.state.timings.wake_up_ns))?;
+    self.write(Operation::PowerDown, None)?;
+
+    Ok(self.into_mode(PowerDown { mode: MeasurementMode::new(), angles_enabled: false }))
   }
 }
 
@@ -300,6 +725,8 @@ where
   ///
   /// - [`Acceleration`](output::Acceleration)
   /// - [`Inclination`](output::Inclination)
+  /// - [`AccelerationX`](output::AccelerationX), [`AccelerationY`](output::AccelerationY), [`AccelerationZ`](output::AccelerationZ)
+  /// - [`InclinationX`](output::InclinationX), [`InclinationY`](output::InclinationY), [`InclinationZ`](output::InclinationZ)
   /// - [`Temperature`](output::Temperature)
   /// - [`SelfTest`](output::SelfTest)
   /// - [`ComponentId`](output::ComponentId)
@@ -307,27 +734,253 @@ where
   /// - [`Status`](output::Status)
   /// - [`Error1`](output::Error1)
   /// - [`Error2`](output::Error2)
+  /// - [`DiagnosticReport`]
   ///
   /// Additinally, multiple outputs can be read by specifying a tuple.
+  ///
+  /// To avoid recomputing the request frames on every read, see [`ReadPlan`].
   pub fn read<V>(&mut self) -> Result<V, Error<E>>
   where
-    V: OffFrameRead<SPI, E>,
+    V: OffFrameRead,
   {
-    let mut current_bank = Bank::Zero;
+    let plan: &ReadPlan<V> = const { &ReadPlan::new() };
+    plan.execute(self)
+  }
+
+  /// Estimate the time in nanoseconds reading a value occupies the SPI bus, using the
+  /// [declared bus frequency](Scl3300Builder::bus_frequency_hz) and the configured [`Timings`].
+  ///
+  /// Returns `None` if no bus frequency was declared. See [`ReadPlan::estimated_bus_time_ns`].
+  pub fn estimated_read_time_ns<V>(&self) -> Option<u64>
+  where
+    V: OffFrameRead,
+  {
+    let plan: &ReadPlan<V> = const { &ReadPlan::new() };
+    let bus_frequency_hz = self.state.bus_frequency_hz?;
+    Some(plan.estimated_bus_time_ns(bus_frequency_hz, &self.state.timings))
+  }
+
+  /// Read a value `N` times and return the average value.
+  ///
+  /// See [`ReadPlan::execute_averaged`] for details.
+  pub fn read_averaged<V, const N: usize>(&mut self) -> Result<V, Error<E>>
+  where
+    V: OffFrameRead,
+  {
+    let plan: &ReadPlan<V> = const { &ReadPlan::new() };
+    plan.execute_averaged::<_, _, N>(self)
+  }
+
+  /// Create a [`Reader`] which reads multiple values into borrowed targets.
+  pub fn reader<'b>(&mut self) -> Reader<'_, 'b, SPI> {
+    Reader::new(self)
+  }
+
+  /// Read a value without aborting when the inclinometer reports an error return status.
+  ///
+  /// The value is returned together with a [`FlagSummary`] of all outputs which were flagged, so degraded data
+  /// can still be logged. Read the [`Status`](output::Status) to find out why outputs were flagged.
+  pub fn read_lenient<V>(&mut self) -> Result<(V, FlagSummary), Error<E>>
+  where
+    V: OffFrameRead,
+  {
+    let plan: &ReadPlan<V> = const { &ReadPlan::new() };
+    plan.execute_lenient(self)
+  }
+
+  /// Read the raw values of the given outputs into `dest`, in the order the outputs were given.
+  ///
+  /// The values are not decoded, e.g. for forwarding them to be decoded elsewhere.
+  /// See [`ReadPlan::try_from_outputs`] and [`ReadPlan::execute_into`] for details.
+  pub fn read_raw_outputs(&mut self, outputs: &[Output], dest: &mut [u16]) -> Result<(), Error<E>> {
+    ReadPlan::try_from_outputs(outputs)?.execute_into(self, dest)
+  }
+
+  /// Check the `STATUS` register on every n-th read, as configured using
+  /// [`Scl3300Builder::status_check_interval`].
+  pub(crate) fn check_status_periodically(&mut self) -> Result<(), Error<E>> {
+    let interval = self.state.status_check_interval;
+    if interval == 0 {
+      return Ok(())
+    }
+
+    self.state.reads_since_status_check = self.state.reads_since_status_check.saturating_add(1);
+    if self.state.reads_since_status_check < interval {
+      return Ok(())
+    }
+    self.state.reads_since_status_check = 0;
+
+    let plan: &ReadPlan<Status> = const { &ReadPlan::new() };
+    let (status, _) = plan.execute_raw_lenient(self, self.mode.mode)?;
+    if status.intersects(Status::CRITICAL) {
+      return Err(Error::CriticalStatus { status })
+    }
+
+    Ok(())
+  }
+
+  /// Read a value and record the instant at which the read was started using the given `clock`.
+  ///
+  /// See [`read`](Scl3300::read) for supported values.
+  pub fn read_timestamped<V, C>(&mut self, clock: &C) -> Result<Timestamped<V, C::Instant>, Error<E>>
+  where
+    V: OffFrameRead,
+    C: Now,
+  {
+    let timestamp = clock.now();
+    let value = self.read()?;
+    Ok(Timestamped { value, timestamp })
+  }
+
+  /// Enable angle outputs.
+  ///
+  /// Angle outputs are enabled by default during start-up.
+  ///
+  /// The `ANG_CTRL` register is read back afterwards and [`Error::AngleEnableFailed`]
+  /// is returned if angle outputs were not enabled.
+  pub fn enable_angle_outputs(&mut self) -> Result<(), Error<E>> {
+    self.transfer(Operation::EnableAngleOutputs, None)?;
+    self.verify_angle_outputs(0)?;
+    self.mode.angles_enabled = true;
+    Ok(())
+  }
+
+  /// Disable angle outputs.
+  ///
+  /// While angle outputs are disabled, reading an [`Inclination`](output::Inclination)
+  /// returns [`PlanError::AnglesDisabled`].
+  pub fn disable_angle_outputs(&mut self) -> Result<(), Error<E>> {
+    self.transfer(Operation::DisableAngleOutputs, None)?;
+    self.mode.angles_enabled = false;
+    Ok(())
+  }
+
+  /// Check whether angle outputs are enabled.
+  pub fn angle_outputs_enabled(&self) -> bool {
+    self.mode.angles_enabled
+  }
+
+  /// Get the time until outputs are valid, see [`valid_after_ns`](Scl3300::valid_after_ns).
+  #[cfg(feature = "fugit")]
+  pub fn valid_after(&self) -> fugit::NanosDurationU32 {
+    fugit::NanosDurationU32::from_ticks(self.valid_after_ns())
+  }
+
+  /// Get the time in nanoseconds after which outputs fully reflect measurements taken after the last
+  /// start-up, wake-up or mode change.
+  ///
+  /// Settling is already waited for, so this is the [group delay](MeasurementMode::group_delay_ns)
+  /// of the current mode, as returned by [`change_mode`](Scl3300::change_mode).
+  pub fn valid_after_ns(&self) -> u32 {
+    self.mode.mode.group_delay_ns()
+  }
+
+  /// Discard the next `n` samples.
+  ///
+  /// This waits for one output data period per sample, while checking that the inclinometer reports normal operation.
+  /// After waking up from power down mode, samples are discarded automatically.
+  pub fn discard_samples(&mut self, n: u8) -> Result<(), Error<E>> {
+    let period = NonZeroU32::new(self.mode.mode.sample_period_ns());
+
+    for _ in 0..n {
+      self.transfer(Operation::Read(Output::AccelerationX), period)?;
+    }
+
+    Ok(())
+  }
+
+  /// Read the currently selected register bank from the `SELBANK` register.
+  ///
+  /// Unlike other reads, this does not switch banks.
+  pub fn current_bank(&mut self) -> Result<Bank, Error<E>> {
+    self.transfer(Operation::Read(Output::CurrentBank), None)?;
+    let frame = self.transfer(Operation::Read(Output::CurrentBank), None)?;
+    Ok(Bank::from_selbank(frame.data()))
+  }
+
+  /// Resynchronize the register bank, e.g. after an ESD event or when another bus master switched banks.
+  ///
+  /// The bank tracked by the driver is updated to the bank read from the inclinometer, which is then
+  /// switched back to bank 0 if needed. Returns the bank which was read.
+  pub fn resync_bank(&mut self) -> Result<Bank, Error<E>> {
+    let bank = self.current_bank()?;
+    self.state.bank = bank;
+
+    if bank != Bank::Zero {
+      self.transfer(Operation::SwitchBank(Bank::Zero), None)?;
+    }
+
+    Ok(bank)
+  }
 
-    let (_, mut partial) = V::start_read(self, &mut current_bank)?;
+  /// Check that the inclinometer and the SPI bus are still functional, e.g. from a watchdog task.
+  ///
+  /// This reads the `WHOAMI` register using only two frames, checking the CRC, return status and value.
+  /// Unlike other reads, this does not check the `STATUS` register periodically and is not retried.
+  pub fn assert_alive(&mut self) -> Result<(), Error<E>> {
+    let plan: &ReadPlan<ComponentId> = const { &ReadPlan::new() };
+    let id = plan.execute_raw(self, self.mode.mode)?;
+    if !id.is_correct() {
+      return Err(Error::WrongDevice { found: id.raw() })
+    }
 
-    let last_value = self.transfer(Operation::SwitchBank(Bank::Zero), None)?.data();
+    Ok(())
+  }
 
-    partial.finish_read(last_value);
+  /// Change to the given [`MeasurementMode`] and wait for the outputs to settle.
+  ///
+  /// Returns the [group delay](MeasurementMode::group_delay_ns) of the new mode in nanoseconds,
+  /// after which outputs fully reflect measurements taken after the mode change.
+  pub fn change_mode(&mut self, mode: MeasurementMode) -> Result<u32, Error<E>> {
+    self.configure(mode, self.mode.angles_enabled)?;
+    self.mode.mode = mode;
 
-    Ok(partial)
+    Ok(mode.group_delay_ns())
   }
 
   /// Put the inclinometer into power down mode.
   pub fn power_down(mut self) -> Result<Scl3300<SPI, PowerDown>, Error<E>> {
     self.transfer(Operation::PowerDown, None)?;
-    Ok(Scl3300 { spi: self.spi, mode: PowerDown { _0: PhantomData } })
+    Ok(self.into_power_down())
+  }
+
+  pub(crate) fn into_power_down(self) -> Scl3300<SPI, PowerDown> {
+    let Normal { mode, angles_enabled } = self.mode;
+    self.into_mode(PowerDown { mode, angles_enabled })
+  }
+
+  /// Put the inclinometer into power down mode while running `f`, e.g. during a long radio transmission,
+  /// then wake it up into the current mode again.
+  ///
+  /// Like [`wake_up_same_mode`](Scl3300::wake_up_same_mode), this restores the measurement mode and angle outputs
+  /// and discards the first samples after waking up. The result of `f` is returned once the inclinometer is ready.
+  ///
+  /// If powering down or waking up fails, the driver is returned in power down mode inside the [`SleepError`],
+  /// so waking up can be retried, e.g. using [`wake_up_same_mode`](Scl3300::wake_up_same_mode). If powering down
+  /// fails, `f` is not run.
+  // The error contains the driver, which cannot be boxed without an allocator.
+  #[allow(clippy::result_large_err)]
+  pub fn sleep_between<R>(mut self, f: impl FnOnce() -> R) -> Result<(Self, R), SleepError<SPI, E>> {
+    if let Err(error) = self.transfer(Operation::PowerDown, None) {
+      return Err(SleepError { error, scl: self.into_power_down() })
+    }
+
+    let res = f();
+
+    let Normal { mode, angles_enabled } = self.mode;
+    match self.wake_up_into(mode, angles_enabled) {
+      Ok(()) => Ok((self, res)),
+      Err(error) => Err(SleepError { error, scl: self.into_power_down() }),
+    }
+  }
+
+  /// Wake the inclinometer up from power down mode without a software reset, select the given
+  /// operation mode and angle outputs, then discard the first samples.
+  pub(crate) fn wake_up_into(&mut self, mode: MeasurementMode, angles_enabled: bool) -> Result<(), Error<E>> {
+    self.write(Operation::WakeUp, NonZeroU32::new(self.state.timings.wake_up_ns))?;
+    self.configure(mode, angles_enabled)?;
+    self.mode = Normal { mode, angles_enabled };
+    self.discard_samples(WAKE_UP_DISCARD_SAMPLES)
   }
 }
 
@@ -336,16 +989,409 @@ where
   SPI: SpiDevice<u8, Error = E>,
 {
   /// Wake the inclinometer up from power down mode and switch to the given [`MeasurementMode`](enum.MeasurementMode.html).
+  ///
+  /// This performs a software reset and the full start-up sequence, using the [`StartupConfig`] of the
+  /// last start-up with the given mode. To return to the previous mode more quickly, use
+  /// [`wake_up_same_mode`](Scl3300::wake_up_same_mode).
+  ///
+  /// Outputs are valid after [`valid_after_ns`](Scl3300::valid_after_ns) of the returned driver.
   #[inline(always)]
   pub fn wake_up(mut self, mode: MeasurementMode) -> Result<Scl3300<SPI, Normal>, Error<E>> {
-    self.write(Operation::WakeUp, Some(WAKE_UP_TIME_NS))?;
-    self.start_up_inner(mode)
+    self.write(Operation::WakeUp, NonZeroU32::new(self.state.timings.wake_up_ns))?;
+    let config = StartupConfig { mode, strict_validation: self.state.strict, ..self.state.startup_config };
+    let mut scl = self.start_up_inner(config)?;
+    scl.discard_samples(WAKE_UP_DISCARD_SAMPLES)?;
+    Ok(scl)
+  }
+
+  /// Wake the inclinometer up from power down mode into the mode it was in before powering down.
+  ///
+  /// Unlike [`wake_up`](Scl3300::wake_up), this skips the software reset and only restores
+  /// the measurement mode and angle outputs before waiting for the outputs to settle.
+  ///
+  /// Outputs are valid after [`valid_after_ns`](Scl3300::valid_after_ns) of the returned driver.
+  pub fn wake_up_same_mode(self) -> Result<Scl3300<SPI, Normal>, Error<E>> {
+    let PowerDown { mode, angles_enabled } = self.mode;
+
+    let mut scl = self.into_mode(Normal { mode, angles_enabled });
+    scl.wake_up_into(mode, angles_enabled)?;
+    Ok(scl)
   }
 }
 
 impl<SPI, MODE> Scl3300<SPI, MODE> {
+  /// Set an observer which is notified about every SPI frame.
+  #[cfg(feature = "observer")]
+  pub fn with_frame_observer(mut self, observer: &'static dyn FrameObserver) -> Self {
+    self.state.observer = Some(ObserverRef(observer));
+    self
+  }
+
+  /// Set a fault injector which corrupts or drops frames, or remove it.
+  #[cfg(feature = "test-support")]
+  pub fn set_fault_injector(&mut self, fault_injector: Option<FaultInjector>) {
+    self.state.fault_injector = fault_injector;
+  }
+
+  /// Get the fault injector, if any.
+  #[cfg(feature = "test-support")]
+  pub fn fault_injector(&self) -> Option<&FaultInjector> {
+    self.state.fault_injector.as_ref()
+  }
+
+  /// Get the counters for monitoring the SPI link.
+  #[cfg(feature = "stats")]
+  pub fn stats(&self) -> Stats {
+    self.state.stats
+  }
+
+  /// Reset the counters for monitoring the SPI link.
+  #[cfg(feature = "stats")]
+  pub fn reset_stats(&mut self) {
+    self.state.stats = Stats::new();
+  }
+
+  pub(crate) fn into_mode<M: Mode>(self, mode: M) -> Scl3300<SPI, M>
+  where
+    MODE: Mode,
+  {
+    #[cfg(feature = "log")]
+    log::trace!("state {} -> {}", MODE::NAME, M::NAME);
+
+    Scl3300 { spi: self.spi, mode, state: self.state }
+  }
+
+  /// Get a reference to the contained SPI peripheral.
+  pub fn spi(&self) -> &SPI {
+    &self.spi
+  }
+
+  /// Get a mutable reference to the contained SPI peripheral.
+  ///
+  /// This allows reconfiguring the SPI peripheral, e.g. on a shared bus, without releasing it.
+  pub fn spi_mut(&mut self) -> &mut SPI {
+    &mut self.spi
+  }
+
   /// Release the contained SPI peripheral.
   pub fn release(self) -> SPI {
     self.spi
   }
 }
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  use crate::sim::Scl3300Sim;
+
+  #[test]
+  fn test_read_status_on_error() {
+    let mut sim = Scl3300Sim::new();
+
+    let mut scl = Scl3300::builder(&mut sim)
+      .mode(MeasurementMode::FullScale12)
+      .read_status_on_error(true)
+      .build_and_start()
+      .unwrap();
+
+    scl.spi.raise_status(Status::MODE_CHANGE);
+    let res = scl.read::<(Acceleration, Serial)>();
+    assert!(
+      matches!(res, Err(Error::ReturnStatus { status: Some(status), .. }) if status.contains(Status::MODE_CHANGE)),
+      "{res:?}"
+    );
+    assert_eq!(scl.spi().bank(), 0);
+    assert!(scl.read::<(Acceleration, Serial)>().is_ok());
+
+    scl.spi.raise_status(Status::MEM);
+    let res = scl.read::<Acceleration>();
+    assert!(matches!(res, Err(Error::CriticalStatus { status }) if status.contains(Status::MEM)), "{res:?}");
+    assert_eq!(res.unwrap_err().severity(), Severity::Reset);
+  }
+
+  #[cfg(feature = "float")]
+  #[test]
+  fn test_read_timestamped() {
+    let mut sim = Scl3300Sim::new();
+    let mut scl = Scl3300::new(&mut sim).start_up(MeasurementMode::FullScale12).unwrap();
+
+    let ticks = core::cell::Cell::new(41u32);
+    let clock = || {
+      ticks.set(ticks.get() + 1);
+      ticks.get()
+    };
+
+    let acc = scl.read_timestamped::<Acceleration, _>(&clock).unwrap();
+    assert_eq!(acc.timestamp, 42);
+    assert_eq!(acc.map(|acc| acc.z_g()).value, Gs(1.0));
+  }
+
+  #[cfg(feature = "float")]
+  #[test]
+  fn test_wake_up_same_mode() {
+    let mut sim = Scl3300Sim::new();
+    sim.set_inclination(20.0, 0.0, 70.0);
+
+    let mut scl = Scl3300::new(&mut sim).start_up(MeasurementMode::InclinationLowNoise).unwrap();
+    scl.disable_angle_outputs().unwrap();
+
+    let scl = scl.power_down().unwrap();
+    assert!(scl.spi().is_powered_down());
+
+    let mut scl = scl.wake_up_same_mode().unwrap();
+    assert_eq!(scl.valid_after_ns(), MeasurementMode::InclinationLowNoise.group_delay_ns());
+    assert!(!scl.spi.is_powered_down());
+    assert_eq!(scl.spi.mode(), MeasurementMode::InclinationLowNoise);
+    assert!(!scl.angle_outputs_enabled() && !scl.spi.angle_outputs_enabled());
+
+    scl.enable_angle_outputs().unwrap();
+    let (inc, temp) = scl.read::<(Inclination, Temperature)>().unwrap();
+    assert_eq!(inc.x_degrees().get().round(), 20.0);
+    assert_eq!((inc.mode(), temp.mode()), (MeasurementMode::InclinationLowNoise, MeasurementMode::InclinationLowNoise));
+  }
+
+  #[cfg(feature = "float")]
+  #[test]
+  fn test_power_down_uninitialized() {
+    let mut sim = Scl3300Sim::new();
+    sim.set_inclination(20.0, 0.0, 70.0);
+
+    let scl = Scl3300::new(&mut sim).power_down().unwrap();
+    assert!(scl.spi().is_powered_down());
+
+    let mut scl = scl.wake_up(MeasurementMode::Inclination).unwrap();
+    assert!(!scl.spi().is_powered_down());
+    assert_eq!(scl.read::<Inclination>().unwrap().x_degrees().get().round(), 20.0);
+  }
+
+  #[test]
+  fn test_wake_up_keeps_startup_config() {
+    let mut sim = Scl3300Sim::new();
+    sim.set_angle_control_stuck(true);
+
+    let config =
+      StartupConfig::new(MeasurementMode::FullScale12).with_check_depth(CheckDepth::Minimal).with_verify_whoami(false);
+    let scl = Scl3300::new(&mut sim).start_up(config).unwrap().power_down().unwrap();
+    let scl = scl.wake_up(MeasurementMode::Inclination).unwrap();
+    assert_eq!(scl.valid_after_ns(), 15_915_963);
+
+    let mut sim = Scl3300Sim::new();
+    sim.set_start_up_delay_ns(50_000_000);
+
+    let config = StartupConfig::new(MeasurementMode::FullScale12).with_start_up_attempts(60);
+    let scl = Scl3300::new(&mut sim).start_up(config).unwrap().power_down().unwrap();
+    assert!(scl.wake_up(MeasurementMode::FullScale24).is_ok());
+
+    let scl = Scl3300::new(&mut sim).power_down().unwrap();
+    let res = scl.wake_up(MeasurementMode::FullScale24);
+    assert!(matches!(res, Err(Error::NeverLeftStartup { .. })), "{res:?}");
+  }
+
+  #[test]
+  fn test_estimated_read_time() {
+    let mut sim = Scl3300Sim::new();
+
+    let scl = Scl3300::new(&mut sim).start_up(MeasurementMode::Inclination).unwrap();
+    assert_eq!(scl.estimated_read_time_ns::<Acceleration>(), None);
+
+    let scl = Scl3300::builder(scl.release()).bus_frequency_hz(1_000_000).build_and_start().unwrap();
+    let frames = ReadPlan::<Acceleration>::new().frame_count() as u64;
+    assert_eq!(scl.estimated_read_time_ns::<Acceleration>(), Some(frames * (32_000 + 10_000)));
+  }
+
+  #[test]
+  fn test_discard_samples() {
+    let mut sim = Scl3300Sim::new();
+
+    let mut scl = Scl3300::new(&mut sim).start_up(MeasurementMode::FullScale12).unwrap();
+    scl.discard_samples(3).unwrap();
+
+    scl.spi_mut().raise_status(Status::SAT);
+    assert!(matches!(scl.discard_samples(2), Err(Error::ReturnStatus { .. })));
+  }
+
+  #[test]
+  fn test_change_mode() {
+    let mut sim = Scl3300Sim::new();
+
+    let mut scl = Scl3300::new(&mut sim).start_up(MeasurementMode::FullScale12).unwrap();
+    assert_eq!(scl.valid_after_ns(), MeasurementMode::FullScale12.group_delay_ns());
+    assert_eq!(scl.change_mode(MeasurementMode::Inclination).unwrap(), 15_915_963);
+    assert_eq!(scl.valid_after_ns(), 15_915_963);
+    assert_eq!(scl.spi().mode(), MeasurementMode::Inclination);
+    assert!(scl.spi().angle_outputs_enabled());
+    scl.read::<Acceleration>().unwrap();
+
+    assert_eq!(MeasurementMode::FullScale24.settling_time_after_mode_change_ns(), 15_000_000);
+  }
+
+  #[test]
+  fn test_resync_bank() {
+    let mut sim = Scl3300Sim::new();
+    sim.set_serial(1021704154);
+
+    let mut scl = Scl3300::new(&mut sim).start_up(MeasurementMode::Inclination).unwrap();
+    assert_eq!(scl.current_bank().unwrap(), Bank::Zero);
+
+    scl.spi_mut().set_bank(1);
+    assert_eq!(scl.current_bank().unwrap(), Bank::One);
+    assert_eq!(scl.resync_bank().unwrap(), Bank::One);
+    assert_eq!(scl.state.bank, Bank::Zero);
+    assert_eq!(scl.spi().bank(), 0);
+    assert_eq!(scl.read::<Serial>().unwrap().to_u32(), 1021704154);
+  }
+
+  #[test]
+  fn test_assert_alive() {
+    let mut sim = Scl3300Sim::new();
+
+    let mut scl = Scl3300::new(&mut sim).start_up(MeasurementMode::Inclination).unwrap();
+    assert!(scl.assert_alive().is_ok());
+
+    scl.spi_mut().set_component_id(0x42);
+    assert!(matches!(scl.assert_alive(), Err(Error::WrongDevice { found: 0x42 })));
+
+    scl.spi_mut().set_component_id(0xC1);
+    scl.spi_mut().raise_status(Status::CLK);
+    assert!(matches!(scl.assert_alive(), Err(Error::ReturnStatus { .. })));
+  }
+
+  #[test]
+  fn test_reset() {
+    let mut sim = Scl3300Sim::new();
+
+    let scl = Scl3300::new(&mut sim).start_up(MeasurementMode::Inclination).unwrap();
+    let scl = scl.power_down().unwrap().reset().unwrap();
+    assert!(!scl.spi().is_powered_down());
+    assert_eq!(scl.spi().mode(), MeasurementMode::FullScale12);
+
+    assert!(scl.start_up(MeasurementMode::FullScale24).is_ok());
+  }
+
+  #[test]
+  fn test_strict_validation() {
+    let mut sim = Scl3300Sim::new();
+
+    let config = StartupConfig::new(MeasurementMode::FullScale12).with_strict_validation(true);
+    let mut scl = Scl3300::new(&mut sim).start_up(config).unwrap();
+    assert!(scl.read::<Acceleration>().is_ok());
+
+    // Simulate a transfer the driver does not know about.
+    scl.spi_mut().transfer_in_place(&mut [0x14, 0x00, 0x00, 0xEF]).unwrap();
+    assert!(matches!(scl.read::<Acceleration>(), Err(Error::ProtocolDesync)));
+    assert!(scl.read::<Acceleration>().is_ok());
+  }
+
+  #[cfg(feature = "float")]
+  #[test]
+  fn test_angle_outputs() {
+    let mut sim = Scl3300Sim::new();
+    sim.set_inclination(10.0, 0.0, 80.0);
+
+    let mut scl = Scl3300::new(&mut sim).start_up(MeasurementMode::Inclination).unwrap();
+    assert!(scl.angle_outputs_enabled());
+
+    scl.disable_angle_outputs().unwrap();
+    assert!(!scl.spi.angle_outputs_enabled());
+    assert!(matches!(scl.read::<(Acceleration, Inclination)>(), Err(Error::Plan(PlanError::AnglesDisabled))));
+    assert!(scl.read::<Acceleration>().is_ok());
+
+    scl.enable_angle_outputs().unwrap();
+    assert_eq!(scl.read::<Inclination>().unwrap().x_degrees().get().round(), 10.0);
+
+    scl.disable_angle_outputs().unwrap();
+    scl.spi_mut().set_angle_control_stuck(true);
+    assert!(matches!(scl.enable_angle_outputs(), Err(Error::AngleEnableFailed)));
+    assert!(!scl.angle_outputs_enabled());
+  }
+
+  #[test]
+  fn test_angle_enable_failed() {
+    let mut sim = Scl3300Sim::new();
+    sim.set_angle_control_stuck(true);
+
+    let res = Scl3300::new(&mut sim).start_up(MeasurementMode::Inclination);
+    assert!(matches!(res, Err(Error::AngleEnableFailed)));
+  }
+
+  #[test]
+  fn test_sleep_between() {
+    let mut sim = Scl3300Sim::new();
+
+    let mut scl = Scl3300::new(&mut sim).start_up(MeasurementMode::Inclination).unwrap();
+    scl.disable_angle_outputs().unwrap();
+
+    let (mut scl, transmitted) = scl.sleep_between(|| 42).unwrap();
+    assert_eq!(transmitted, 42);
+    assert!(!scl.spi().is_powered_down());
+    assert_eq!(scl.spi().mode(), MeasurementMode::Inclination);
+    assert!(!scl.spi().angle_outputs_enabled());
+    assert!(scl.read::<Acceleration>().is_ok());
+
+    scl.spi_mut().set_start_up_delay_ns(1_000_000_000);
+    let SleepError { error, mut scl } = scl.sleep_between(|| ()).unwrap_err();
+    assert!(matches!(error, Error::NeverLeftStartup { .. }), "{error:?}");
+
+    scl.spi_mut().set_start_up_delay_ns(0);
+    let mut scl = scl.wake_up_same_mode().unwrap();
+    assert!(!scl.angle_outputs_enabled());
+    assert!(scl.read::<Acceleration>().is_ok());
+  }
+
+  #[test]
+  fn test_read_raw_outputs() {
+    let mut sim = Scl3300Sim::new();
+    sim.set_serial(1021704154);
+
+    let mut scl = Scl3300::new(&mut sim).start_up(MeasurementMode::Inclination).unwrap();
+    let mut dest = [0; 2];
+    scl.read_raw_outputs(&[Output::WhoAmI, Output::Serial1], &mut dest).unwrap();
+    assert_eq!(dest, [0x00C1, 63450]);
+    assert!(matches!(scl.read_raw_outputs(&[], &mut []), Err(Error::Plan(PlanError::Empty))));
+  }
+
+  #[cfg(feature = "log")]
+  #[test]
+  fn test_log() {
+    use std::{
+      sync::Mutex,
+      thread::{self, ThreadId},
+    };
+
+    /// Records the messages logged by the test thread.
+    struct Recorder(Mutex<Option<(ThreadId, Vec<String>)>>);
+
+    impl log::Log for Recorder {
+      fn enabled(&self, _metadata: &log::Metadata<'_>) -> bool {
+        true
+      }
+
+      fn log(&self, record: &log::Record<'_>) {
+        if let Some((id, messages)) = &mut *self.0.lock().unwrap() {
+          if *id == thread::current().id() {
+            messages.push(record.args().to_string());
+          }
+        }
+      }
+
+      fn flush(&self) {}
+    }
+
+    static RECORDER: Recorder = Recorder(Mutex::new(None));
+    log::set_logger(&RECORDER).unwrap();
+    log::set_max_level(log::LevelFilter::Trace);
+    *RECORDER.0.lock().unwrap() = Some((thread::current().id(), Vec::new()));
+
+    let mut sim = Scl3300Sim::new();
+    sim.set_serial(1021704154);
+    let mut scl = Scl3300::new(&mut sim).start_up(MeasurementMode::Inclination).unwrap();
+    scl.read::<Serial>().unwrap();
+
+    let (_, messages) = RECORDER.0.lock().unwrap().take().unwrap();
+    assert!(messages.contains(&"request [64, 00, 00, A7]: Some(Read(Serial1))".to_string()), "{messages:#?}");
+    assert!(messages.iter().any(|message| message.starts_with("response [65, F7, DA, 19]: Ok(")), "{messages:#?}");
+    assert!(messages.contains(&"bank Zero -> One".to_string()));
+    assert!(messages.contains(&"state Uninitialized -> Normal".to_string()));
+  }
+}
@@ -1,7 +1,11 @@
-use core::{num::NonZeroU32, ops::RangeInclusive};
+use core::{fmt, num::NonZeroU32, ops::RangeInclusive};
+
+/// The time in nanoseconds between polling the return status while start-up is still in progress.
+pub const START_UP_POLL_INTERVAL_NS: u32 = 1_000_000;
 
 /// A measurement mode.
-#[derive(Debug, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum MeasurementMode {
   /// 1.2g full-scale,
   /// 40 Hz first-order low-pass filter
@@ -28,7 +32,8 @@ impl MeasurementMode {
     Self::FullScale12
   }
 
-  pub(crate) const fn self_test_thresholds(&self) -> RangeInclusive<i16> {
+  /// Get the range of raw [`SelfTest`](crate::SelfTest) values considered valid in this mode.
+  pub const fn self_test_thresholds(&self) -> RangeInclusive<i16> {
     match self {
       Self::FullScale12 => -1800..=1800,
       Self::FullScale24 => -900..=900,
@@ -36,7 +41,8 @@ impl MeasurementMode {
     }
   }
 
-  pub(crate) const fn acceleration_sensitivity(&self) -> u16 {
+  /// Get the acceleration sensitivity in LSB/g.
+  pub const fn acceleration_sensitivity(&self) -> u16 {
     match self {
       Self::FullScale12 => 6000,
       Self::FullScale24 => 3000,
@@ -44,6 +50,68 @@ impl MeasurementMode {
     }
   }
 
+  /// Get the output data rate in Hz.
+  pub const fn output_data_rate_hz(&self) -> u16 {
+    2000
+  }
+
+  /// Get the time between two output samples in nanoseconds.
+  pub const fn sample_period_ns(&self) -> u32 {
+    1_000_000_000 / self.output_data_rate_hz() as u32
+  }
+
+  /// Get the first-order low-pass filter corner frequency in Hz.
+  pub const fn low_pass_filter_hz(&self) -> u16 {
+    match self {
+      Self::FullScale12 => 40,
+      Self::FullScale24 => 70,
+      Self::Inclination | Self::InclinationLowNoise => 10,
+    }
+  }
+
+  /// Get the group delay of the low-pass filter in nanoseconds, i.e. how long outputs lag behind the input.
+  ///
+  /// For a first-order filter, this is `1 / (2π · f_c)`.
+  pub const fn group_delay_ns(&self) -> u32 {
+    (1_000_000_000_000 / (6_283 * self.low_pass_filter_hz() as u64)) as u32
+  }
+
+  /// Get the time in nanoseconds until outputs are valid after changing to this mode, according to the datasheet.
+  ///
+  /// The driver waits for this time when changing modes, see [`Timings::settling_ns`](crate::Timings::settling_ns).
+  pub const fn settling_time_after_mode_change_ns(&self) -> u32 {
+    self.start_up_wait_time_ns().get()
+  }
+
+  /// Get the typical acceleration noise density in µg/√Hz.
+  pub const fn noise_density_ug_per_sqrt_hz(&self) -> u16 {
+    match self {
+      Self::FullScale12 | Self::FullScale24 | Self::Inclination => 37,
+      Self::InclinationLowNoise => 17,
+    }
+  }
+
+  /// Get the time between two output samples.
+  #[cfg(feature = "fugit")]
+  pub const fn sample_period(&self) -> fugit::NanosDurationU32 {
+    fugit::NanosDurationU32::from_ticks(self.sample_period_ns())
+  }
+
+  /// Get the group delay of the low-pass filter, see [`group_delay_ns`](MeasurementMode::group_delay_ns).
+  #[cfg(feature = "fugit")]
+  pub const fn group_delay(&self) -> fugit::NanosDurationU32 {
+    fugit::NanosDurationU32::from_ticks(self.group_delay_ns())
+  }
+
+  /// Get the maximum number of times the return status is polled while start-up is still in progress
+  /// after the settling time, see [`StartupConfig::with_start_up_attempts`](crate::StartupConfig::with_start_up_attempts).
+  ///
+  /// Polling is spread over up to another [settling time](MeasurementMode::settling_time_after_mode_change_ns),
+  /// with [`START_UP_POLL_INTERVAL_NS`] between attempts.
+  pub const fn start_up_attempts(&self) -> u8 {
+    (self.start_up_wait_time_ns().get() / START_UP_POLL_INTERVAL_NS) as u8
+  }
+
   pub(crate) const fn start_up_wait_time_ns(&self) -> NonZeroU32 {
     const T_25_MS: NonZeroU32 = match NonZeroU32::new(25_000_000) {
       Some(v) => v,
@@ -65,3 +133,37 @@ impl MeasurementMode {
     }
   }
 }
+
+/// A raw measurement mode does not correspond to any [`MeasurementMode`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct InvalidMode(pub u8);
+
+impl fmt::Display for InvalidMode {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(f, "invalid measurement mode {}", self.0)
+  }
+}
+
+impl From<MeasurementMode> for u8 {
+  /// Convert the measurement mode to its value in the `MODE` register.
+  #[inline]
+  fn from(mode: MeasurementMode) -> Self {
+    mode as u8
+  }
+}
+
+impl TryFrom<u8> for MeasurementMode {
+  type Error = InvalidMode;
+
+  /// Convert a value of the `MODE` register to a measurement mode, e.g. when received from a log.
+  #[inline]
+  fn try_from(mode: u8) -> Result<Self, Self::Error> {
+    Ok(match mode {
+      0 => Self::FullScale12,
+      1 => Self::FullScale24,
+      2 => Self::Inclination,
+      3 => Self::InclinationLowNoise,
+      mode => return Err(InvalidMode(mode)),
+    })
+  }
+}
@@ -0,0 +1,84 @@
+use embedded_hal::spi::SpiDevice;
+
+use crate::{Error, Normal, OffFrameRead, ReadPlan, Scl3300};
+
+/// Reads `F` every cycle and `S` only every n-th cycle.
+///
+/// Both values are read in a single pipelined sequence of frames, e.g. to read
+/// [`Acceleration`](crate::Acceleration) at a high rate and [`Temperature`](crate::Temperature) at a low rate:
+///
+/// ```rust
+/// use scl3300::{Acceleration, MixedRatePlan, Temperature};
+///
+/// let plan = MixedRatePlan::<Acceleration, Temperature>::new(10);
+/// ```
+#[derive(Debug, Clone)]
+pub struct MixedRatePlan<F, S> {
+  fast: ReadPlan<F>,
+  both: ReadPlan<(F, S)>,
+  interval: u32,
+  cycle: u32,
+}
+
+impl<F, S> MixedRatePlan<F, S>
+where
+  F: OffFrameRead,
+  (F, S): OffFrameRead,
+{
+  /// Create a plan reading `S` every `interval` cycles, starting with the first cycle.
+  ///
+  /// An `interval` of `0` is treated as `1`.
+  pub const fn new(interval: u32) -> Self {
+    Self { fast: ReadPlan::new(), both: ReadPlan::new(), interval: if interval == 0 { 1 } else { interval }, cycle: 0 }
+  }
+
+  /// Get the number of cycles between reads of `S`.
+  pub const fn interval(&self) -> u32 {
+    self.interval
+  }
+
+  /// Execute the next cycle.
+  ///
+  /// Returns `F`, and `S` if it was read in this cycle. If the read fails, the cycle is repeated next time.
+  pub fn execute<SPI, E>(&mut self, scl: &mut Scl3300<SPI, Normal>) -> Result<(F, Option<S>), Error<E>>
+  where
+    SPI: SpiDevice<u8, Error = E>,
+  {
+    let value = if self.cycle == 0 {
+      let (fast, slow) = self.both.execute(scl)?;
+      (fast, Some(slow))
+    } else {
+      (self.fast.execute(scl)?, None)
+    };
+
+    self.cycle = (self.cycle + 1) % self.interval;
+
+    Ok(value)
+  }
+}
+
+#[cfg(all(test, feature = "float"))]
+mod tests {
+  use super::*;
+
+  use crate::{sim::Scl3300Sim, Acceleration, Gs, MeasurementMode, Temperature};
+
+  #[test]
+  fn test_mixed_rate_plan() {
+    let mut sim = Scl3300Sim::new();
+    sim.set_acceleration(0.0, 0.0, 1.0);
+    sim.set_temperature(25.0);
+
+    let mut scl = Scl3300::new(&mut sim).start_up(MeasurementMode::FullScale12).unwrap();
+    let mut plan = MixedRatePlan::<Acceleration, Temperature>::new(3);
+
+    let slow = (0..6)
+      .map(|_| {
+        let (acc, temp) = plan.execute(&mut scl).unwrap();
+        assert_eq!(acc.z_g(), Gs(1.0));
+        temp.map(|temp| temp.degrees_celsius().get().round())
+      })
+      .collect::<Vec<_>>();
+    assert_eq!(slow, [Some(25.0), None, None, Some(25.0), None, None]);
+  }
+}
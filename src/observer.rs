@@ -0,0 +1,53 @@
+use core::fmt;
+
+/// An observer which is notified about every SPI frame.
+///
+/// This can be used to mirror all traffic to a logger or to record it for protocol conformance testing.
+pub trait FrameObserver: Sync {
+  /// Called after every frame transfer with the request and the received response.
+  ///
+  /// Note that the response belongs to the previous request.
+  fn on_transfer(&self, request: &[u8; 4], response: &[u8; 4]);
+}
+
+/// A reference to a [`FrameObserver`].
+#[derive(Clone, Copy)]
+pub(crate) struct ObserverRef(pub(crate) &'static dyn FrameObserver);
+
+impl fmt::Debug for ObserverRef {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    f.write_str("FrameObserver")
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  use core::sync::atomic::{AtomicU32, Ordering};
+
+  use crate::{sim::Scl3300Sim, Acceleration, MeasurementMode, Scl3300};
+
+  struct Counter(AtomicU32);
+
+  impl FrameObserver for Counter {
+    fn on_transfer(&self, request: &[u8; 4], _response: &[u8; 4]) {
+      if request[0] == 0x04 {
+        self.0.fetch_add(1, Ordering::Relaxed);
+      }
+    }
+  }
+
+  static COUNTER: Counter = Counter(AtomicU32::new(0));
+
+  #[test]
+  fn test_frame_observer() {
+    let mut sim = Scl3300Sim::new();
+
+    let mut scl = Scl3300::new(&mut sim).with_frame_observer(&COUNTER).start_up(MeasurementMode::Inclination).unwrap();
+    scl.read::<Acceleration>().unwrap();
+    scl.read::<(Acceleration, Acceleration)>().unwrap();
+
+    assert_eq!(COUNTER.0.load(Ordering::Relaxed), 3);
+  }
+}
@@ -1,244 +1,193 @@
-use embedded_hal::spi::SpiDevice;
-
 use crate::{
-  operation::{Bank, Operation, Output},
-  output::{Acceleration, ComponentId, Error1, Error2, Inclination, SelfTest, Serial, Status, Temperature},
-  Error, Normal, Scl3300,
+  operation::Output,
+  output::{
+    Acceleration, AccelerationX, AccelerationY, AccelerationZ, ComponentId, Error1, Error2, Inclination, InclinationX,
+    InclinationY, InclinationZ, SelfTest, Serial, Status, Temperature,
+  },
+  Calibration, MeasurementMode, OutputList,
 };
 
-fn transfer_with_bank<SPI, E>(
-  scl: &mut Scl3300<SPI, Normal>,
-  current_bank: &mut Bank,
-  required_bank: Bank,
-  operation: Operation,
-) -> Result<u16, Error<E>>
-where
-  SPI: SpiDevice<u8, Error = E>,
-{
-  let mut last_value1 = None;
-
-  if *current_bank != required_bank {
-    last_value1 = Some(scl.transfer(Operation::SwitchBank(required_bank), None)?.data());
-    *current_bank = required_bank;
-  }
+/// Types implementing this trait can be read using [`Scl3300::read`](crate::Scl3300::read).
+pub trait OffFrameRead: Sized {
+  /// The outputs needed for this value, in the order they are read.
+  const OUTPUTS: OutputList;
 
-  let last_value2 = scl.transfer(operation, None)?.data();
+  /// Decode the value from the raw output values, given in the same order as [`OUTPUTS`](OffFrameRead::OUTPUTS).
+  fn decode(values: &[u16], mode: MeasurementMode) -> Self;
 
-  Ok(last_value1.unwrap_or(last_value2))
+  /// Apply the driver's calibration to the decoded value.
+  ///
+  /// By default, the value is returned unchanged.
+  fn calibrate(self, _calibration: &Calibration) -> Self {
+    self
+  }
 }
 
-/// Types implementing this trait can be read using [`Scl3300::read`](crate::Scl3300::read).
-pub trait OffFrameRead<SPI, E>: Sized
-where
-  SPI: SpiDevice<u8, Error = E>,
-{
-  /// Start an off-frame read.
-  fn start_read(scl: &mut Scl3300<SPI, Normal>, current_bank: &mut Bank) -> Result<(u16, Self), Error<E>>;
+impl OffFrameRead for Acceleration {
+  const OUTPUTS: OutputList = OutputList::new(&[Output::AccelerationX, Output::AccelerationY, Output::AccelerationZ]);
+
+  fn decode(values: &[u16], mode: MeasurementMode) -> Self {
+    Acceleration { x: values[0], y: values[1], z: values[2], mode }
+  }
 
-  /// Finish an off-frame read.
-  fn finish_read(&mut self, last_value: u16);
+  fn calibrate(self, calibration: &Calibration) -> Self {
+    calibration.apply(&self, calibration.acceleration_offset)
+  }
 }
 
-impl<SPI, E> OffFrameRead<SPI, E> for Acceleration
-where
-  SPI: SpiDevice<u8, Error = E>,
-{
-  fn start_read(scl: &mut Scl3300<SPI, Normal>, _current_bank: &mut Bank) -> Result<(u16, Self), Error<E>> {
-    let mut acc = Acceleration { x: 0, y: 0, z: 0, mode: scl.mode.mode };
+impl OffFrameRead for Inclination {
+  const OUTPUTS: OutputList = OutputList::new(&[Output::AngleX, Output::AngleY, Output::AngleZ]);
 
-    let last_value = scl.transfer(Operation::Read(Output::AccelerationX), None)?.data();
-    acc.x = scl.transfer(Operation::Read(Output::AccelerationY), None)?.data();
-    acc.y = scl.transfer(Operation::Read(Output::AccelerationZ), None)?.data();
-    Ok((last_value, acc))
+  fn decode(values: &[u16], mode: MeasurementMode) -> Self {
+    Inclination { x: values[0], y: values[1], z: values[2], mode }
   }
 
-  fn finish_read(&mut self, last_value: u16) {
-    self.z = last_value;
+  fn calibrate(self, calibration: &Calibration) -> Self {
+    calibration.apply(&self, calibration.inclination_offset)
   }
 }
 
-impl<SPI, E> OffFrameRead<SPI, E> for Inclination
-where
-  SPI: SpiDevice<u8, Error = E>,
-{
-  fn start_read(scl: &mut Scl3300<SPI, Normal>, current_bank: &mut Bank) -> Result<(u16, Self), Error<E>> {
-    let mut inc = Inclination { x: 0, y: 0, z: 0 };
-    let last_value = transfer_with_bank(scl, current_bank, Bank::Zero, Operation::Read(Output::AngleX))?;
-    inc.x = scl.transfer(Operation::Read(Output::AngleY), None)?.data();
-    inc.y = scl.transfer(Operation::Read(Output::AngleZ), None)?.data();
-    Ok((last_value, inc))
-  }
+impl OffFrameRead for AccelerationX {
+  const OUTPUTS: OutputList = OutputList::new(&[Output::AccelerationX]);
 
-  fn finish_read(&mut self, last_value: u16) {
-    self.z = last_value;
+  fn decode(values: &[u16], mode: MeasurementMode) -> Self {
+    AccelerationX { raw: values[0], mode }
   }
 }
 
-impl<SPI, E> OffFrameRead<SPI, E> for Temperature
-where
-  SPI: SpiDevice<u8, Error = E>,
-{
-  fn start_read(scl: &mut Scl3300<SPI, Normal>, _current_bank: &mut Bank) -> Result<(u16, Self), Error<E>> {
-    let temp = Temperature { temp: 0 };
-    let last_value = scl.transfer(Operation::Read(Output::Temperature), None)?.data();
-    Ok((last_value, temp))
-  }
+impl OffFrameRead for AccelerationY {
+  const OUTPUTS: OutputList = OutputList::new(&[Output::AccelerationY]);
 
-  fn finish_read(&mut self, last_value: u16) {
-    self.temp = last_value;
+  fn decode(values: &[u16], mode: MeasurementMode) -> Self {
+    AccelerationY { raw: values[0], mode }
   }
 }
 
-impl<SPI, E> OffFrameRead<SPI, E> for SelfTest
-where
-  SPI: SpiDevice<u8, Error = E>,
-{
-  fn start_read(scl: &mut Scl3300<SPI, Normal>, _current_bank: &mut Bank) -> Result<(u16, Self), Error<E>> {
-    let st = SelfTest { sto: 0, mode: scl.mode.mode };
-    let last_value = scl.transfer(Operation::Read(Output::SelfTest), None)?.data();
-    Ok((last_value, st))
-  }
+impl OffFrameRead for AccelerationZ {
+  const OUTPUTS: OutputList = OutputList::new(&[Output::AccelerationZ]);
 
-  fn finish_read(&mut self, last_value: u16) {
-    self.sto = last_value;
+  fn decode(values: &[u16], mode: MeasurementMode) -> Self {
+    AccelerationZ { raw: values[0], mode }
   }
 }
 
-impl<SPI, E> OffFrameRead<SPI, E> for ComponentId
-where
-  SPI: SpiDevice<u8, Error = E>,
-{
-  fn start_read(scl: &mut Scl3300<SPI, Normal>, current_bank: &mut Bank) -> Result<(u16, Self), Error<E>> {
-    let id = ComponentId { id: 0 };
-    let last_value = transfer_with_bank(scl, current_bank, Bank::Zero, Operation::Read(Output::WhoAmI))?;
-    Ok((last_value, id))
+impl OffFrameRead for InclinationX {
+  const OUTPUTS: OutputList = OutputList::new(&[Output::AngleX]);
+
+  fn decode(values: &[u16], mode: MeasurementMode) -> Self {
+    InclinationX { raw: values[0], mode }
   }
+}
+
+impl OffFrameRead for InclinationY {
+  const OUTPUTS: OutputList = OutputList::new(&[Output::AngleY]);
 
-  fn finish_read(&mut self, last_value: u16) {
-    self.id = last_value.to_be_bytes()[1];
+  fn decode(values: &[u16], mode: MeasurementMode) -> Self {
+    InclinationY { raw: values[0], mode }
   }
 }
 
-impl<SPI, E> OffFrameRead<SPI, E> for Serial
-where
-  SPI: SpiDevice<u8, Error = E>,
-{
-  fn start_read(scl: &mut Scl3300<SPI, Normal>, current_bank: &mut Bank) -> Result<(u16, Self), Error<E>> {
-    let mut serial = Serial { part1: 0, part2: 0 };
-    let last_value = transfer_with_bank(scl, current_bank, Bank::One, Operation::Read(Output::Serial1))?;
-    serial.part1 = scl.transfer(Operation::Read(Output::Serial2), None)?.data();
-    Ok((last_value, serial))
+impl OffFrameRead for InclinationZ {
+  const OUTPUTS: OutputList = OutputList::new(&[Output::AngleZ]);
+
+  fn decode(values: &[u16], mode: MeasurementMode) -> Self {
+    InclinationZ { raw: values[0], mode }
   }
+}
 
-  fn finish_read(&mut self, last_value: u16) {
-    self.part2 = last_value;
+impl OffFrameRead for Temperature {
+  const OUTPUTS: OutputList = OutputList::new(&[Output::Temperature]);
+
+  fn decode(values: &[u16], mode: MeasurementMode) -> Self {
+    Temperature { temp: values[0], mode }
   }
 }
 
-impl<SPI, E> OffFrameRead<SPI, E> for Status
-where
-  SPI: SpiDevice<u8, Error = E>,
-{
-  fn start_read(scl: &mut Scl3300<SPI, Normal>, current_bank: &mut Bank) -> Result<(u16, Self), Error<E>> {
-    let status = Self::from_bits_retain(0);
-    let last_value = transfer_with_bank(scl, current_bank, Bank::Zero, Operation::Read(Output::Status))?;
-    Ok((last_value, status))
+impl OffFrameRead for SelfTest {
+  const OUTPUTS: OutputList = OutputList::new(&[Output::SelfTest]);
+
+  fn decode(values: &[u16], mode: MeasurementMode) -> Self {
+    SelfTest { sto: values[0], mode }
   }
+}
+
+impl OffFrameRead for ComponentId {
+  const OUTPUTS: OutputList = OutputList::new(&[Output::WhoAmI]);
 
-  fn finish_read(&mut self, last_value: u16) {
-    *self = Self::from_bits_retain(last_value)
+  fn decode(values: &[u16], _mode: MeasurementMode) -> Self {
+    ComponentId { id: values[0].to_be_bytes()[1] }
   }
 }
 
-impl<SPI, E> OffFrameRead<SPI, E> for Error1
-where
-  SPI: SpiDevice<u8, Error = E>,
-{
-  fn start_read(scl: &mut Scl3300<SPI, Normal>, current_bank: &mut Bank) -> Result<(u16, Self), Error<E>> {
-    let status = Self::from_bits_retain(0);
-    let last_value = transfer_with_bank(scl, current_bank, Bank::Zero, Operation::Read(Output::Error1))?;
-    Ok((last_value, status))
+impl OffFrameRead for Serial {
+  const OUTPUTS: OutputList = OutputList::new(&[Output::Serial1, Output::Serial2]);
+
+  fn decode(values: &[u16], _mode: MeasurementMode) -> Self {
+    Serial { part1: values[0], part2: values[1] }
   }
+}
 
-  fn finish_read(&mut self, last_value: u16) {
-    *self = Self::from_bits_retain(last_value)
+impl OffFrameRead for Status {
+  const OUTPUTS: OutputList = OutputList::new(&[Output::Status]);
+
+  fn decode(values: &[u16], _mode: MeasurementMode) -> Self {
+    Self::from_bits_retain(values[0])
   }
 }
 
-impl<SPI, E> OffFrameRead<SPI, E> for Error2
-where
-  SPI: SpiDevice<u8, Error = E>,
-{
-  fn start_read(scl: &mut Scl3300<SPI, Normal>, current_bank: &mut Bank) -> Result<(u16, Self), Error<E>> {
-    let status = Self::from_bits_retain(0);
-    let last_value = transfer_with_bank(scl, current_bank, Bank::Zero, Operation::Read(Output::Error2))?;
-    Ok((last_value, status))
+impl OffFrameRead for Error1 {
+  const OUTPUTS: OutputList = OutputList::new(&[Output::Error1]);
+
+  fn decode(values: &[u16], _mode: MeasurementMode) -> Self {
+    Self::from_bits_retain(values[0])
   }
+}
+
+impl OffFrameRead for Error2 {
+  const OUTPUTS: OutputList = OutputList::new(&[Output::Error2]);
 
-  fn finish_read(&mut self, last_value: u16) {
-    *self = Self::from_bits_retain(last_value)
+  fn decode(values: &[u16], _mode: MeasurementMode) -> Self {
+    Self::from_bits_retain(values[0])
   }
 }
 
 macro_rules! off_frame_read_tuple {
-  ($($var:ident: $value:ident),+) => {
-    impl<SPI, E, $($value),+> OffFrameRead<SPI, E> for ($($value),+)
+  ($($value:ident),+) => {
+    impl<$($value),+> OffFrameRead for ($($value),+)
     where
-      SPI: SpiDevice<u8, Error = E>,
       $(
-        $value: OffFrameRead<SPI, E>,
+        $value: OffFrameRead,
       )+
     {
-      fn start_read(scl: &mut Scl3300<SPI, Normal>, current_bank: &mut Bank) -> Result<(u16, Self), Error<E>> {
-        off_frame_read_tuple!(@start_read scl, current_bank, last_value, $($var: $value),+);
-        Ok((last_value, ($($var),+)))
+      const OUTPUTS: OutputList = OutputList::EMPTY$(.concat(&$value::OUTPUTS))+;
+
+      #[allow(unused_assignments)]
+      fn decode(values: &[u16], mode: MeasurementMode) -> Self {
+        let mut offset = 0;
+
+        ($({
+          let len = $value::OUTPUTS.len();
+          let value = $value::decode(&values[offset..(offset + len)], mode);
+          offset += len;
+          value
+        }),+)
       }
 
-      off_frame_read_tuple!(@finish $($var),+);
-    }
-  };
-  (@finish $first_var:ident, $($var:ident),+) => {
-    fn finish_read(&mut self, last_value: u16) {
-      let ($(off_frame_read_tuple!(@_ $var)),+, last) = self;
-      last.finish_read(last_value);
+      #[allow(non_snake_case)]
+      fn calibrate(self, calibration: &Calibration) -> Self {
+        let ($($value),+) = self;
+        ($($value.calibrate(calibration)),+)
+      }
     }
   };
-  (@_ $id:ident) => { _ };
-  (@start_read
-    $scl:expr, $current_bank:expr,
-    $last_value:ident,
-    $current_var:ident: $current_value:ident,
-    $($var:ident: $value:ident),+
-  ) => {
-    let ($last_value, mut $current_var) = <$current_value>::start_read($scl, $current_bank)?;
-    off_frame_read_tuple!(@start_read_inner $scl, $current_bank, $current_var: $current_value, $($var: $value),+);
-  };
-  (@start_read_inner
-    $scl:expr, $current_bank:expr,
-    $previous_var:ident: $previous_value:ident,
-    $current_var:ident: $current_value:ident
-  ) => {
-    let (last_value, $current_var) = <$current_value>::start_read($scl, $current_bank)?;
-    $previous_var.finish_read(last_value);
-  };
-  (@start_read_inner
-    $scl:expr, $current_bank:expr,
-    $previous_var:ident: $previous_value:ident,
-    $current_var:ident: $current_value:ident,
-    $($var:ident: $value:ident),+
-  ) => {
-    let (last_value, mut $current_var) = <$current_value>::start_read($scl, $current_bank)?;
-    $previous_var.finish_read(last_value);
-    off_frame_read_tuple!(@start_read_inner $scl, $current_bank, $current_var: $current_value, $($var: $value),+);
-  };
 }
 
-off_frame_read_tuple!(v1: V1, v2: V2);
-off_frame_read_tuple!(v1: V1, v2: V2, v3: V3);
-off_frame_read_tuple!(v1: V1, v2: V2, v3: V3, v4: V4);
-off_frame_read_tuple!(v1: V1, v2: V2, v3: V3, v4: V4, v5: V5);
-off_frame_read_tuple!(v1: V1, v2: V2, v3: V3, v4: V4, v5: V5, v6: V6);
-off_frame_read_tuple!(v1: V1, v2: V2, v3: V3, v4: V4, v5: V5, v6: V6, v7: V7);
-off_frame_read_tuple!(v1: V1, v2: V2, v3: V3, v4: V4, v5: V5, v6: V6, v7: V7, v8: V8);
-off_frame_read_tuple!(v1: V1, v2: V2, v3: V3, v4: V4, v5: V5, v6: V6, v7: V7, v8: V8, v9: V9);
-off_frame_read_tuple!(v1: V1, v2: V2, v3: V3, v4: V4, v5: V5, v6: V6, v7: V7, v8: V8, v9: V9, v10: V10);
+off_frame_read_tuple!(V1, V2);
+off_frame_read_tuple!(V1, V2, V3);
+off_frame_read_tuple!(V1, V2, V3, V4);
+off_frame_read_tuple!(V1, V2, V3, V4, V5);
+off_frame_read_tuple!(V1, V2, V3, V4, V5, V6);
+off_frame_read_tuple!(V1, V2, V3, V4, V5, V6, V7);
+off_frame_read_tuple!(V1, V2, V3, V4, V5, V6, V7, V8);
+off_frame_read_tuple!(V1, V2, V3, V4, V5, V6, V7, V8, V9);
+off_frame_read_tuple!(V1, V2, V3, V4, V5, V6, V7, V8, V9, V10);
@@ -0,0 +1,157 @@
+use libm::sqrtf;
+
+use crate::{Acceleration, Inclination};
+
+/// A rotation represented as a unit quaternion.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Quaternion {
+  /// Scalar part
+  pub w: f32,
+  /// X-component of the vector part
+  pub x: f32,
+  /// Y-component of the vector part
+  pub y: f32,
+  /// Z-component of the vector part
+  pub z: f32,
+}
+
+impl Quaternion {
+  /// The identity rotation.
+  pub const IDENTITY: Self = Self { w: 1.0, x: 0.0, y: 0.0, z: 0.0 };
+
+  /// Rotate the vector `(x, y, z)` by this quaternion.
+  pub fn rotate(&self, (x, y, z): (f32, f32, f32)) -> (f32, f32, f32) {
+    let Self { w, x: qx, y: qy, z: qz } = *self;
+
+    // t = 2 * (q × v)
+    let tx = 2.0 * (qy * z - qz * y);
+    let ty = 2.0 * (qz * x - qx * z);
+    let tz = 2.0 * (qx * y - qy * x);
+
+    // v + w * t + q × t
+    (x + w * tx + (qy * tz - qz * ty), y + w * ty + (qz * tx - qx * tz), z + w * tz + (qx * ty - qy * tx))
+  }
+}
+
+impl Acceleration {
+  /// Get the direction of the measured acceleration as a unit vector.
+  ///
+  /// At rest, this is the direction opposite to gravity in the device frame.
+  /// Returns `None` if the measured acceleration is zero.
+  pub fn gravity_vector(&self) -> Option<(f32, f32, f32)> {
+    let (x, y, z) = (self.x_g().get(), self.y_g().get(), self.z_g().get());
+
+    let norm = sqrtf(x * x + y * y + z * z);
+    if norm == 0.0 {
+      return None
+    }
+
+    Some((x / norm, y / norm, z / norm))
+  }
+
+  /// Get the rotation from the device frame to the gravity frame.
+  ///
+  /// The rotation maps the [`gravity_vector`](Acceleration::gravity_vector) onto the Z-axis,
+  /// without any rotation around the Z-axis. Returns `None` if the measured acceleration is zero.
+  pub fn orientation_quaternion(&self) -> Option<Quaternion> {
+    let (x, y, z) = self.gravity_vector()?;
+
+    // The shortest rotation from `(x, y, z)` onto `(0, 0, 1)` is around their cross product.
+    let w = 1.0 + z;
+    if w <= f32::EPSILON {
+      // Upside down, rotate by 180° around the X-axis.
+      return Some(Quaternion { w: 0.0, x: 1.0, y: 0.0, z: 0.0 })
+    }
+
+    let norm = sqrtf(w * w + y * y + x * x);
+    Some(Quaternion { w: w / norm, x: y / norm, y: -x / norm, z: 0.0 })
+  }
+}
+
+/// A reference plane against which inclination is reported.
+///
+/// This is useful when the inclinometer is mounted on a permanently tilted structure and only the
+/// deviation from the as-built state is of interest.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ReferencePlane {
+  rotation: Quaternion,
+}
+
+impl ReferencePlane {
+  /// Create a reference plane from a rotation from the device frame to the reference frame.
+  pub const fn from_rotation(rotation: Quaternion) -> Self {
+    Self { rotation }
+  }
+
+  /// Capture the reference plane from an acceleration measured in the reference pose.
+  ///
+  /// Returns `None` if the measured acceleration is zero.
+  pub fn capture(acceleration: &Acceleration) -> Option<Self> {
+    acceleration.orientation_quaternion().map(Self::from_rotation)
+  }
+
+  /// Get the rotation from the device frame to the reference frame.
+  pub const fn rotation(&self) -> Quaternion {
+    self.rotation
+  }
+
+  /// Get the inclination relative to this reference plane.
+  ///
+  /// In the reference pose, this is the same as the inclination of a level inclinometer.
+  pub fn inclination(&self, acceleration: &Acceleration) -> Inclination {
+    let (x_g, y_g, z_g) =
+      self.rotation.rotate((acceleration.x_g().get(), acceleration.y_g().get(), acceleration.z_g().get()));
+
+    Inclination {
+      x: Acceleration::acc_to_inc(x_g, y_g, z_g),
+      y: Acceleration::acc_to_inc(y_g, x_g, z_g),
+      z: Acceleration::acc_to_inc(z_g, x_g, y_g),
+      mode: acceleration.mode,
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  use crate::MeasurementMode;
+
+  fn acceleration(x: i16, y: i16, z: i16) -> Acceleration {
+    Acceleration { x: x as u16, y: y as u16, z: z as u16, mode: MeasurementMode::FullScale12 }
+  }
+
+  fn assert_close((x1, y1, z1): (f32, f32, f32), (x2, y2, z2): (f32, f32, f32)) {
+    assert!((x1 - x2).abs() < 1e-6 && (y1 - y2).abs() < 1e-6 && (z1 - z2).abs() < 1e-6, "{:?}", (x1, y1, z1));
+  }
+
+  #[test]
+  fn test_gravity_vector() {
+    assert_eq!(acceleration(0, 0, 6000).gravity_vector(), Some((0.0, 0.0, 1.0)));
+    assert_eq!(acceleration(3000, 0, 0).gravity_vector(), Some((1.0, 0.0, 0.0)));
+    assert_eq!(acceleration(0, 0, 0).gravity_vector(), None);
+  }
+
+  #[test]
+  fn test_orientation_quaternion() {
+    assert_eq!(acceleration(0, 0, 6000).orientation_quaternion(), Some(Quaternion::IDENTITY));
+
+    for acc in [acceleration(6000, 0, 0), acceleration(-1000, 2000, 3000), acceleration(0, 0, -6000)] {
+      let q = acc.orientation_quaternion().unwrap();
+      assert_close(q.rotate(acc.gravity_vector().unwrap()), (0.0, 0.0, 1.0));
+    }
+  }
+
+  #[test]
+  fn test_reference_plane() {
+    let level = acceleration(0, 0, 6000).to_inclination();
+
+    let tilted = acceleration(-1000, 2000, 5000);
+    let plane = ReferencePlane::capture(&tilted).unwrap();
+    assert_eq!(plane.inclination(&tilted), level);
+
+    let plane = ReferencePlane::from_rotation(Quaternion::IDENTITY);
+    assert_eq!(plane.inclination(&tilted), tilted.to_inclination());
+    assert_eq!(ReferencePlane::capture(&acceleration(0, 0, 0)), None);
+  }
+}
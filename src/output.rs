@@ -1,13 +1,90 @@
 //! This module includes all types which can be read using [`Scl3300::read`](crate::Scl3300::read).
 
-use core::fmt;
+use core::{cmp::Ordering, fmt};
 
 use bitflags::bitflags;
 
-use crate::MeasurementMode;
+use crate::{filter::div_round, Angle, InvalidMode, MeasurementMode};
+#[cfg(feature = "float")]
+use crate::{Celsius, Degrees, Gs};
+
+/// Convert a raw inclination value to an angle in the range [0°, 360°).
+#[cfg(feature = "float")]
+#[inline]
+pub const fn raw_to_degrees(raw: u16) -> Degrees {
+  Degrees(raw as f32 / Inclination::FACTOR * 90.0)
+}
+
+/// Convert an angle to a raw inclination value, rounded to the nearest value.
+///
+/// Angles wrap around at 0°/360°, so e.g. -90° is converted to the same value as 270°.
+#[cfg(feature = "float")]
+#[inline]
+pub const fn degrees_to_raw(degrees: Degrees) -> u16 {
+  let raw = degrees.get() / 90.0 * Inclination::FACTOR;
+  let raw = if raw < 0.0 { raw - 0.5 } else { raw + 0.5 };
+  raw as i32 as u16
+}
+
+/// Convert a raw acceleration value to g-force in the given measurement mode.
+#[cfg(feature = "float")]
+#[inline]
+pub const fn raw_to_g(raw: u16, mode: MeasurementMode) -> Gs {
+  Gs((raw as i16) as f32 / mode.acceleration_sensitivity() as f32)
+}
+
+/// Check whether a raw acceleration value is at one of the output rails.
+#[inline]
+const fn is_acceleration_saturated(raw: u16) -> bool {
+  matches!(raw as i16, i16::MIN | i16::MAX)
+}
+
+/// Check whether a raw inclination value is at or beyond ±90°.
+///
+/// Inclination angles are calculated from the acceleration and are therefore limited to ±90°,
+/// so such values indicate clipping.
+#[inline]
+const fn is_inclination_saturated(raw: u16) -> bool {
+  (raw as i16).unsigned_abs() >= 1 << 14
+}
+
+/// A raw value is at one of the output rails, so it does not represent the real measurement.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Saturated;
+
+impl fmt::Display for Saturated {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    "value is saturated".fmt(f)
+  }
+}
+
+/// A measurement axis.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Axis {
+  /// X-axis
+  X,
+  /// Y-axis
+  Y,
+  /// Z-axis
+  Z,
+}
+
+impl Axis {
+  /// All axes in order.
+  pub const ALL: [Axis; 3] = [Axis::X, Axis::Y, Axis::Z];
+}
+
+/// A measurement consisting of X, Y and Z components.
+pub trait Axes: Sized {
+  /// Get the raw values of the X, Y and Z components.
+  fn raw_axes(&self) -> [u16; 3];
+
+  /// Create a copy of this measurement with the given raw X, Y and Z components.
+  fn with_raw_axes(&self, raw: [u16; 3]) -> Self;
+}
 
 /// An acceleration measurement.
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct Acceleration {
   pub(crate) x: u16,
   pub(crate) y: u16,
@@ -16,6 +93,12 @@ pub struct Acceleration {
 }
 
 impl Acceleration {
+  /// Create an acceleration from raw values read in the given measurement mode, e.g. from a log.
+  #[inline(always)]
+  pub const fn from_raw(x: u16, y: u16, z: u16, mode: MeasurementMode) -> Self {
+    Self { x, y, z, mode }
+  }
+
   /// Get the raw acceleration value in the X-direction.
   #[inline(always)]
   pub fn x_raw(&self) -> u16 {
@@ -34,47 +117,103 @@ impl Acceleration {
     self.z
   }
 
-  /// Convert raw acceleration to g-force.
-  fn raw_to_g(&self, acc: u16) -> f32 {
-    (acc as i16) as f32 / self.mode.acceleration_sensitivity() as f32
+  /// Get the measurement mode this value was read in.
+  #[inline(always)]
+  pub fn mode(&self) -> MeasurementMode {
+    self.mode
   }
 
   /// Get the g-force in X-direction.
+  #[cfg(feature = "float")]
   #[inline]
-  pub fn x_g(&self) -> f32 {
-    self.raw_to_g(self.x)
+  pub fn x_g(&self) -> Gs {
+    raw_to_g(self.x, self.mode)
   }
 
   /// Get the g-force in Y-direction.
+  #[cfg(feature = "float")]
   #[inline]
-  pub fn y_g(&self) -> f32 {
-    self.raw_to_g(self.y)
+  pub fn y_g(&self) -> Gs {
+    raw_to_g(self.y, self.mode)
   }
 
   /// Get the g-force in Z-direction.
+  #[cfg(feature = "float")]
+  #[inline]
+  pub fn z_g(&self) -> Gs {
+    raw_to_g(self.z, self.mode)
+  }
+
+  /// Convert raw `(x, y, z)` samples, e.g. from a log, to g-force in the given measurement mode.
+  ///
+  /// The sensitivity is only looked up once for all samples.
+  #[cfg(feature = "float")]
+  #[inline]
+  pub fn convert_slice(raw: &[(u16, u16, u16)], mode: MeasurementMode) -> impl Iterator<Item = (Gs, Gs, Gs)> + '_ {
+    let factor = 1.0 / mode.acceleration_sensitivity() as f32;
+    let to_g = move |raw: u16| Gs((raw as i16) as f32 * factor);
+
+    raw.iter().map(move |&(x, y, z)| (to_g(x), to_g(y), to_g(z)))
+  }
+
+  /// Check whether the acceleration on any axis is at one of the output rails.
+  ///
+  /// Saturation is also reported by the [`Status::SAT`] flag.
+  #[inline]
+  pub fn is_saturated(&self) -> bool {
+    self.raw_axes().into_iter().any(is_acceleration_saturated)
+  }
+
+  #[cfg(feature = "float")]
+  #[inline]
+  fn try_raw_to_g(&self, raw: u16) -> Result<Gs, Saturated> {
+    if is_acceleration_saturated(raw) {
+      return Err(Saturated)
+    }
+
+    Ok(raw_to_g(raw, self.mode))
+  }
+
+  /// Get the g-force in X-direction, unless it is saturated.
+  #[cfg(feature = "float")]
   #[inline]
-  pub fn z_g(&self) -> f32 {
-    self.raw_to_g(self.z)
+  pub fn try_x_g(&self) -> Result<Gs, Saturated> {
+    self.try_raw_to_g(self.x)
+  }
+
+  /// Get the g-force in Y-direction, unless it is saturated.
+  #[cfg(feature = "float")]
+  #[inline]
+  pub fn try_y_g(&self) -> Result<Gs, Saturated> {
+    self.try_raw_to_g(self.y)
+  }
+
+  /// Get the g-force in Z-direction, unless it is saturated.
+  #[cfg(feature = "float")]
+  #[inline]
+  pub fn try_z_g(&self) -> Result<Gs, Saturated> {
+    self.try_raw_to_g(self.z)
   }
 
   /// Convert the acceleration to inclination angles.
-  #[cfg(feature = "libm")]
+  #[cfg(all(feature = "libm", feature = "float"))]
   #[inline]
   pub fn to_inclination(&self) -> Inclination {
-    let x_g = self.x_g();
-    let y_g = self.y_g();
-    let z_g = self.z_g();
+    let x_g = self.x_g().get();
+    let y_g = self.y_g().get();
+    let z_g = self.z_g().get();
 
     Inclination {
       x: Self::acc_to_inc(x_g, y_g, z_g),
       y: Self::acc_to_inc(y_g, x_g, z_g),
       z: Self::acc_to_inc(z_g, x_g, y_g),
+      mode: self.mode,
     }
   }
 
-  #[cfg(feature = "libm")]
+  #[cfg(all(feature = "libm", feature = "float"))]
   #[inline]
-  fn acc_to_inc(a: f32, b: f32, c: f32) -> u16 {
+  pub(crate) fn acc_to_inc(a: f32, b: f32, c: f32) -> u16 {
     use core::f32::consts::FRAC_PI_2;
     use libm::{atan2f, powf, roundf, sqrtf};
 
@@ -82,17 +221,61 @@ impl Acceleration {
   }
 }
 
+impl Axes for Acceleration {
+  #[inline]
+  fn raw_axes(&self) -> [u16; 3] {
+    [self.x, self.y, self.z]
+  }
+
+  #[inline]
+  fn with_raw_axes(&self, [x, y, z]: [u16; 3]) -> Self {
+    Self { x, y, z, mode: self.mode }
+  }
+}
+
+impl From<(u16, u16, u16, MeasurementMode)> for Acceleration {
+  #[inline]
+  fn from((x, y, z, mode): (u16, u16, u16, MeasurementMode)) -> Self {
+    Self::from_raw(x, y, z, mode)
+  }
+}
+
+impl TryFrom<(u16, u16, u16, u8)> for Acceleration {
+  type Error = InvalidMode;
+
+  /// Convert raw values together with a raw measurement mode, see [`MeasurementMode::try_from`].
+  #[inline]
+  fn try_from((x, y, z, mode): (u16, u16, u16, u8)) -> Result<Self, Self::Error> {
+    Ok(Self::from_raw(x, y, z, MeasurementMode::try_from(mode)?))
+  }
+}
+
+impl From<Acceleration> for (u16, u16, u16) {
+  #[inline]
+  fn from(value: Acceleration) -> Self {
+    (value.x, value.y, value.z)
+  }
+}
+
 /// An inclination measurement.
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct Inclination {
   pub(crate) x: u16,
   pub(crate) y: u16,
   pub(crate) z: u16,
+  pub(crate) mode: MeasurementMode,
 }
 
 impl Inclination {
+  #[cfg(feature = "float")]
   pub(crate) const FACTOR: f32 = (1 << 14) as f32;
 
+  /// Create an inclination from raw values read in the given measurement mode, e.g. from a log.
+  #[inline(always)]
+  pub const fn from_raw(x: u16, y: u16, z: u16, mode: MeasurementMode) -> Self {
+    Self { x, y, z, mode }
+  }
+
   /// Get the raw inclination value on the X-axis.
   #[inline(always)]
   pub fn x_raw(&self) -> u16 {
@@ -111,52 +294,526 @@ impl Inclination {
     self.z
   }
 
+  /// Get the measurement mode this value was read in.
+  #[inline(always)]
+  pub fn mode(&self) -> MeasurementMode {
+    self.mode
+  }
+
+  /// Get the inclination angle on the X-axis.
+  #[inline]
+  pub fn x_angle(&self) -> Angle {
+    Angle::from_raw(self.x)
+  }
+
+  /// Get the inclination angle on the Y-axis.
+  #[inline]
+  pub fn y_angle(&self) -> Angle {
+    Angle::from_raw(self.y)
+  }
+
+  /// Get the inclination angle on the Z-axis.
+  #[inline]
+  pub fn z_angle(&self) -> Angle {
+    Angle::from_raw(self.z)
+  }
+
+  /// Get the inclination angles on the X, Y and Z-axes.
   #[inline]
-  fn raw_to_degrees(raw: u16) -> f32 {
-    raw as f32 / Inclination::FACTOR * 90.0
+  pub fn angles(&self) -> [Angle; 3] {
+    [self.x, self.y, self.z].map(Angle::from_raw)
   }
 
   /// Get the inclination angle on the X-axis in degrees.
+  #[cfg(feature = "float")]
   #[inline]
-  pub fn x_degrees(&self) -> f32 {
-    Self::raw_to_degrees(self.x)
+  pub fn x_degrees(&self) -> Degrees {
+    raw_to_degrees(self.x)
   }
 
   /// Get the inclination angle on the Y-axis in degrees.
+  #[cfg(feature = "float")]
   #[inline]
-  pub fn y_degrees(&self) -> f32 {
-    Self::raw_to_degrees(self.y)
+  pub fn y_degrees(&self) -> Degrees {
+    raw_to_degrees(self.y)
   }
 
   /// Get the inclination angle on the Z-axis in degrees.
+  #[cfg(feature = "float")]
+  #[inline]
+  pub fn z_degrees(&self) -> Degrees {
+    raw_to_degrees(self.z)
+  }
+
+  /// Check whether the inclination on any axis is at or beyond the ±90° rails.
+  #[inline]
+  pub fn is_saturated(&self) -> bool {
+    [self.x, self.y, self.z].into_iter().any(is_inclination_saturated)
+  }
+
+  #[cfg(feature = "float")]
+  #[inline]
+  fn try_raw_to_degrees(raw: u16) -> Result<Degrees, Saturated> {
+    if is_inclination_saturated(raw) {
+      return Err(Saturated)
+    }
+
+    Ok(raw_to_degrees(raw))
+  }
+
+  /// Get the inclination angle on the X-axis in degrees, unless it is saturated.
+  #[cfg(feature = "float")]
+  #[inline]
+  pub fn try_x_degrees(&self) -> Result<Degrees, Saturated> {
+    Self::try_raw_to_degrees(self.x)
+  }
+
+  /// Get the inclination angle on the Y-axis in degrees, unless it is saturated.
+  #[cfg(feature = "float")]
+  #[inline]
+  pub fn try_y_degrees(&self) -> Result<Degrees, Saturated> {
+    Self::try_raw_to_degrees(self.y)
+  }
+
+  /// Get the inclination angle on the Z-axis in degrees, unless it is saturated.
+  #[cfg(feature = "float")]
+  #[inline]
+  pub fn try_z_degrees(&self) -> Result<Degrees, Saturated> {
+    Self::try_raw_to_degrees(self.z)
+  }
+
+  /// Convert the inclination angles to the acceleration expected in the given measurement mode
+  /// for a stationary inclinometer, i.e. the components of 1 g gravity on each axis.
+  ///
+  /// This is the inverse of [`Acceleration::to_inclination`].
+  #[cfg(all(feature = "libm", feature = "float"))]
+  #[inline]
+  pub fn to_gravity_components(&self, mode: MeasurementMode) -> Acceleration {
+    let sensitivity = mode.acceleration_sensitivity() as f32;
+    let [x, y, z] = self.angles().map(|angle| libm::roundf(angle.sin() * sensitivity) as i16 as u16);
+
+    Acceleration { x, y, z, mode }
+  }
+
+  #[cfg(feature = "float")]
+  const ARCMIN_PER_LSB: f32 = 90.0 * 60.0 / Self::FACTOR;
+
+  /// Convert a raw value to `units` per 90°, rounded to the nearest value.
+  #[inline]
+  const fn raw_to_arc(raw: u16, units: u64) -> u32 {
+    ((raw as u64 * units + (1 << 13)) >> 14) as u32
+  }
+
+  /// Get the inclination angle on the X-axis in arcminutes.
+  #[cfg(feature = "float")]
+  #[inline]
+  pub fn x_arcmin(&self) -> f32 {
+    self.x as f32 * Self::ARCMIN_PER_LSB
+  }
+
+  /// Get the inclination angle on the X-axis in arcseconds.
+  #[cfg(feature = "float")]
+  #[inline]
+  pub fn x_arcsec(&self) -> f32 {
+    self.x as f32 * Self::ARCMIN_PER_LSB * 60.0
+  }
+
+  /// Get the inclination angle on the X-axis in whole arcminutes, rounded to the nearest value.
+  #[inline]
+  pub const fn x_arcmin_int(&self) -> u32 {
+    Self::raw_to_arc(self.x, 90 * 60)
+  }
+
+  /// Get the inclination angle on the X-axis in whole arcseconds, rounded to the nearest value.
+  #[inline]
+  pub const fn x_arcsec_int(&self) -> u32 {
+    Self::raw_to_arc(self.x, 90 * 60 * 60)
+  }
+
+  /// Get the inclination angle on the Y-axis in arcminutes.
+  #[cfg(feature = "float")]
+  #[inline]
+  pub fn y_arcmin(&self) -> f32 {
+    self.y as f32 * Self::ARCMIN_PER_LSB
+  }
+
+  /// Get the inclination angle on the Y-axis in arcseconds.
+  #[cfg(feature = "float")]
+  #[inline]
+  pub fn y_arcsec(&self) -> f32 {
+    self.y as f32 * Self::ARCMIN_PER_LSB * 60.0
+  }
+
+  /// Get the inclination angle on the Y-axis in whole arcminutes, rounded to the nearest value.
+  #[inline]
+  pub const fn y_arcmin_int(&self) -> u32 {
+    Self::raw_to_arc(self.y, 90 * 60)
+  }
+
+  /// Get the inclination angle on the Y-axis in whole arcseconds, rounded to the nearest value.
+  #[inline]
+  pub const fn y_arcsec_int(&self) -> u32 {
+    Self::raw_to_arc(self.y, 90 * 60 * 60)
+  }
+
+  /// Get the inclination angle on the Z-axis in arcminutes.
+  #[cfg(feature = "float")]
+  #[inline]
+  pub fn z_arcmin(&self) -> f32 {
+    self.z as f32 * Self::ARCMIN_PER_LSB
+  }
+
+  /// Get the inclination angle on the Z-axis in arcseconds.
+  #[cfg(feature = "float")]
+  #[inline]
+  pub fn z_arcsec(&self) -> f32 {
+    self.z as f32 * Self::ARCMIN_PER_LSB * 60.0
+  }
+
+  /// Get the inclination angle on the Z-axis in whole arcminutes, rounded to the nearest value.
+  #[inline]
+  pub const fn z_arcmin_int(&self) -> u32 {
+    Self::raw_to_arc(self.z, 90 * 60)
+  }
+
+  /// Get the inclination angle on the Z-axis in whole arcseconds, rounded to the nearest value.
+  #[inline]
+  pub const fn z_arcsec_int(&self) -> u32 {
+    Self::raw_to_arc(self.z, 90 * 60 * 60)
+  }
+
+  /// Convert raw `(x, y, z)` samples, e.g. from a log, to angles in degrees.
+  #[cfg(feature = "float")]
+  #[inline]
+  pub fn convert_slice(raw: &[(u16, u16, u16)]) -> impl Iterator<Item = (Degrees, Degrees, Degrees)> + '_ {
+    raw.iter().map(|&(x, y, z)| (raw_to_degrees(x), raw_to_degrees(y), raw_to_degrees(z)))
+  }
+
+  /// Get the per-axis angular difference `self - other`.
+  ///
+  /// Differences wrap around at 0°/360°, so e.g. the difference between 1° and 359° is 2°.
+  pub fn delta(&self, other: &Self) -> InclinationDelta {
+    InclinationDelta {
+      x: (self.x as i16).wrapping_sub(other.x as i16),
+      y: (self.y as i16).wrapping_sub(other.y as i16),
+      z: (self.z as i16).wrapping_sub(other.z as i16),
+    }
+  }
+}
+
+/// The difference between two inclination measurements, in the range [-180°, 180°).
+///
+/// See [`Inclination::delta`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct InclinationDelta {
+  x: i16,
+  y: i16,
+  z: i16,
+}
+
+impl InclinationDelta {
+  #[cfg(feature = "float")]
+  #[inline]
+  fn raw_to_degrees(raw: i16) -> Degrees {
+    Degrees(raw as f32 / Inclination::FACTOR * 90.0)
+  }
+
+  /// Get the raw difference on the given axis.
+  #[inline]
+  pub fn raw(&self, axis: Axis) -> i16 {
+    match axis {
+      Axis::X => self.x,
+      Axis::Y => self.y,
+      Axis::Z => self.z,
+    }
+  }
+
+  /// Get the difference on the X-axis in degrees.
+  #[cfg(feature = "float")]
+  #[inline]
+  pub fn x_degrees(&self) -> Degrees {
+    Self::raw_to_degrees(self.x)
+  }
+
+  /// Get the difference on the Y-axis in degrees.
+  #[cfg(feature = "float")]
   #[inline]
-  pub fn z_degrees(&self) -> f32 {
+  pub fn y_degrees(&self) -> Degrees {
+    Self::raw_to_degrees(self.y)
+  }
+
+  /// Get the difference on the Z-axis in degrees.
+  #[cfg(feature = "float")]
+  #[inline]
+  pub fn z_degrees(&self) -> Degrees {
     Self::raw_to_degrees(self.z)
   }
+
+  /// Get the axis with the largest absolute difference together with the raw absolute difference.
+  pub fn max_abs_delta_raw(&self) -> (Axis, u16) {
+    Axis::ALL.into_iter().map(|axis| (axis, self.raw(axis).unsigned_abs())).fold((Axis::X, 0), |max, (axis, diff)| {
+      if diff > max.1 {
+        (axis, diff)
+      } else {
+        max
+      }
+    })
+  }
+
+  /// Get the largest absolute difference of all axes in degrees.
+  #[cfg(feature = "float")]
+  pub fn max_abs_delta(&self) -> Degrees {
+    Degrees(self.max_abs_delta_raw().1 as f32 / Inclination::FACTOR * 90.0)
+  }
+}
+
+impl Axes for Inclination {
+  #[inline]
+  fn raw_axes(&self) -> [u16; 3] {
+    [self.x, self.y, self.z]
+  }
+
+  #[inline]
+  fn with_raw_axes(&self, [x, y, z]: [u16; 3]) -> Self {
+    Self { x, y, z, mode: self.mode }
+  }
+}
+
+impl From<(u16, u16, u16, MeasurementMode)> for Inclination {
+  #[inline]
+  fn from((x, y, z, mode): (u16, u16, u16, MeasurementMode)) -> Self {
+    Self::from_raw(x, y, z, mode)
+  }
+}
+
+impl TryFrom<(u16, u16, u16, u8)> for Inclination {
+  type Error = InvalidMode;
+
+  /// Convert raw values together with a raw measurement mode, see [`MeasurementMode::try_from`].
+  #[inline]
+  fn try_from((x, y, z, mode): (u16, u16, u16, u8)) -> Result<Self, Self::Error> {
+    Ok(Self::from_raw(x, y, z, MeasurementMode::try_from(mode)?))
+  }
+}
+
+impl From<Inclination> for (u16, u16, u16) {
+  #[inline]
+  fn from(value: Inclination) -> Self {
+    (value.x, value.y, value.z)
+  }
+}
+
+macro_rules! single_axis_acceleration {
+  ($($name:ident: $axis:literal),+) => {
+    $(
+      #[doc = concat!("An acceleration measurement of only the ", $axis, "-axis.")]
+      ///
+      /// Reading a single axis needs fewer frames than reading [`Acceleration`].
+      /// The driver's calibration is not applied.
+      #[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+      pub struct $name {
+        pub(crate) raw: u16,
+        pub(crate) mode: MeasurementMode,
+      }
+
+      impl $name {
+        /// Create a value from a raw acceleration value read in the given measurement mode.
+        #[inline(always)]
+        pub const fn from_raw(raw: u16, mode: MeasurementMode) -> Self {
+          Self { raw, mode }
+        }
+
+        /// Get the raw acceleration value.
+        #[inline(always)]
+        pub fn raw(&self) -> u16 {
+          self.raw
+        }
+
+        /// Get the measurement mode this value was read in.
+        #[inline(always)]
+        pub fn mode(&self) -> MeasurementMode {
+          self.mode
+        }
+
+        /// Get the g-force.
+        #[cfg(feature = "float")]
+        #[inline]
+        pub fn g(&self) -> Gs {
+          raw_to_g(self.raw, self.mode)
+        }
+
+        /// Check whether the acceleration is at one of the output rails.
+        #[inline]
+        pub fn is_saturated(&self) -> bool {
+          is_acceleration_saturated(self.raw)
+        }
+
+        /// Get the g-force, unless it is saturated.
+        #[cfg(feature = "float")]
+        #[inline]
+        pub fn try_g(&self) -> Result<Gs, Saturated> {
+          if self.is_saturated() {
+            return Err(Saturated)
+          }
+
+          Ok(self.g())
+        }
+      }
+    )+
+  };
 }
 
+single_axis_acceleration!(AccelerationX: "X", AccelerationY: "Y", AccelerationZ: "Z");
+
+macro_rules! single_axis_inclination {
+  ($($name:ident: $axis:literal),+) => {
+    $(
+      #[doc = concat!("An inclination measurement of only the ", $axis, "-axis.")]
+      ///
+      /// Reading a single axis needs fewer frames than reading [`Inclination`].
+      /// The driver's calibration is not applied.
+      #[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+      pub struct $name {
+        pub(crate) raw: u16,
+        pub(crate) mode: MeasurementMode,
+      }
+
+      impl $name {
+        /// Create a value from a raw inclination value read in the given measurement mode.
+        #[inline(always)]
+        pub const fn from_raw(raw: u16, mode: MeasurementMode) -> Self {
+          Self { raw, mode }
+        }
+
+        /// Get the raw inclination value.
+        #[inline(always)]
+        pub fn raw(&self) -> u16 {
+          self.raw
+        }
+
+        /// Get the measurement mode this value was read in.
+        #[inline(always)]
+        pub fn mode(&self) -> MeasurementMode {
+          self.mode
+        }
+
+        /// Get the inclination angle.
+        #[inline]
+        pub fn angle(&self) -> Angle {
+          Angle::from_raw(self.raw)
+        }
+
+        /// Get the inclination angle in degrees.
+        #[cfg(feature = "float")]
+        #[inline]
+        pub fn degrees(&self) -> Degrees {
+          raw_to_degrees(self.raw)
+        }
+
+        /// Check whether the inclination is at or beyond the ±90° rails.
+        #[inline]
+        pub fn is_saturated(&self) -> bool {
+          is_inclination_saturated(self.raw)
+        }
+
+        /// Get the inclination angle in degrees, unless it is saturated.
+        #[cfg(feature = "float")]
+        #[inline]
+        pub fn try_degrees(&self) -> Result<Degrees, Saturated> {
+          if self.is_saturated() {
+            return Err(Saturated)
+          }
+
+          Ok(self.degrees())
+        }
+      }
+    )+
+  };
+}
+
+single_axis_inclination!(InclinationX: "X", InclinationY: "Y", InclinationZ: "Z");
+
 /// A temperature measurement.
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct Temperature {
   pub(crate) temp: u16,
+  pub(crate) mode: MeasurementMode,
 }
 
 impl Temperature {
+  /// Create a temperature from a raw value read in the given measurement mode.
+  #[inline(always)]
+  pub const fn from_raw(raw: u16, mode: MeasurementMode) -> Self {
+    Self { temp: raw, mode }
+  }
+
   /// Get the raw temperature value.
   #[inline(always)]
   pub fn raw(&self) -> u16 {
     self.temp
   }
 
+  /// Get the measurement mode this value was read in.
+  #[inline(always)]
+  pub fn mode(&self) -> MeasurementMode {
+    self.mode
+  }
+
   /// Get the temperature in °C.
+  #[cfg(feature = "float")]
   #[inline]
-  pub fn degrees_celsius(&self) -> f32 {
-    (self.temp as i16) as f32 / 18.9 - 273.0
+  pub fn degrees_celsius(&self) -> Celsius {
+    Celsius((self.temp as i16) as f32 / 18.9 - 273.0)
   }
+
+  /// Get the temperature in hundredths of a °C, rounded to the nearest value.
+  ///
+  /// This uses the datasheet formula, see [`TemperatureFormula::Datasheet`].
+  #[inline]
+  pub const fn centi_celsius(&self) -> i32 {
+    self.centi_celsius_with(TemperatureFormula::Datasheet)
+  }
+
+  /// Get the temperature in hundredths of a °C using the given formula, rounded to the nearest value.
+  ///
+  /// This only uses integer arithmetic.
+  pub const fn centi_celsius_with(&self, formula: TemperatureFormula) -> i32 {
+    // `raw / 18.9 - offset` in hundredths of a °C is `(raw * 1000 - offset * 189) / 189`.
+    let offset = match formula {
+      TemperatureFormula::Datasheet => 27300,
+      TemperatureFormula::Kelvin => 27315,
+    };
+
+    div_round((self.temp as i16) as i32 * 1000 - offset * 189, 189)
+  }
+}
+
+/// Temperatures are ordered by their raw value interpreted as `i16`, like in all conversions, which increases
+/// with temperature.
+impl PartialOrd for Temperature {
+  fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+    Some(self.cmp(other))
+  }
+}
+
+impl Ord for Temperature {
+  fn cmp(&self, other: &Self) -> Ordering {
+    (self.temp as i16).cmp(&(other.temp as i16)).then((self.mode as u8).cmp(&(other.mode as u8)))
+  }
+}
+
+/// A formula for converting a raw [`Temperature`] value.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum TemperatureFormula {
+  /// The datasheet formula `raw / 18.9 - 273`.
+  #[default]
+  Datasheet,
+  /// Interpret `raw / 18.9` as Kelvin, i.e. `raw / 18.9 - 273.15`.
+  Kelvin,
 }
 
 /// A self-test reading.
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct SelfTest {
   pub(crate) sto: u16,
   pub(crate) mode: MeasurementMode,
@@ -168,14 +825,29 @@ impl SelfTest {
     self.sto
   }
 
+  /// Get the measurement mode this value was read in.
+  pub fn mode(&self) -> MeasurementMode {
+    self.mode
+  }
+
   /// Check if the self-test reading is within the recommended thresholds.
   pub fn is_within_thresholds(&self) -> bool {
     self.mode.self_test_thresholds().contains(&(self.sto as i16))
   }
+
+  /// Get the deviation of the self-test output from its nominal value of `0` as a percentage
+  /// of the threshold limit.
+  ///
+  /// Values with a magnitude above 100 % are outside the recommended thresholds.
+  #[cfg(feature = "float")]
+  #[inline]
+  pub fn deviation_percent(&self) -> f32 {
+    (self.sto as i16) as f32 / *self.mode.self_test_thresholds().end() as f32 * 100.0
+  }
 }
 
 /// A component ID reading.
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct ComponentId {
   pub(crate) id: u8,
 }
@@ -198,7 +870,7 @@ impl ComponentId {
 }
 
 /// A serial number reading.
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct Serial {
   pub(crate) part1: u16,
   pub(crate) part2: u16,
@@ -211,6 +883,48 @@ impl Serial {
     let [b2, b3] = self.part1.to_be_bytes();
     u32::from_be_bytes([b0, b1, b2, b3])
   }
+
+  /// The length of the formatted serial number, e.g. `1021704154B33`.
+  pub const STRING_LEN: usize = 13;
+
+  /// Format the serial number into the given buffer without using [`core::fmt`].
+  ///
+  /// Returns the formatted serial number, which is the same as the [`Display`](fmt::Display) output.
+  pub fn to_string_no_alloc<'b>(&self, buf: &'b mut [u8; Self::STRING_LEN]) -> &'b str {
+    let mut n = self.to_u32();
+    for digit in buf[..10].iter_mut().rev() {
+      *digit = b'0' + (n % 10) as u8;
+      n /= 10;
+    }
+    buf[10..].copy_from_slice(b"B33");
+
+    match core::str::from_utf8(buf) {
+      Ok(s) => s,
+      Err(_) => unreachable!(),
+    }
+  }
+
+  /// Format the serial number as a [`heapless::String`].
+  #[cfg(feature = "heapless")]
+  pub fn to_heapless_string(&self) -> heapless::String<{ Self::STRING_LEN }> {
+    let mut buf = [0; Self::STRING_LEN];
+    let mut s = heapless::String::new();
+    // The capacity is exactly the formatted length.
+    let _ = s.push_str(self.to_string_no_alloc(&mut buf));
+    s
+  }
+}
+
+impl PartialOrd for Serial {
+  fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+    Some(self.cmp(other))
+  }
+}
+
+impl Ord for Serial {
+  fn cmp(&self, other: &Self) -> Ordering {
+    self.to_u32().cmp(&other.to_u32())
+  }
 }
 
 impl fmt::Display for Serial {
@@ -221,6 +935,7 @@ impl fmt::Display for Serial {
 
 bitflags! {
   /// `STATUS` register flags.
+  #[derive(Debug, Clone, Copy, PartialEq, Eq)]
   pub struct Status: u16 {
     /// Digital block error type 1
     const DIGI1          = 0b1000000000;
@@ -247,6 +962,7 @@ bitflags! {
 
 bitflags! {
   /// `ERR_FLAG1` register flags.
+  #[derive(Debug, Clone, Copy, PartialEq, Eq)]
   pub struct Error1: u16 {
     /// Signal saturated at A2D
     const ADC_SAT    = 0b100000000000;
@@ -257,8 +973,48 @@ bitflags! {
   }
 }
 
+impl Error1 {
+  /// Get the C2V channels which saturated, as indicated by [`AFE_SAT`](Error1::AFE_SAT).
+  pub const fn saturated_channels(&self) -> SaturatedChannels {
+    SaturatedChannels((self.bits() & Self::AFE_SAT.bits()) >> 1)
+  }
+}
+
+/// The C2V channels reported as saturated in [`Error1::AFE_SAT`].
+///
+/// Channel `n` corresponds to bit `n + 1` of the `ERR_FLAG1` register.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct SaturatedChannels(u16);
+
+impl SaturatedChannels {
+  /// The number of C2V channels.
+  pub const COUNT: u8 = 10;
+
+  /// Get the raw bitmask, with channel `n` in bit `n`.
+  pub const fn bits(&self) -> u16 {
+    self.0
+  }
+
+  /// Check whether no channel saturated.
+  pub const fn is_empty(&self) -> bool {
+    self.0 == 0
+  }
+
+  /// Check whether the given channel saturated.
+  pub const fn contains(&self, channel: u8) -> bool {
+    channel < Self::COUNT && self.0 & (1 << channel) != 0
+  }
+
+  /// Get an iterator over all saturated channels.
+  pub fn iter(&self) -> impl Iterator<Item = u8> {
+    let channels = *self;
+    (0..Self::COUNT).filter(move |&channel| channels.contains(channel))
+  }
+}
+
 bitflags! {
   /// `ERR_FLAG2` register flags.
+  #[derive(Debug, Clone, Copy, PartialEq, Eq)]
   pub struct Error2: u16 {
     /// External capacitor connection error
     const D_EXT_C      = 0b10000000000000;
@@ -298,36 +1054,215 @@ bitflags! {
 mod tests {
   use super::*;
 
+  #[cfg(feature = "float")]
   #[test]
   fn test_acceleration() {
     let acceleration = Acceleration { x: 0x00DC, y: 0, z: 0, mode: MeasurementMode::FullScale12 };
     let precision = 10000.0;
-    assert_eq!((acceleration.x_g() * precision).round() / precision, 0.0367);
+    assert_eq!((acceleration.x_g().get() * precision).round() / precision, 0.0367);
+  }
+
+  #[test]
+  fn test_from_raw() {
+    let acceleration = Acceleration::from((0x00DC, 1, 2, MeasurementMode::FullScale12));
+    assert_eq!(acceleration, Acceleration::from_raw(0x00DC, 1, 2, MeasurementMode::FullScale12));
+    assert_eq!(<(u16, u16, u16)>::from(acceleration), (0x00DC, 1, 2));
+
+    let inclination = Inclination::try_from((0x4000, 0, 0xC000, 3)).unwrap();
+    assert_eq!(inclination.mode(), MeasurementMode::InclinationLowNoise);
+    assert_eq!(inclination.x_angle(), Angle::RIGHT);
+    assert_eq!(Inclination::try_from((0, 0, 0, 4)), Err(InvalidMode(4)));
+    assert_eq!(u8::from(MeasurementMode::Inclination), 2);
+  }
+
+  #[cfg(all(feature = "libm", feature = "float"))]
+  #[test]
+  fn test_to_gravity_components() {
+    let acceleration = Acceleration::from_raw(3000, (-1500_i16) as u16, 5000, MeasurementMode::FullScale12);
+    let gravity = acceleration.to_inclination().to_gravity_components(MeasurementMode::FullScale12);
+    let magnitude = acceleration.raw_axes().map(|raw| (raw as i16 as f32).powi(2)).iter().sum::<f32>().sqrt();
+    for (expected, actual) in acceleration.raw_axes().into_iter().zip(gravity.raw_axes()) {
+      assert!(((expected as i16) as f32 / magnitude * 6000.0 - (actual as i16) as f32).abs() <= 2.0);
+    }
+
+    let level = Inclination::from_raw(0, 0, 1 << 14, MeasurementMode::Inclination);
+    assert_eq!(level.to_gravity_components(MeasurementMode::Inclination).raw_axes(), [0, 0, 12000]);
+  }
+
+  #[test]
+  fn test_inclination_arc() {
+    let inclination = Inclination { x: 1, y: 0x4000, z: 0xFFFF, mode: MeasurementMode::Inclination };
+    assert_eq!(inclination.x_arcsec_int(), 20);
+    assert_eq!(inclination.y_arcmin_int(), 90 * 60);
+    assert_eq!(inclination.z_arcsec_int(), 360 * 60 * 60 - 20);
+
+    #[cfg(feature = "float")]
+    {
+      assert_eq!(inclination.y_arcmin(), 5400.0);
+      assert!((inclination.x_arcsec() - 19.775_39).abs() < 1e-4);
+    }
+  }
+
+  #[cfg(feature = "float")]
+  #[test]
+  fn test_self_test_deviation_percent() {
+    let self_test = SelfTest { sto: -900i16 as u16, mode: MeasurementMode::FullScale12 };
+    assert_eq!(self_test.deviation_percent(), -50.0);
+
+    let self_test = SelfTest { sto: 990, mode: MeasurementMode::FullScale24 };
+    assert_eq!(self_test.deviation_percent(), 110.0);
   }
 
+  #[cfg(feature = "float")]
+  #[test]
+  fn test_convert_slice() {
+    let raw = [(0x00DC, 0, 0xE890), (3000, 0xFFFF, 6000)];
+    let mode = MeasurementMode::FullScale24;
+
+    for (&(x, y, z), (x_g, y_g, z_g)) in raw.iter().zip(Acceleration::convert_slice(&raw, mode)) {
+      let acceleration = Acceleration { x, y, z, mode };
+      assert!((x_g - acceleration.x_g()).get().abs() < 1e-6);
+      assert!((y_g - acceleration.y_g()).get().abs() < 1e-6);
+      assert!((z_g - acceleration.z_g()).get().abs() < 1e-6);
+    }
+
+    for (&(x, y, z), angles) in raw.iter().zip(Inclination::convert_slice(&raw)) {
+      let inclination = Inclination { x, y, z, mode };
+      assert_eq!(angles, (inclination.x_degrees(), inclination.y_degrees(), inclination.z_degrees()));
+    }
+  }
+
+  #[cfg(feature = "float")]
   #[test]
   fn test_inclination() {
-    let inclination = Inclination { x: 0x0F88, y: 0, z: 0 };
+    let inclination = Inclination { x: 0x0F88, y: 0, z: 0, ..Default::default() };
     let precision = 100.0;
-    assert_eq!((inclination.x_degrees() * precision).round() / precision, 21.84);
+    assert_eq!((inclination.x_degrees().get() * precision).round() / precision, 21.84);
   }
 
+  #[cfg(feature = "float")]
+  #[test]
+  fn test_inclination_delta() {
+    let degrees = |d: f32| (d / 90.0 * Inclination::FACTOR) as i32 as u16;
+    let a = Inclination { x: degrees(1.0), y: degrees(350.0), z: degrees(90.0), ..Default::default() };
+    let b = Inclination { x: degrees(359.0), y: degrees(10.0), z: degrees(85.0), ..Default::default() };
+
+    let delta = a.delta(&b);
+    assert_eq!(delta.x_degrees().get().round(), 2.0);
+    assert_eq!(delta.y_degrees().get().round(), -20.0);
+    assert_eq!(delta.z_degrees().get().round(), 5.0);
+    assert_eq!(delta.max_abs_delta_raw().0, Axis::Y);
+    assert_eq!(delta.max_abs_delta().get().round(), 20.0);
+    assert_eq!(b.delta(&a).max_abs_delta(), delta.max_abs_delta());
+  }
+
+  #[cfg(feature = "float")]
   #[test]
   fn test_temperature() {
-    let temperature = Temperature { temp: 0x161E };
+    let temperature = Temperature { temp: 0x161E, ..Default::default() };
     let precision = 10.0;
-    assert_eq!((temperature.degrees_celsius() * precision).round() / precision, 26.6);
+    assert_eq!((temperature.degrees_celsius().get() * precision).round() / precision, 26.6);
+  }
+
+  #[test]
+  fn test_saturated_channels() {
+    let error = Error1::from_bits_retain(0b100000010010);
+    let channels = error.saturated_channels();
+    assert_eq!(channels.bits(), 0b1001);
+    assert!(channels.contains(3) && !channels.contains(1));
+    assert_eq!(channels.iter().collect::<Vec<_>>(), [0, 3]);
+    assert!(Error1::MEM.saturated_channels().is_empty());
+  }
+
+  #[test]
+  fn test_centi_celsius() {
+    for raw in i16::MIN..=i16::MAX {
+      let temperature = Temperature { temp: raw as u16, ..Default::default() };
+
+      let expected = (raw as f64 / 18.9 - 273.0) * 100.0;
+      assert_eq!(temperature.centi_celsius(), expected.round() as i32, "raw = {raw}");
+
+      let expected = (raw as f64 / 18.9 - 273.15) * 100.0;
+      assert_eq!(temperature.centi_celsius_with(TemperatureFormula::Kelvin), expected.round() as i32, "raw = {raw}");
+    }
   }
 
   #[test]
   fn test_serial() {
     let serial = Serial { part1: 0xF7DA, part2: 0x3CE5 };
     assert_eq!(serial.to_string(), "1021704154B33");
+
+    let mut buf = [0; Serial::STRING_LEN];
+    assert_eq!(serial.to_string_no_alloc(&mut buf), "1021704154B33");
+
+    let serial = Serial { part1: 0xFFFF, part2: 0xFFFF };
+    assert_eq!(serial.to_string_no_alloc(&mut buf), serial.to_string());
+  }
+
+  #[cfg(feature = "float")]
+  #[test]
+  fn test_conversion_functions() {
+    assert_eq!(raw_to_degrees(0x4000), Degrees(90.0));
+    assert_eq!(degrees_to_raw(Degrees(90.0)), 0x4000);
+    assert_eq!(degrees_to_raw(Degrees(-90.0)), degrees_to_raw(Degrees(270.0)));
+    assert_eq!(raw_to_degrees(degrees_to_raw(Degrees(11.25))), Degrees(11.25));
+    assert_eq!(raw_to_g(-3000i16 as u16, MeasurementMode::FullScale24), Gs(-1.0));
+
+    let inc = Inclination { x: 0x1234, ..Default::default() };
+    assert_eq!(inc.x_degrees(), raw_to_degrees(inc.x_raw()));
+  }
+
+  #[cfg(feature = "float")]
+  #[test]
+  fn test_saturated() {
+    let acc = Acceleration { x: 0x7FFF, y: 6000, z: 0x8000, mode: MeasurementMode::FullScale12 };
+    assert!(acc.is_saturated());
+    assert_eq!(acc.try_x_g(), Err(Saturated));
+    assert_eq!(acc.try_y_g(), Ok(Gs(1.0)));
+    assert_eq!(acc.try_z_g(), Err(Saturated));
+
+    let acc = AccelerationX { raw: 0x7FFE, mode: MeasurementMode::FullScale12 };
+    assert!(!acc.is_saturated());
+    assert_eq!(acc.try_g(), Ok(acc.g()));
+
+    let inc = Inclination { x: 0x3FFF, y: 0x4000, z: 0xC000, mode: MeasurementMode::Inclination };
+    assert!(inc.is_saturated());
+    assert_eq!(inc.try_x_degrees(), Ok(raw_to_degrees(0x3FFF)));
+    assert_eq!(inc.try_y_degrees(), Err(Saturated));
+    assert_eq!(inc.try_z_degrees(), Err(Saturated));
+
+    let inc = Inclination { x: 0xC001, y: 0x0000, z: 0x3FFF, mode: MeasurementMode::Inclination };
+    assert!(!inc.is_saturated());
+    assert_eq!(inc.try_x_degrees(), Ok(Degrees(270.0 + 90.0 / 16384.0)));
+
+    let inc = InclinationY { raw: 0x8000, mode: MeasurementMode::Inclination };
+    assert!(inc.is_saturated());
+    assert_eq!(inc.try_degrees(), Err(Saturated));
+    let inc = InclinationY { raw: 0xC001, mode: MeasurementMode::Inclination };
+    assert_eq!(inc.try_degrees(), Ok(inc.degrees()));
+  }
+
+  #[cfg(feature = "float")]
+  #[test]
+  fn test_ordering() {
+    let low = Serial { part1: 0xFFFF, part2: 0x0000 };
+    let high = Serial { part1: 0x0000, part2: 0x0001 };
+    assert!(low < high);
+
+    let cold = Temperature { temp: 5000, ..Default::default() };
+    let warm = Temperature { temp: 6000, ..Default::default() };
+    assert!(cold < warm);
+    assert!(cold.degrees_celsius() < warm.degrees_celsius());
+
+    let negative = Temperature { temp: 0xFF00, ..Default::default() };
+    assert!(negative < cold);
+    assert!(negative.degrees_celsius() < cold.degrees_celsius());
   }
 
   #[test]
   fn test_serial_empty() {
     let serial = Serial { part1: 0, part2: 0 };
     assert_eq!(serial.to_string(), "0000000000B33");
+    assert_eq!(serial.to_string_no_alloc(&mut [0; Serial::STRING_LEN]), "0000000000B33");
   }
 }
@@ -0,0 +1,247 @@
+use embedded_hal::spi::SpiDevice;
+
+use crate::{Error, MeasurementMode, Normal, OffFrameRead, Scl3300, SelfTest};
+
+/// How often a [`PeriodicSelfTest`] reads the self-test output.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SelfTestInterval {
+  /// Every given number of reads.
+  Reads(u32),
+  /// Every given number of milliseconds, as reported by [`PeriodicSelfTest::advance_ms`].
+  Milliseconds(u32),
+}
+
+/// The direction in which the self-test output moved since the previous self-test.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SelfTestTrend {
+  /// This is the first self-test, or the margin changed by at most the configured tolerance.
+  Stable,
+  /// The self-test output moved towards the threshold limits.
+  Degrading,
+  /// The self-test output moved away from the threshold limits.
+  Improving,
+}
+
+/// The result of a periodic self-test.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SelfTestReport {
+  /// The self-test reading.
+  pub self_test: SelfTest,
+  /// The distance of the self-test output to the closest threshold limit, `0` if it is outside the thresholds.
+  pub margin: u16,
+  /// The trend compared to the previous self-test.
+  pub trend: SelfTestTrend,
+}
+
+impl SelfTestReport {
+  /// Check if the self-test reading is within the recommended thresholds.
+  pub fn passed(&self) -> bool {
+    self.self_test.is_within_thresholds()
+  }
+}
+
+/// Interleaves self-test (`STO`) reads into normal reads for continuous diagnostics.
+///
+/// When a self-test is due, the `STO` register is read in the same transaction as the requested values
+/// and a [`SelfTestReport`] is returned alongside them.
+#[derive(Debug, Clone)]
+pub struct PeriodicSelfTest {
+  interval: SelfTestInterval,
+  tolerance: u16,
+  elapsed: u32,
+  previous_margin: Option<u16>,
+}
+
+impl PeriodicSelfTest {
+  /// Create a new periodic self-test with the given interval.
+  ///
+  /// The first self-test is performed with the first read.
+  pub const fn new(interval: SelfTestInterval) -> Self {
+    Self { interval, tolerance: 0, elapsed: u32::MAX, previous_margin: None }
+  }
+
+  /// Set the number of raw counts the margin may change by before a trend is reported.
+  pub const fn with_tolerance(mut self, tolerance: u16) -> Self {
+    self.tolerance = tolerance;
+    self
+  }
+
+  /// Report elapsed time for [`SelfTestInterval::Milliseconds`] intervals.
+  pub fn advance_ms(&mut self, ms: u32) {
+    if let SelfTestInterval::Milliseconds(_) = self.interval {
+      self.elapsed = self.elapsed.saturating_add(ms);
+    }
+  }
+
+  /// Check whether a self-test will be performed with the next read.
+  pub fn is_due(&self) -> bool {
+    match self.interval {
+      SelfTestInterval::Reads(n) | SelfTestInterval::Milliseconds(n) => self.elapsed >= n,
+    }
+  }
+
+  /// Read the given values, and the self-test output if a self-test is due.
+  pub fn read<SPI, E, V>(&mut self, scl: &mut Scl3300<SPI, Normal>) -> Result<(V, Option<SelfTestReport>), Error<E>>
+  where
+    SPI: SpiDevice<u8, Error = E>,
+    V: OffFrameRead,
+    (V, SelfTest): OffFrameRead,
+  {
+    if !self.is_due() {
+      let value = scl.read()?;
+
+      if let SelfTestInterval::Reads(_) = self.interval {
+        self.elapsed = self.elapsed.saturating_add(1);
+      }
+
+      return Ok((value, None))
+    }
+
+    let (value, self_test): (V, SelfTest) = scl.read()?;
+
+    self.elapsed = match self.interval {
+      SelfTestInterval::Reads(_) => 1,
+      SelfTestInterval::Milliseconds(_) => 0,
+    };
+
+    Ok((value, Some(self.report(self_test))))
+  }
+
+  fn report(&mut self, self_test: SelfTest) -> SelfTestReport {
+    let thresholds = self_test.mode().self_test_thresholds();
+    let sto = self_test.raw() as i16;
+
+    let margin = if thresholds.contains(&sto) {
+      (sto as i32 - *thresholds.start() as i32).min(*thresholds.end() as i32 - sto as i32) as u16
+    } else {
+      0
+    };
+
+    let trend = match self.previous_margin {
+      Some(previous) if margin.saturating_add(self.tolerance) < previous => SelfTestTrend::Degrading,
+      Some(previous) if margin > previous.saturating_add(self.tolerance) => SelfTestTrend::Improving,
+      _ => SelfTestTrend::Stable,
+    };
+
+    self.previous_margin = Some(margin);
+
+    SelfTestReport { self_test, margin, trend }
+  }
+}
+
+/// Tracks drift of the self-test output over time.
+///
+/// The first reading is taken as the baseline, later readings are smoothed with a moving average
+/// with a weight of 1/8. A baseline is captured again when the measurement mode changes.
+#[derive(Debug, Clone)]
+pub struct StoTrend {
+  drift_limit: u16,
+  baseline: Option<(i16, MeasurementMode)>,
+  average: i32,
+}
+
+impl StoTrend {
+  /// Create a new tracker which reports drift larger than the given number of raw counts.
+  pub const fn new(drift_limit: u16) -> Self {
+    Self { drift_limit, baseline: None, average: 0 }
+  }
+
+  /// Add a self-test reading and return the current drift.
+  pub fn update(&mut self, self_test: &SelfTest) -> i16 {
+    let sto = self_test.raw() as i16;
+
+    match self.baseline {
+      Some((_, mode)) if mode == self_test.mode() => {
+        self.average += sto as i32 - (self.average >> 3);
+      },
+      _ => {
+        self.baseline = Some((sto, self_test.mode()));
+        self.average = (sto as i32) << 3;
+      },
+    }
+
+    self.drift()
+  }
+
+  /// Get the drift of the smoothed self-test output from the baseline in raw counts.
+  pub fn drift(&self) -> i16 {
+    match self.baseline {
+      Some((baseline, _)) => ((self.average >> 3) - baseline as i32) as i16,
+      None => 0,
+    }
+  }
+
+  /// Check whether the drift exceeds the configured limit.
+  pub fn is_drifting(&self) -> bool {
+    self.drift().unsigned_abs() > self.drift_limit
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  use crate::{sim::Scl3300Sim, Acceleration};
+
+  #[test]
+  fn test_periodic_self_test() {
+    let mut sim = Scl3300Sim::new();
+    sim.set_self_test(100);
+
+    let mut scl = Scl3300::new(&mut sim).start_up(MeasurementMode::FullScale12).unwrap();
+    let mut pst = PeriodicSelfTest::new(SelfTestInterval::Reads(2)).with_tolerance(10);
+
+    let (_, report) = pst.read::<_, _, Acceleration>(&mut scl).unwrap();
+    let report = report.unwrap();
+    assert!(report.passed());
+    assert_eq!(report.margin, 1700);
+    assert_eq!(report.trend, SelfTestTrend::Stable);
+
+    let (_, report) = pst.read::<_, _, Acceleration>(&mut scl).unwrap();
+    assert_eq!(report, None);
+
+    scl.spi_mut().set_self_test(-1500);
+
+    let (_, report) = pst.read::<_, _, Acceleration>(&mut scl).unwrap();
+    let report = report.unwrap();
+    assert!(report.passed());
+    assert_eq!(report.margin, 300);
+    assert_eq!(report.trend, SelfTestTrend::Degrading);
+  }
+
+  #[test]
+  fn test_periodic_self_test_milliseconds() {
+    let mut sim = Scl3300Sim::new();
+    let mut scl = Scl3300::new(&mut sim).start_up(MeasurementMode::FullScale12).unwrap();
+    let mut pst = PeriodicSelfTest::new(SelfTestInterval::Milliseconds(1000));
+
+    assert!(pst.read::<_, _, Acceleration>(&mut scl).unwrap().1.is_some());
+    pst.advance_ms(600);
+    assert!(pst.read::<_, _, Acceleration>(&mut scl).unwrap().1.is_none());
+    pst.advance_ms(600);
+    assert!(pst.is_due());
+    assert!(pst.read::<_, _, Acceleration>(&mut scl).unwrap().1.is_some());
+  }
+
+  #[test]
+  fn test_sto_trend() {
+    let self_test = |sto: i16| SelfTest { sto: sto as u16, mode: MeasurementMode::FullScale12 };
+
+    let mut trend = StoTrend::new(50);
+    assert_eq!(trend.update(&self_test(100)), 0);
+
+    for _ in 0..4 {
+      trend.update(&self_test(110));
+    }
+    assert!(!trend.is_drifting());
+
+    for _ in 0..32 {
+      trend.update(&self_test(-100));
+    }
+    assert!(trend.drift() < -150);
+    assert!(trend.is_drifting());
+
+    trend.update(&SelfTest { sto: 0, mode: MeasurementMode::Inclination });
+    assert_eq!(trend.drift(), 0);
+  }
+}
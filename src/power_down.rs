@@ -0,0 +1,101 @@
+use embedded_hal::spi::SpiDevice;
+
+use crate::{
+  operation::{Operation, Output},
+  Bank, ComponentId, Error, Error2, PowerDown, Scl3300,
+};
+
+/// The result of [`Scl3300::verify`] for a powered down inclinometer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PowerDownReport {
+  /// The component ID read from the `WHOAMI` register.
+  pub component_id: ComponentId,
+  /// The `ERR_FLAG2` register.
+  pub error2: Error2,
+}
+
+impl PowerDownReport {
+  /// Check whether the inclinometer reports being in power down mode.
+  pub fn is_powered_down(&self) -> bool {
+    self.error2.contains(Error2::PD)
+  }
+
+  /// Check whether the bus is alive, i.e. the expected component ID was read.
+  pub fn is_alive(&self) -> bool {
+    self.component_id.is_correct()
+  }
+}
+
+/// An error returned by [`Scl3300::sleep_between`].
+///
+/// The inclinometer is returned in power down mode, so waking it up can be retried.
+#[derive(Debug)]
+pub struct SleepError<SPI, E> {
+  /// The error which occurred while powering down or waking up.
+  pub error: Error<E>,
+  /// The inclinometer.
+  pub scl: Scl3300<SPI, PowerDown>,
+}
+
+impl<SPI, E> Scl3300<SPI, PowerDown>
+where
+  SPI: SpiDevice<u8, Error = E>,
+{
+  /// Check that the inclinometer is in power down mode and still responding.
+  ///
+  /// This only reads the `ERR_FLAG2` and `WHOAMI` registers, which does not wake the inclinometer up.
+  /// Since the `PD` flag causes an error return status, only the CRC and, with strict validation,
+  /// the echoed request of responses are checked.
+  pub fn verify(&mut self) -> Result<PowerDownReport, Error<E>> {
+    if self.state.bank != Bank::Zero {
+      self.transfer_unchecked(Operation::SwitchBank(Bank::Zero))?;
+    }
+
+    self.transfer_unchecked(Operation::Read(Output::Error2))?;
+    let error2 = self.transfer_unchecked(Operation::Read(Output::WhoAmI))?;
+    let component_id = self.transfer_unchecked(Operation::Read(Output::WhoAmI))?;
+
+    Ok(PowerDownReport {
+      component_id: ComponentId { id: component_id as u8 },
+      error2: Error2::from_bits_retain(error2),
+    })
+  }
+
+  fn transfer_unchecked(&mut self, operation: Operation) -> Result<u16, Error<E>> {
+    let expected_echo = self.state.expected_echo;
+
+    let frame = self.transfer_inner(operation.to_frame(), None)?;
+    frame.check_crc()?;
+
+    if self.state.strict && expected_echo.is_some_and(|echo| frame.echo() != echo) {
+      return Err(Error::ProtocolDesync)
+    }
+
+    Ok(frame.data())
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  use crate::{sim::Scl3300Sim, MeasurementMode, StartupConfig};
+
+  #[test]
+  fn test_verify() {
+    let mut sim = Scl3300Sim::new();
+
+    let scl = Scl3300::new(&mut sim)
+      .start_up(StartupConfig::new(MeasurementMode::Inclination).with_strict_validation(true))
+      .unwrap();
+    let mut scl = scl.power_down().unwrap();
+
+    let report = scl.verify().unwrap();
+    assert!(report.is_powered_down());
+    assert!(report.is_alive());
+    assert!(scl.spi().is_powered_down());
+
+    let scl = scl.wake_up_same_mode().unwrap();
+    assert!(!scl.spi().is_powered_down());
+  }
+}
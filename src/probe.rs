@@ -0,0 +1,107 @@
+use embedded_hal::spi::SpiDevice;
+
+use crate::{
+  frame::crc8,
+  operation::{Operation, Output},
+  ComponentId, Error, Scl3300,
+};
+
+/// The probable cause of a response which could not be decoded, see [`Scl3300::probe`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ProbableCause {
+  /// Only zeros were received, e.g. the inclinometer is not powered or `MISO` is not connected.
+  NoResponse,
+  /// Only ones were received, e.g. chip select is not connected or `MISO` is floating.
+  StuckHigh,
+  /// The response is valid when read LSB first, i.e. the bit order is wrong.
+  BitOrder,
+  /// The response is valid with its bytes reversed, i.e. the byte order is wrong.
+  ByteOrder,
+  /// The response is valid when shifted by one bit, i.e. the SPI mode (`CPOL`/`CPHA`) is wrong.
+  SpiMode,
+  /// The response matches none of the known patterns.
+  Unknown,
+}
+
+impl ProbableCause {
+  /// Find the probable cause for a response with a CRC mismatch.
+  pub fn classify(response: [u8; 4]) -> Self {
+    let is_valid = |[b0, b1, b2, crc]: [u8; 4]| crc8([b0, b1, b2]) == crc;
+
+    let word = u32::from_be_bytes(response);
+    let shifted = [word << 1, (word << 1) | 1, word >> 1, (word >> 1) | (1 << 31)];
+
+    if word == 0 {
+      Self::NoResponse
+    } else if word == u32::MAX {
+      Self::StuckHigh
+    } else if is_valid(response.map(u8::reverse_bits)) {
+      Self::BitOrder
+    } else if is_valid(word.swap_bytes().to_be_bytes()) {
+      Self::ByteOrder
+    } else if shifted.into_iter().any(|word| is_valid(word.to_be_bytes())) {
+      Self::SpiMode
+    } else {
+      Self::Unknown
+    }
+  }
+}
+
+impl<SPI, E, MODE> Scl3300<SPI, MODE>
+where
+  SPI: SpiDevice<u8, Error = E>,
+{
+  /// Check the SPI connection by reading the `WHOAMI` register, e.g. during bring-up.
+  ///
+  /// This can be used in any mode, since the return status is not checked. If the response cannot be
+  /// decoded, [`Error::BusMisconfigured`] is returned with the [`ProbableCause`]. If the component ID
+  /// is not the expected one, [`Error::WrongDevice`] is returned.
+  pub fn probe(&mut self) -> Result<(), Error<E>> {
+    let request = Operation::Read(Output::WhoAmI).to_frame();
+    self.transfer_inner(request, None)?;
+    let response = self.transfer_inner(request, None)?;
+
+    if response.check_crc::<E>().is_err() {
+      return Err(Error::BusMisconfigured(ProbableCause::classify(response.bytes)))
+    }
+
+    let id = ComponentId { id: response.data() as u8 };
+    if !id.is_correct() || response.echo() != request.echo() {
+      return Err(Error::WrongDevice { found: id.raw() })
+    }
+
+    Ok(())
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  use crate::{frame::Frame, sim::Scl3300Sim, MeasurementMode};
+
+  #[test]
+  fn test_classify() {
+    let valid = Frame::with_crc([0x41, 0x00, 0xC1]).bytes;
+    let word = u32::from_be_bytes(valid);
+
+    assert_eq!(ProbableCause::classify([0x00; 4]), ProbableCause::NoResponse);
+    assert_eq!(ProbableCause::classify([0xFF; 4]), ProbableCause::StuckHigh);
+    assert_eq!(ProbableCause::classify(valid.map(u8::reverse_bits)), ProbableCause::BitOrder);
+    assert_eq!(ProbableCause::classify(word.swap_bytes().to_be_bytes()), ProbableCause::ByteOrder);
+    assert_eq!(ProbableCause::classify((word >> 1).to_be_bytes()), ProbableCause::SpiMode);
+  }
+
+  #[test]
+  fn test_probe() {
+    let mut sim = Scl3300Sim::new();
+
+    let mut scl = Scl3300::new(&mut sim);
+    scl.probe().unwrap();
+    let mut scl = scl.start_up(MeasurementMode::Inclination).unwrap();
+    scl.probe().unwrap();
+
+    scl.spi_mut().set_component_id(0x42);
+    assert!(matches!(scl.probe(), Err(Error::WrongDevice { found: 0x42 })));
+  }
+}
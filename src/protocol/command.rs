@@ -0,0 +1,113 @@
+use super::{frame::Frame, register::Register};
+use crate::MeasurementMode;
+
+/// The value of the `MODE` register, which is used to send commands to the inclinometer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Command {
+  /// The measurement mode.
+  pub mode: MeasurementMode,
+  /// Whether to enter power down mode.
+  pub power_down: bool,
+  /// Whether to perform a software reset.
+  pub sw_reset: bool,
+}
+
+impl Command {
+  /// The address of the `MODE` register.
+  pub const ADDRESS: u8 = Register::MODE.address;
+
+  /// The first byte of a request writing the `MODE` register.
+  pub(crate) const WRITE_REQUEST: u8 = Register::MODE.frame(true, 0).bytes[0];
+
+  const MODE_MASK: u16 = 0b11;
+  const PD: u16 = 1 << 2;
+  const SW_RESET: u16 = 1 << 5;
+
+  /// Create a command changing to the given measurement mode.
+  pub const fn new(mode: MeasurementMode) -> Self {
+    Self { mode, power_down: false, sw_reset: false }
+  }
+
+  /// Set whether to enter power down mode.
+  pub const fn with_power_down(mut self, power_down: bool) -> Self {
+    self.power_down = power_down;
+    self
+  }
+
+  /// Set whether to perform a software reset.
+  pub const fn with_sw_reset(mut self, sw_reset: bool) -> Self {
+    self.sw_reset = sw_reset;
+    self
+  }
+
+  /// Decode a `MODE` register value. Unknown bits are ignored.
+  pub const fn from_bits(bits: u16) -> Self {
+    let mode = match bits & Self::MODE_MASK {
+      0 => MeasurementMode::FullScale12,
+      1 => MeasurementMode::FullScale24,
+      2 => MeasurementMode::Inclination,
+      _ => MeasurementMode::InclinationLowNoise,
+    };
+
+    Self { mode, power_down: bits & Self::PD != 0, sw_reset: bits & Self::SW_RESET != 0 }
+  }
+
+  /// Encode the `MODE` register value.
+  pub const fn bits(self) -> u16 {
+    let mut bits = self.mode as u16;
+    if self.power_down {
+      bits |= Self::PD;
+    }
+    if self.sw_reset {
+      bits |= Self::SW_RESET;
+    }
+    bits
+  }
+
+  pub(crate) const fn to_frame(self) -> Frame {
+    Register::MODE.frame(true, self.bits())
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  use crate::operation::Operation;
+
+  #[test]
+  fn test_command_bits() {
+    let command = Command::new(MeasurementMode::Inclination).with_power_down(true);
+    assert_eq!(command.bits(), 0x0006);
+    assert_eq!(Command::from_bits(0x0006), command);
+    assert_eq!(Command::from_bits(0x0023), Command::new(MeasurementMode::InclinationLowNoise).with_sw_reset(true));
+  }
+
+  #[test]
+  fn test_command_frames() {
+    use MeasurementMode::*;
+
+    #[rustfmt::skip]
+    let frames: [(Operation, u32); 7] = [
+      (Operation::ChangeMode(FullScale12),         0xB400001F),
+      (Operation::ChangeMode(FullScale24),         0xB4000102),
+      (Operation::ChangeMode(Inclination),         0xB4000225),
+      (Operation::ChangeMode(InclinationLowNoise), 0xB4000338),
+      (Operation::PowerDown,                       0xB400046B),
+      (Operation::WakeUp,                          0xB400001F),
+      (Operation::Reset,                           0xB4002098),
+    ];
+
+    for (operation, frame) in frames {
+      assert_eq!(operation.to_frame().bytes, frame.to_be_bytes(), "{operation:?}");
+    }
+  }
+
+  #[test]
+  fn test_reset_request() {
+    assert!(Operation::Reset.to_frame().is_reset_request());
+    assert!(Command::new(MeasurementMode::Inclination).with_sw_reset(true).to_frame().is_reset_request());
+    assert!(!Operation::PowerDown.to_frame().is_reset_request());
+    assert!(!Register::ANG_CTRL.frame(true, 0x20).is_reset_request());
+  }
+}
@@ -0,0 +1,217 @@
+use super::command::Command;
+use crate::error::Error;
+
+/// The return status (`RS`) of a response frame.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReturnStatus {
+  /// Start-up in progress
+  StartupInProgress,
+  /// Normal operation, no flags
+  NormalOperation,
+  /// Error flags are set in the `STATUS` register
+  Error,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Frame {
+  pub(crate) bytes: [u8; 4],
+}
+
+impl Frame {
+  /// Create a request frame for the given register address.
+  ///
+  /// Only the lower 5 bits of `address` are used.
+  pub const fn request(write: bool, address: u8, data: u16) -> Self {
+    let [d0, d1] = data.to_be_bytes();
+    let b0 = ((write as u8) << 7) | ((address & 0x1F) << 2);
+    Self::with_crc([b0, d0, d1])
+  }
+
+  /// Create a frame from received bytes.
+  pub const fn from_bytes(bytes: &[u8; 4]) -> Self {
+    Self { bytes: *bytes }
+  }
+
+  /// Split received bytes, e.g. a DMA buffer, into frames without copying.
+  ///
+  /// Returns [`Error::BufferSize`] if the length is not a multiple of 4.
+  pub fn slice_from_bytes<E>(bytes: &[u8]) -> Result<&[[u8; 4]], Error<E>> {
+    match bytes.as_chunks() {
+      (frames, []) => Ok(frames),
+      _ => Err(Error::BufferSize),
+    }
+  }
+
+  /// Create a frame from the given three bytes and their CRC.
+  pub const fn with_crc([b0, d0, d1]: [u8; 3]) -> Self {
+    Self { bytes: [b0, d0, d1, crc8([b0, d0, d1])] }
+  }
+
+  /// Check that this is a well-formed request frame, i.e. the CRC matches and the `RS` bits are zero.
+  pub fn check_request<E>(&self) -> Result<(), Error<E>> {
+    if self.bytes[0] & 0b11 != 0 || self.check_crc::<E>().is_err() {
+      return Err(Error::InvalidRequest { frame: self.bytes })
+    }
+
+    Ok(())
+  }
+
+  pub const fn return_status(&self) -> ReturnStatus {
+    use ReturnStatus::*;
+
+    match self.bytes[0] & 0b11 {
+      0b00 => StartupInProgress,
+      0b01 => NormalOperation,
+      _ => Error,
+    }
+  }
+
+  /// Check whether this is a request writing the `MODE` register with the software reset bit set,
+  /// regardless of the other bits.
+  pub(crate) const fn is_reset_request(&self) -> bool {
+    self.bytes[0] == Command::WRITE_REQUEST && Command::from_bits(self.data()).sw_reset
+  }
+
+  /// Get the `RW` and `ADDR` bits, which a response echoes from the previous request.
+  pub const fn echo(&self) -> u8 {
+    self.bytes[0] & !0b11
+  }
+
+  pub const fn data(&self) -> u16 {
+    u16::from_be_bytes([self.bytes[1], self.bytes[2]])
+  }
+
+  /// Compare the CRC of the input array to the given CRC checksum.
+  pub fn check_crc<E>(&self) -> Result<(), Error<E>> {
+    let crc = self.bytes[3];
+    let calculated_crc = crc8([self.bytes[0], self.bytes[1], self.bytes[2]]);
+
+    if calculated_crc == crc {
+      Ok(())
+    } else {
+      Err(Error::Crc)
+    }
+  }
+
+  /// Check the CRC and return status of a response frame.
+  pub fn check<E>(&self) -> Result<(), Error<E>> {
+    self.check_crc()?;
+
+    match self.return_status() {
+      ReturnStatus::StartupInProgress => Err(Error::Startup),
+      ReturnStatus::Error => Err(Error::ReturnStatus { frame: self.bytes, rs: self.bytes[0] & 0b11, status: None }),
+      ReturnStatus::NormalOperation => Ok(()),
+    }
+  }
+
+  pub fn as_bytes(&self) -> &[u8] {
+    &self.bytes
+  }
+
+  pub fn as_bytes_mut(&mut self) -> &mut [u8] {
+    &mut self.bytes
+  }
+}
+
+/// Calculate the CRC8 checksum for the given input array.
+#[cfg(not(feature = "crc-table"))]
+pub(crate) const fn crc8(data: [u8; 3]) -> u8 {
+  crc8_bitwise(data)
+}
+
+/// Calculate the CRC8 checksum for the given input array using a lookup table.
+#[cfg(feature = "crc-table")]
+pub(crate) const fn crc8(data: [u8; 3]) -> u8 {
+  let mut crc = 0xff;
+
+  let mut i = 0;
+  while i < data.len() {
+    crc = CRC8_TABLE[(crc ^ data[i]) as usize];
+    i += 1;
+  }
+
+  !crc
+}
+
+/// CRC8 lookup table, generated at compile time.
+#[cfg(feature = "crc-table")]
+const CRC8_TABLE: [u8; 256] = {
+  let mut table = [0; 256];
+
+  let mut i = 0;
+  while i < table.len() {
+    table[i] = crc8_byte(i as u8);
+    i += 1;
+  }
+
+  table
+};
+
+/// Shift a single byte through the CRC8 polynomial.
+const fn crc8_byte(mut crc: u8) -> u8 {
+  let mut bit = 0;
+  while bit < 8 {
+    if crc & 0x80 > 0 {
+      crc = (crc << 1) ^ 0x1d;
+    } else {
+      crc <<= 1;
+    }
+    bit += 1;
+  }
+
+  crc
+}
+
+/// Calculate the CRC8 checksum for the given input array bit by bit.
+#[cfg_attr(feature = "crc-table", allow(dead_code))]
+const fn crc8_bitwise(data: [u8; 3]) -> u8 {
+  let mut crc = 0xff;
+
+  let mut i = 0;
+  while i < data.len() {
+    crc = crc8_byte(crc ^ data[i]);
+    i += 1;
+  }
+
+  !crc
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_check_return_status() {
+    for (op, rs) in [(0x1B, 0b11), (0x1A, 0b10)] {
+      let frame = Frame { bytes: [op, 0x00, 0x12, crc8([op, 0x00, 0x12])] };
+      assert!(
+        matches!(frame.check::<()>(), Err(Error::ReturnStatus { frame: f, rs: r, status: None }) if f == frame.bytes && r == rs)
+      );
+    }
+  }
+
+  #[test]
+  fn test_crc8() {
+    let examples = [
+      ([183, 0, 2], 169),
+      ([25, 0, 18], 157),
+      ([25, 0, 0], 106),
+      ([27, 0, 18], 158),
+      ([24, 0, 0], 229),
+      ([183, 0, 0], 147),
+    ];
+
+    for (data, crc) in examples {
+      assert_eq!(crc8(data), crc);
+    }
+  }
+
+  #[test]
+  fn test_crc8_table() {
+    for byte in 0..=u8::MAX {
+      for data in [[byte, 0, 0], [0, byte, 0], [0, 0, byte], [byte, !byte, byte.rotate_left(3)]] {
+        assert_eq!(crc8(data), crc8_bitwise(data));
+      }
+    }
+  }
+}
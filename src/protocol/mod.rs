@@ -0,0 +1,138 @@
+//! Pure functions for encoding requests and decoding responses, without any SPI access.
+//!
+//! This is the protocol layer the driver is built on: frames, CRC and the register map, see [`Register`].
+//! It does not depend on `embedded-hal`, so it can be used e.g. for host-side tools decoding captured SPI
+//! traffic, bootloaders or other transports.
+//! To decode output values from captured responses, see [`ReadPlan::decode_rx`](crate::ReadPlan::decode_rx).
+
+use core::convert::Infallible;
+
+mod command;
+pub use self::command::Command;
+pub(crate) mod frame;
+pub(crate) mod operation;
+mod register;
+pub use self::register::Register;
+
+use self::frame::{crc8, Frame};
+pub use self::{frame::ReturnStatus, operation::Operation};
+use crate::Error;
+
+/// A decoded response frame.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Response {
+  /// Whether the previous request was a write request.
+  pub write: bool,
+  /// The register address of the previous request.
+  pub address: u8,
+  /// The return status.
+  pub return_status: ReturnStatus,
+  /// The data, i.e. the register value for a read request.
+  pub data: u16,
+}
+
+/// Encode the request frame for the given operation.
+pub const fn encode(operation: Operation) -> [u8; 4] {
+  operation.to_frame().bytes
+}
+
+/// Encode a raw request frame for the given register address.
+///
+/// Only the lower 5 bits of `address` are used.
+pub const fn encode_register(write: bool, address: u8, data: u16) -> [u8; 4] {
+  Frame::request(write, address, data).bytes
+}
+
+/// Decode a request frame into the corresponding operation, if it is known.
+///
+/// Since [`Operation::WakeUp`] uses the same frame as changing to
+/// [`MeasurementMode::FullScale12`](crate::MeasurementMode::FullScale12), the latter is returned for both.
+pub fn decode_request(frame: [u8; 4]) -> Option<Operation> {
+  Operation::ALL.into_iter().find(|operation| encode(*operation) == frame)
+}
+
+/// Decode a response frame.
+///
+/// Returns [`Error::Crc`] if the CRC does not match.
+pub fn decode(frame: [u8; 4]) -> Result<Response, Error<Infallible>> {
+  let frame = Frame { bytes: frame };
+  frame.check_crc()?;
+
+  Ok(Response {
+    write: frame.bytes[0] & 0x80 != 0,
+    address: (frame.bytes[0] >> 2) & 0x1F,
+    return_status: frame.return_status(),
+    data: frame.data(),
+  })
+}
+
+/// Split a buffer of received bytes, e.g. from DMA, into frames without copying.
+///
+/// Returns [`Error::BufferSize`] if the length is not a multiple of 4.
+pub fn frames(bytes: &[u8]) -> Result<&[[u8; 4]], Error<Infallible>> {
+  Frame::slice_from_bytes(bytes)
+}
+
+/// Calculate the CRC of the first three bytes of a frame.
+pub const fn crc(data: [u8; 3]) -> u8 {
+  crc8(data)
+}
+
+/// Complete a frame by appending the CRC of the given three bytes.
+pub const fn with_crc(data: [u8; 3]) -> [u8; 4] {
+  Frame::with_crc(data).bytes
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  use crate::{Bank, MeasurementMode, Output};
+
+  #[test]
+  fn test_encode_decode_request() {
+    assert_eq!(encode(Operation::Read(Output::Temperature)), [0x14, 0x00, 0x00, 0xEF]);
+
+    for operation in Operation::ALL {
+      assert_eq!(decode_request(encode(operation)), Some(operation));
+    }
+    assert_eq!(decode_request(encode(Operation::WakeUp)), Some(Operation::ChangeMode(MeasurementMode::FullScale12)));
+    assert_eq!(decode_request(encode(Operation::SwitchBank(Bank::One))), Some(Operation::SwitchBank(Bank::One)));
+    assert_eq!(decode_request([0; 4]), None);
+
+    for operation in Operation::ALL {
+      let response = decode(encode(operation)).unwrap();
+      assert_eq!(encode_register(response.write, response.address, response.data), encode(operation));
+    }
+  }
+
+  #[test]
+  fn test_decode_response() {
+    let response = decode([0x19, 0x00, 0x12, 0x9D]).unwrap();
+    assert_eq!(
+      response,
+      Response { write: false, address: 0x06, return_status: ReturnStatus::NormalOperation, data: 0x0012 }
+    );
+
+    let response = decode([0xB7, 0x00, 0x02, 0xA9]).unwrap();
+    assert!(response.write);
+    assert_eq!(response.return_status, ReturnStatus::Error);
+
+    assert!(matches!(decode([0x19, 0x00, 0x12, 0x00]), Err(Error::Crc)));
+  }
+
+  #[test]
+  fn test_frames() {
+    let rx = [0x19, 0x00, 0x12, 0x9D, 0xB7, 0x00, 0x02, 0xA9];
+    let frames = frames(&rx).unwrap();
+    assert_eq!(frames, [[0x19, 0x00, 0x12, 0x9D], [0xB7, 0x00, 0x02, 0xA9]]);
+    assert_eq!(decode(frames[0]).unwrap().data, 0x0012);
+
+    assert!(matches!(super::frames(&rx[..7]), Err(Error::BufferSize)));
+  }
+
+  #[test]
+  fn test_with_crc() {
+    assert_eq!(with_crc([0x14, 0x00, 0x00]), encode(Operation::Read(Output::Temperature)));
+  }
+}
@@ -0,0 +1,193 @@
+use super::{command::Command, register::Register};
+use crate::{Frame, MeasurementMode};
+
+/// A register bank.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Bank {
+  /// Bank 0
+  #[default]
+  Zero,
+  /// Bank 1
+  One,
+}
+
+impl Bank {
+  /// Get the bank from the value of the `SELBANK` register.
+  pub(crate) const fn from_selbank(value: u16) -> Self {
+    if value & 1 == 0 {
+      Self::Zero
+    } else {
+      Self::One
+    }
+  }
+}
+
+/// An output register.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Output {
+  /// X-axis acceleration (`ACC_X`)
+  AccelerationX,
+  /// Y-axis acceleration (`ACC_Y`)
+  AccelerationY,
+  /// Z-axis acceleration (`ACC_Z`)
+  AccelerationZ,
+  /// X-axis angle (`ANG_X`)
+  AngleX,
+  /// Y-axis angle (`ANG_Y`)
+  AngleY,
+  /// Z-axis angle (`ANG_Z`)
+  AngleZ,
+  /// Temperature (`TEMP`)
+  Temperature,
+  /// Self-test output (`STO`)
+  SelfTest,
+  /// Status summary (`STATUS`)
+  Status,
+  /// Error flags 1 (`ERR_FLAG1`)
+  Error1,
+  /// Error flags 2 (`ERR_FLAG2`)
+  Error2,
+  /// Command register (`MODE`)
+  Command,
+  /// Angle control register (`ANG_CTRL`)
+  AngleControl,
+  /// Component ID (`WHOAMI`)
+  WhoAmI,
+  /// Serial number, first part (`SERIAL1`)
+  Serial1,
+  /// Serial number, second part (`SERIAL2`)
+  Serial2,
+  /// Currently selected bank (`SELBANK`)
+  CurrentBank,
+}
+
+impl Output {
+  /// Get the bank this output can be read from.
+  ///
+  /// Returns `None` if the output can be read from any bank.
+  pub const fn bank(self) -> Option<Bank> {
+    self.register().bank
+  }
+
+  /// Get the register this output is read from.
+  pub const fn register(self) -> Register {
+    match self {
+      Self::AccelerationX => Register::ACC_X,
+      Self::AccelerationY => Register::ACC_Y,
+      Self::AccelerationZ => Register::ACC_Z,
+      Self::AngleX => Register::ANG_X,
+      Self::AngleY => Register::ANG_Y,
+      Self::AngleZ => Register::ANG_Z,
+      Self::Temperature => Register::TEMP,
+      Self::SelfTest => Register::STO,
+      Self::Status => Register::STATUS,
+      Self::Error1 => Register::ERR_FLAG1,
+      Self::Error2 => Register::ERR_FLAG2,
+      Self::Command => Register::MODE,
+      Self::AngleControl => Register::ANG_CTRL,
+      Self::WhoAmI => Register::WHOAMI,
+      Self::Serial1 => Register::SERIAL1,
+      Self::Serial2 => Register::SERIAL2,
+      Self::CurrentBank => Register::SELBANK,
+    }
+  }
+
+  /// Check whether this output is an angle output.
+  pub const fn is_angle(self) -> bool {
+    matches!(self, Self::AngleX | Self::AngleY | Self::AngleZ)
+  }
+
+  /// Check whether this output is a numeric measurement, as opposed to flags or identification data.
+  pub const fn is_numeric(self) -> bool {
+    matches!(
+      self,
+      Self::AccelerationX
+        | Self::AccelerationY
+        | Self::AccelerationZ
+        | Self::AngleX
+        | Self::AngleY
+        | Self::AngleZ
+        | Self::Temperature
+        | Self::SelfTest
+    )
+  }
+}
+
+/// An operation which can be requested from the inclinometer.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Operation {
+  /// Read an output register.
+  Read(Output),
+  /// Enable angle outputs.
+  EnableAngleOutputs,
+  /// Disable angle outputs.
+  DisableAngleOutputs,
+  /// Change the measurement mode.
+  ChangeMode(MeasurementMode),
+  /// Enter power down mode.
+  PowerDown,
+  /// Wake up from power down mode.
+  WakeUp,
+  /// Software reset.
+  Reset,
+  /// Switch the register bank.
+  SwitchBank(Bank),
+}
+
+impl Operation {
+  /// All operations with a distinct request frame.
+  ///
+  /// [`WakeUp`](Operation::WakeUp) is not included, since it uses the same frame
+  /// as [`ChangeMode`](Operation::ChangeMode)`(`[`FullScale12`](MeasurementMode::FullScale12)`)`.
+  pub(crate) const ALL: [Self; 27] = {
+    use Bank::*;
+    use MeasurementMode::*;
+    use Operation::*;
+    use Output::*;
+
+    [
+      Read(AccelerationX),
+      Read(AccelerationY),
+      Read(AccelerationZ),
+      Read(AngleX),
+      Read(AngleY),
+      Read(AngleZ),
+      Read(Temperature),
+      Read(SelfTest),
+      Read(Status),
+      Read(Error1),
+      Read(Error2),
+      Read(Command),
+      Read(AngleControl),
+      Read(WhoAmI),
+      Read(Serial1),
+      Read(Serial2),
+      Read(CurrentBank),
+      EnableAngleOutputs,
+      DisableAngleOutputs,
+      ChangeMode(FullScale12),
+      ChangeMode(FullScale24),
+      ChangeMode(Inclination),
+      ChangeMode(InclinationLowNoise),
+      PowerDown,
+      Reset,
+      SwitchBank(Zero),
+      SwitchBank(One),
+    ]
+  };
+
+  pub(crate) const fn to_frame(self) -> Frame {
+    use MeasurementMode::FullScale12;
+
+    match self {
+      Self::Read(output) => output.register().frame(false, 0),
+      Self::EnableAngleOutputs => Register::ANG_CTRL.frame(true, 0x001F),
+      Self::DisableAngleOutputs => Register::ANG_CTRL.frame(true, 0x0000),
+      Self::ChangeMode(mode) => Command::new(mode).to_frame(),
+      Self::PowerDown => Command::new(FullScale12).with_power_down(true).to_frame(),
+      Self::WakeUp => Command::new(FullScale12).to_frame(),
+      Self::Reset => Command::new(FullScale12).with_sw_reset(true).to_frame(),
+      Self::SwitchBank(bank) => Register::SELBANK.frame(true, bank as u16),
+    }
+  }
+}
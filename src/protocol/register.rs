@@ -0,0 +1,160 @@
+use super::frame::Frame;
+use crate::Bank;
+
+/// A register in the register map of the inclinometer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Register {
+  /// The register address.
+  pub address: u8,
+  /// The bank the register is located in, or `None` if it can be accessed from any bank.
+  pub bank: Option<Bank>,
+  /// Whether the register can be written.
+  pub writable: bool,
+  /// The register name as used in the datasheet.
+  pub name: &'static str,
+}
+
+impl Register {
+  /// X-axis acceleration
+  pub const ACC_X: Self = Self::read_only(0x01, Bank::Zero, "ACC_X");
+  /// Y-axis acceleration
+  pub const ACC_Y: Self = Self::read_only(0x02, Bank::Zero, "ACC_Y");
+  /// Z-axis acceleration
+  pub const ACC_Z: Self = Self::read_only(0x03, Bank::Zero, "ACC_Z");
+  /// Self-test output
+  pub const STO: Self = Self::read_only(0x04, Bank::Zero, "STO");
+  /// Temperature
+  pub const TEMP: Self = Self::read_only(0x05, Bank::Zero, "TEMP");
+  /// Status summary
+  pub const STATUS: Self = Self::read_only(0x06, Bank::Zero, "STATUS");
+  /// Error flags 1
+  pub const ERR_FLAG1: Self = Self::read_only(0x07, Bank::Zero, "ERR_FLAG1");
+  /// Error flags 2
+  pub const ERR_FLAG2: Self = Self::read_only(0x08, Bank::Zero, "ERR_FLAG2");
+  /// X-axis angle
+  pub const ANG_X: Self = Self::read_only(0x09, Bank::Zero, "ANG_X");
+  /// Y-axis angle
+  pub const ANG_Y: Self = Self::read_only(0x0A, Bank::Zero, "ANG_Y");
+  /// Z-axis angle
+  pub const ANG_Z: Self = Self::read_only(0x0B, Bank::Zero, "ANG_Z");
+  /// Angle control
+  pub const ANG_CTRL: Self = Self { address: 0x0C, bank: Some(Bank::Zero), writable: true, name: "ANG_CTRL" };
+  /// Mode and commands
+  pub const MODE: Self = Self { address: 0x0D, bank: Some(Bank::Zero), writable: true, name: "MODE" };
+  /// Component ID
+  pub const WHOAMI: Self = Self::read_only(0x10, Bank::Zero, "WHOAMI");
+  /// Serial number, first part
+  pub const SERIAL1: Self = Self::read_only(0x19, Bank::One, "SERIAL1");
+  /// Serial number, second part
+  pub const SERIAL2: Self = Self::read_only(0x1A, Bank::One, "SERIAL2");
+  /// Bank selection
+  pub const SELBANK: Self = Self { address: 0x1F, bank: None, writable: true, name: "SELBANK" };
+
+  /// All documented registers, ordered by bank and address.
+  pub const MAP: [Self; 17] = [
+    Self::ACC_X,
+    Self::ACC_Y,
+    Self::ACC_Z,
+    Self::STO,
+    Self::TEMP,
+    Self::STATUS,
+    Self::ERR_FLAG1,
+    Self::ERR_FLAG2,
+    Self::ANG_X,
+    Self::ANG_Y,
+    Self::ANG_Z,
+    Self::ANG_CTRL,
+    Self::MODE,
+    Self::WHOAMI,
+    Self::SERIAL1,
+    Self::SERIAL2,
+    Self::SELBANK,
+  ];
+
+  const fn read_only(address: u8, bank: Bank, name: &'static str) -> Self {
+    Self { address, bank: Some(bank), writable: false, name }
+  }
+
+  /// Look up the register at the given address in the given bank.
+  pub fn find(bank: Bank, address: u8) -> Option<&'static Self> {
+    Self::MAP.iter().find(|register| register.address == address && register.bank.is_none_or(|b| b == bank))
+  }
+
+  /// Look up a register by its name.
+  pub fn by_name(name: &str) -> Option<&'static Self> {
+    Self::MAP.iter().find(|register| register.name == name)
+  }
+
+  /// Encode the request frame for reading this register.
+  pub const fn read_request(&self) -> [u8; 4] {
+    self.frame(false, 0).bytes
+  }
+
+  /// Encode the request frame for writing `data` to this register.
+  ///
+  /// Returns `None` if the register is not writable.
+  pub const fn write_request(&self, data: u16) -> Option<[u8; 4]> {
+    if !self.writable {
+      return None
+    }
+
+    Some(self.frame(true, data).bytes)
+  }
+
+  pub(crate) const fn frame(&self, write: bool, data: u16) -> Frame {
+    Frame::request(write, self.address, data)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  use crate::{operation::Operation, Output};
+
+  #[test]
+  fn test_request_frames() {
+    use Operation::*;
+    use Output::*;
+
+    #[rustfmt::skip]
+    let frames: [(Operation, u32); 21] = [
+      (Read(AccelerationX),    0x040000F7),
+      (Read(AccelerationY),    0x080000FD),
+      (Read(AccelerationZ),    0x0C0000FB),
+      (Read(SelfTest),         0x100000E9),
+      (EnableAngleOutputs,     0xB0001F6F),
+      (DisableAngleOutputs,    0xB0000019),
+      (Read(AngleX),           0x240000C7),
+      (Read(AngleY),           0x280000CD),
+      (Read(AngleZ),           0x2C0000CB),
+      (Read(Temperature),      0x140000EF),
+      (Read(Status),           0x180000E5),
+      (Read(Error1),           0x1C0000E3),
+      (Read(Error2),           0x200000C1),
+      (Read(Command),          0x340000DF),
+      (Read(AngleControl),     0x300000D9),
+      (Read(WhoAmI),           0x40000091),
+      (Read(Serial1),          0x640000A7),
+      (Read(Serial2),          0x680000AD),
+      (Read(CurrentBank),      0x7C0000B3),
+      (SwitchBank(Bank::Zero), 0xFC000073),
+      (SwitchBank(Bank::One),  0xFC00016E),
+    ];
+
+    for (operation, frame) in frames {
+      assert_eq!(operation.to_frame().bytes, frame.to_be_bytes(), "{operation:?}");
+    }
+  }
+
+  #[test]
+  fn test_lookup() {
+    assert_eq!(Register::find(Bank::One, 0x19), Some(&Register::SERIAL1));
+    assert_eq!(Register::find(Bank::Zero, 0x19), None);
+    assert_eq!(Register::find(Bank::One, 0x1F), Some(&Register::SELBANK));
+    assert_eq!(Register::by_name("ANG_CTRL"), Some(&Register::ANG_CTRL));
+    assert_eq!(Register::STATUS.write_request(0), None);
+    assert_eq!(Register::SELBANK.write_request(1), Some(0xFC00016E_u32.to_be_bytes()));
+    assert_eq!(Output::Serial2.register(), Register::SERIAL2);
+  }
+}
@@ -0,0 +1,936 @@
+use core::{convert::Infallible, marker::PhantomData};
+
+use embedded_hal::spi::SpiDevice;
+
+use crate::{
+  operation::{Bank, Operation, Output},
+  Error, Frame, MeasurementMode, Normal, OffFrameRead, ReturnStatus, Scl3300, Timings,
+};
+
+/// The maximum number of outputs which can be read at once.
+pub const MAX_OUTPUTS: usize = 32;
+
+/// The maximum number of frames in a [`ReadPlan`]: one per output, a switch to bank 1 and a final bank switch.
+const MAX_FRAMES: usize = MAX_OUTPUTS + 2;
+
+/// Marks a frame whose response does not contain an output value.
+const NO_OUTPUT: u8 = u8::MAX;
+
+/// A fixed-capacity list of outputs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct OutputList {
+  outputs: [Output; MAX_OUTPUTS],
+  len: usize,
+}
+
+impl OutputList {
+  /// An empty list.
+  pub const EMPTY: Self = Self { outputs: [Output::Status; MAX_OUTPUTS], len: 0 };
+
+  /// Create a list containing the given outputs.
+  ///
+  /// Panics if more than [`MAX_OUTPUTS`] outputs are given.
+  pub const fn new(outputs: &[Output]) -> Self {
+    let mut list = Self::EMPTY;
+
+    let mut i = 0;
+    while i < outputs.len() {
+      list = list.push(outputs[i]);
+      i += 1;
+    }
+
+    list
+  }
+
+  /// Append an output to the list.
+  ///
+  /// Panics if the list is full.
+  pub const fn push(mut self, output: Output) -> Self {
+    assert!(self.len < MAX_OUTPUTS, "too many outputs");
+    self.outputs[self.len] = output;
+    self.len += 1;
+    self
+  }
+
+  /// Append all outputs of `other` to the list.
+  ///
+  /// Panics if the combined list exceeds [`MAX_OUTPUTS`].
+  pub const fn concat(mut self, other: &OutputList) -> Self {
+    let mut i = 0;
+    while i < other.len {
+      self = self.push(other.outputs[i]);
+      i += 1;
+    }
+
+    self
+  }
+
+  /// Get the number of outputs in the list.
+  pub const fn len(&self) -> usize {
+    self.len
+  }
+
+  /// Check whether the list is empty.
+  pub const fn is_empty(&self) -> bool {
+    self.len == 0
+  }
+
+  /// Get the outputs as a slice.
+  pub fn as_slice(&self) -> &[Output] {
+    &self.outputs[..self.len]
+  }
+}
+
+/// An invalid read plan, see [`ReadPlan::try_from_outputs`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PlanError {
+  /// No outputs are given.
+  Empty,
+  /// More than [`MAX_OUTPUTS`] outputs are given.
+  TooManyOutputs {
+    /// The number of given outputs.
+    len: usize,
+  },
+  /// The plan reads angle outputs while they are disabled.
+  AnglesDisabled,
+}
+
+/// The outputs of a lenient read whose response frames reported an error or start-up return status.
+///
+/// See [`Scl3300::read_lenient`](crate::Scl3300::read_lenient).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FlagSummary {
+  outputs: OutputList,
+  flagged: u32,
+  startup: u32,
+}
+
+impl FlagSummary {
+  /// Check whether no output was flagged.
+  pub const fn is_empty(&self) -> bool {
+    self.flagged == 0
+  }
+
+  /// Get the number of flagged outputs.
+  pub const fn len(&self) -> usize {
+    self.flagged.count_ones() as usize
+  }
+
+  /// Check whether any flagged output reported that start-up is still in progress.
+  pub const fn in_startup(&self) -> bool {
+    self.startup != 0
+  }
+
+  /// Check whether any flagged output reported an error, i.e. error flags are set in the `STATUS` register.
+  pub const fn has_errors(&self) -> bool {
+    self.flagged & !self.startup != 0
+  }
+
+  /// Check whether the given output was flagged.
+  pub fn contains(&self, output: Output) -> bool {
+    self.iter().any(|o| o == output)
+  }
+
+  /// Iterate over all flagged outputs.
+  pub fn iter(&self) -> impl Iterator<Item = Output> + '_ {
+    self.outputs.as_slice().iter().enumerate().filter(|(i, _)| self.flagged & (1 << i) != 0).map(|(_, &output)| output)
+  }
+}
+
+/// A precomputed sequence of request frames for reading a value.
+///
+/// Creating a plan computes all request frames including bank switches, so executing
+/// it only needs to perform the SPI transfers and decode the responses. Plans can be
+/// created at compile time:
+///
+/// ```rust
+/// use scl3300::{Acceleration, ReadPlan, Temperature};
+///
+/// const PLAN: ReadPlan<(Acceleration, Temperature)> = ReadPlan::new();
+/// ```
+pub struct ReadPlan<V> {
+  frames: [Frame; MAX_FRAMES],
+  targets: [u8; MAX_FRAMES],
+  len: usize,
+  outputs: usize,
+  angles: bool,
+  numeric: u32,
+  _value: PhantomData<fn() -> V>,
+}
+
+impl<V> core::fmt::Debug for ReadPlan<V> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    f.debug_struct("ReadPlan").field("frames", &&self.frames[..self.len]).finish()
+  }
+}
+
+impl<V> Clone for ReadPlan<V> {
+  fn clone(&self) -> Self {
+    *self
+  }
+}
+
+impl<V> Copy for ReadPlan<V> {}
+
+impl<V: OffFrameRead> Default for ReadPlan<V> {
+  fn default() -> Self {
+    Self::new()
+  }
+}
+
+impl<V> ReadPlan<V> {
+  /// Compute the read plan for the given outputs.
+  pub(crate) const fn with_outputs(outputs: &OutputList) -> Self {
+    let mut frames = [Operation::SwitchBank(Bank::Zero).to_frame(); MAX_FRAMES];
+    let mut targets = [NO_OUTPUT; MAX_FRAMES];
+    let mut len = 0;
+    let mut current_bank = Bank::Zero;
+    let mut angles = false;
+    let mut numeric = 0;
+
+    // Outputs are grouped by bank, so at most one round-trip to bank 1 is needed.
+    // Outputs available in all banks are read in bank 0.
+    let banks = [Bank::Zero, Bank::One];
+    let mut b = 0;
+    while b < banks.len() {
+      let pass_bank = banks[b];
+
+      let mut i = 0;
+      while i < outputs.len {
+        let output = outputs.outputs[i];
+
+        let bank = match output.bank() {
+          Some(bank) => bank,
+          None => Bank::Zero,
+        };
+
+        if bank as u8 == pass_bank as u8 {
+          angles |= output.is_angle();
+          if output.is_numeric() {
+            numeric |= 1 << i;
+          }
+
+          if bank as u8 != current_bank as u8 {
+            frames[len] = Operation::SwitchBank(bank).to_frame();
+            len += 1;
+            current_bank = bank;
+          }
+
+          frames[len] = Operation::Read(output).to_frame();
+          targets[len] = i as u8;
+          len += 1;
+        }
+
+        i += 1;
+      }
+
+      b += 1;
+    }
+
+    // The final bank switch returns the last output value and leaves the device in bank 0.
+    frames[len] = Operation::SwitchBank(Bank::Zero).to_frame();
+    len += 1;
+
+    Self { frames, targets, len, outputs: outputs.len, angles, numeric, _value: PhantomData }
+  }
+
+  /// Get the number of frames transferred when executing the plan, including bank switches.
+  pub const fn frame_count(&self) -> usize {
+    self.len
+  }
+
+  /// Estimate the time in nanoseconds the plan occupies the SPI bus at the given frequency, including the
+  /// delays between frames according to `timings`.
+  ///
+  /// With [deferred flushes](crate::Scl3300Builder::deferred_flush), the actual time can be one frame shorter.
+  pub const fn estimated_bus_time_ns(&self, bus_frequency_hz: u32, timings: &Timings) -> u64 {
+    let frame_ns = Timings::frame_transfer_ns(bus_frequency_hz) as u64;
+    let delay_ns = timings.delay_ns(None) as u64;
+    self.len as u64 * (frame_ns + delay_ns)
+  }
+
+  /// Assert that the plan needs at most `max_frames` frames.
+  ///
+  /// When used to initialize a constant, exceeding the budget fails compilation:
+  ///
+  /// ```compile_fail
+  /// use scl3300::{Acceleration, Inclination, ReadPlan, Serial};
+  ///
+  /// const PLAN: ReadPlan<(Acceleration, Inclination, Serial)> = ReadPlan::new().with_frame_budget(8);
+  /// # let _ = PLAN;
+  /// ```
+  pub const fn with_frame_budget(self, max_frames: usize) -> Self {
+    assert!(self.len <= max_frames, "read plan exceeds frame budget");
+    self
+  }
+
+  /// Check that the plan can be executed with the current state of the driver, i.e. angle outputs
+  /// are enabled if the plan reads them.
+  pub fn validate<SPI>(&self, scl: &Scl3300<SPI, Normal>) -> Result<(), PlanError> {
+    if self.angles && !scl.mode.angles_enabled {
+      return Err(PlanError::AnglesDisabled)
+    }
+
+    Ok(())
+  }
+
+  /// Execute the read plan and store the raw output values in `values`, without decoding them.
+  ///
+  /// Like [`execute`](ReadPlan::execute), this checks whether angle outputs are enabled and retries after transient errors.
+  pub(crate) fn execute_values<SPI, E>(
+    &self,
+    scl: &mut Scl3300<SPI, Normal>,
+    values: &mut [u16; MAX_OUTPUTS],
+  ) -> Result<(), Error<E>>
+  where
+    SPI: SpiDevice<u8, Error = E>,
+  {
+    self.validate(scl)?;
+
+    let mut retries = scl.state.retries;
+    loop {
+      let result = self.collect_values(|frame| scl.transfer_frame(frame, None), values);
+
+      match result {
+        Ok(_) => return Ok(()),
+        Err(Error::Crc | Error::ProtocolDesync) if retries > 0 => {
+          retries -= 1;
+          #[cfg(feature = "stats")]
+          {
+            scl.state.stats.retries = scl.state.stats.retries.wrapping_add(1);
+          }
+          scl.restore_bank_zero()?;
+        },
+        Err(err) => return Err(err),
+      }
+    }
+  }
+
+  /// Send all frames using the given `transfer` function and store the output values from the responses in `values`.
+  ///
+  /// Returns a bit mask of all outputs whose response did not report normal operation, and
+  /// a bit mask of all outputs whose response reported that start-up is in progress.
+  fn collect_values<E>(
+    &self,
+    transfer: impl FnMut(Frame) -> Result<Frame, Error<E>>,
+    values: &mut [u16; MAX_OUTPUTS],
+  ) -> Result<(u32, u32), Error<E>> {
+    self.collect_frames(transfer, values, &self.frames[..self.len], &self.targets[..self.len], NO_OUTPUT)
+  }
+
+  /// Send the given frames using the given `transfer` function and store the output values from the responses in `values`,
+  /// where the first response returns the value of `previous_target`.
+  fn collect_frames<E>(
+    &self,
+    mut transfer: impl FnMut(Frame) -> Result<Frame, Error<E>>,
+    values: &mut [u16; MAX_OUTPUTS],
+    frames: &[Frame],
+    targets: &[u8],
+    mut previous_target: u8,
+  ) -> Result<(u32, u32), Error<E>> {
+    let mut flagged = 0;
+    let mut startup = 0;
+
+    for (frame, &target) in frames.iter().zip(targets) {
+      let response = transfer(*frame)?;
+
+      if let Some(value) = values.get_mut(previous_target as usize) {
+        *value = response.data();
+
+        match response.return_status() {
+          ReturnStatus::NormalOperation => (),
+          ReturnStatus::StartupInProgress => {
+            flagged |= 1 << previous_target;
+            startup |= 1 << previous_target;
+          },
+          ReturnStatus::Error => flagged |= 1 << previous_target,
+        }
+      }
+      previous_target = target;
+    }
+
+    Ok((flagged, startup))
+  }
+}
+
+impl ReadPlan<()> {
+  /// Compute a read plan for the given outputs at run time, e.g. from a configuration.
+  ///
+  /// Outputs are grouped by bank, so the plan is valid regardless of their order.
+  /// Returns an error if no outputs or more than [`MAX_OUTPUTS`] outputs are given.
+  pub const fn try_from_outputs(outputs: &[Output]) -> Result<Self, PlanError> {
+    if outputs.is_empty() {
+      return Err(PlanError::Empty)
+    }
+
+    if outputs.len() > MAX_OUTPUTS {
+      return Err(PlanError::TooManyOutputs { len: outputs.len() })
+    }
+
+    Ok(Self::with_outputs(&OutputList::new(outputs)))
+  }
+
+  /// Execute the read plan and store the raw output values in `dest`, in the order the outputs were given.
+  ///
+  /// Returns [`Error::BufferSize`] if the length of `dest` is not equal to the number of outputs.
+  pub fn execute_into<SPI, E>(&self, scl: &mut Scl3300<SPI, Normal>, dest: &mut [u16]) -> Result<(), Error<E>>
+  where
+    SPI: SpiDevice<u8, Error = E>,
+  {
+    if dest.len() != self.outputs {
+      return Err(Error::BufferSize)
+    }
+
+    let mut values = [0; MAX_OUTPUTS];
+    self.execute_values(scl, &mut values)?;
+    dest.copy_from_slice(&values[..self.outputs]);
+
+    Ok(())
+  }
+}
+
+impl<V: OffFrameRead> ReadPlan<V> {
+  /// Compute the read plan for `V`.
+  pub const fn new() -> Self {
+    Self::with_outputs(&V::OUTPUTS)
+  }
+
+  /// Get the size in bytes of the buffers needed for [`fill_tx`](ReadPlan::fill_tx) and [`decode_rx`](ReadPlan::decode_rx).
+  pub const fn buffer_len(&self) -> usize {
+    self.len * 4
+  }
+
+  /// Check whether the plan reads any angle outputs.
+  pub const fn reads_angles(&self) -> bool {
+    self.angles
+  }
+
+  /// Execute the read plan.
+  ///
+  /// The driver's calibration is applied to the decoded value, and the read is retried after transient errors
+  /// if configured using [`Scl3300Builder::retries`](crate::Scl3300Builder::retries).
+  ///
+  /// Returns [`PlanError::AnglesDisabled`] if the plan reads angle outputs while they are disabled.
+  ///
+  /// If configured using [`Scl3300Builder::status_check_interval`](crate::Scl3300Builder::status_check_interval),
+  /// the `STATUS` register is checked before every n-th read.
+  pub fn execute<SPI, E>(&self, scl: &mut Scl3300<SPI, Normal>) -> Result<V, Error<E>>
+  where
+    SPI: SpiDevice<u8, Error = E>,
+  {
+    self.validate(scl)?;
+
+    scl.check_status_periodically()?;
+
+    let mode = scl.mode.mode;
+
+    let mut retries = scl.state.retries;
+    loop {
+      let result = if scl.state.deferred_flush && self.can_defer_flush() && scl.state.bank == Bank::Zero {
+        self.execute_deferred(scl, mode)
+      } else {
+        self.execute_raw(scl, mode)
+      };
+
+      match result {
+        Ok(value) => return Ok(value.calibrate(&scl.state.calibration)),
+        Err(Error::Crc | Error::ProtocolDesync) if retries > 0 => {
+          retries -= 1;
+          #[cfg(feature = "stats")]
+          {
+            scl.state.stats.retries = scl.state.stats.retries.wrapping_add(1);
+          }
+          scl.restore_bank_zero()?;
+        },
+        Err(err) => return Err(err),
+      }
+    }
+  }
+
+  /// Execute the read plan `N` times and return the average value.
+  ///
+  /// Raw output values are averaged as signed integers and rounded to the nearest integer before decoding,
+  /// so averaging cannot overflow. Note that inclination angles close to ±180° wrap around and should not be averaged.
+  ///
+  /// Only [numeric outputs](Output::is_numeric) are averaged. Flags and identification data, e.g. [`Output::Status`],
+  /// are taken from the last read.
+  pub fn execute_averaged<SPI, E, const N: usize>(&self, scl: &mut Scl3300<SPI, Normal>) -> Result<V, Error<E>>
+  where
+    SPI: SpiDevice<u8, Error = E>,
+  {
+    const { assert!(N > 0, "cannot average zero reads") };
+
+    let mut sums = [0i64; MAX_OUTPUTS];
+    let mut values = [0; MAX_OUTPUTS];
+    for _ in 0..N {
+      self.execute_values(scl, &mut values)?;
+
+      for (sum, &value) in sums.iter_mut().zip(&values) {
+        *sum += value as i16 as i64;
+      }
+    }
+
+    let n = N as i64;
+    let averages = core::array::from_fn::<_, MAX_OUTPUTS, _>(|i| {
+      if self.numeric & (1 << i) == 0 {
+        return values[i]
+      }
+
+      let sum = sums[i];
+      (if sum < 0 { sum - n / 2 } else { sum + n / 2 } / n) as i16 as u16
+    });
+
+    Ok(V::decode(&averages[..self.outputs], scl.mode.mode).calibrate(&scl.state.calibration))
+  }
+
+  /// Execute the read plan, without aborting when a response frame reports an error return status.
+  ///
+  /// The values are returned together with a [`FlagSummary`] of all outputs whose response frames reported
+  /// an error or start-up return status. CRC errors still abort the read.
+  pub fn execute_lenient<SPI, E>(&self, scl: &mut Scl3300<SPI, Normal>) -> Result<(V, FlagSummary), Error<E>>
+  where
+    SPI: SpiDevice<u8, Error = E>,
+  {
+    self.validate(scl)?;
+
+    let mode = scl.mode.mode;
+    let (value, flags) = self.execute_raw_lenient(scl, mode)?;
+    Ok((value.calibrate(&scl.state.calibration), flags))
+  }
+
+  /// Execute the read plan in any mode, without calibration and without checking the return status.
+  pub(crate) fn execute_raw_lenient<SPI, E, MODE>(
+    &self,
+    scl: &mut Scl3300<SPI, MODE>,
+    mode: MeasurementMode,
+  ) -> Result<(V, FlagSummary), Error<E>>
+  where
+    SPI: SpiDevice<u8, Error = E>,
+  {
+    self.collect_flagged(
+      |frame| {
+        let frame = scl.transfer_inner(frame, None)?;
+        let res = frame.check_crc();
+        #[cfg(feature = "stats")]
+        scl.state.stats.record(&res);
+        res?;
+        Ok(frame)
+      },
+      mode,
+    )
+  }
+
+  /// Execute the read plan in any mode, without calibration.
+  pub(crate) fn execute_raw<SPI, E, MODE>(
+    &self,
+    scl: &mut Scl3300<SPI, MODE>,
+    mode: MeasurementMode,
+  ) -> Result<V, Error<E>>
+  where
+    SPI: SpiDevice<u8, Error = E>,
+  {
+    self.collect(|frame| scl.transfer_frame(frame, None), mode)
+  }
+
+  /// Check whether the plan only reads outputs in bank 0, so the final bank switch only flushes the last value.
+  fn can_defer_flush(&self) -> bool {
+    self.len >= 2 && self.targets[..self.len - 1].iter().all(|&target| target != NO_OUTPUT)
+  }
+
+  /// Execute the read plan without the final bank switch, without calibration.
+  ///
+  /// The last read request is left pending. If the previous read left the same request pending, its
+  /// response returns the last output value, otherwise the request is sent once more up front.
+  fn execute_deferred<SPI, E>(&self, scl: &mut Scl3300<SPI, Normal>, mode: MeasurementMode) -> Result<V, Error<E>>
+  where
+    SPI: SpiDevice<u8, Error = E>,
+  {
+    let last = self.len - 2;
+    let last_frame = self.frames[last];
+
+    if scl.state.pending_read != Some(last_frame.bytes) {
+      scl.transfer_frame(last_frame, None)?;
+    }
+
+    let mut values = [0; MAX_OUTPUTS];
+    self.collect_frames(
+      |frame| scl.transfer_frame(frame, None),
+      &mut values,
+      &self.frames[..=last],
+      &self.targets[..=last],
+      self.targets[last],
+    )?;
+    scl.state.pending_read = Some(last_frame.bytes);
+
+    Ok(V::decode(&values[..self.outputs], mode))
+  }
+
+  /// Write all request frames into the given TX buffer.
+  ///
+  /// This allows performing the transfers outside of the driver, e.g. using DMA. Every frame
+  /// has to be sent in a separate SPI transaction, with at least 10 µs between transactions.
+  ///
+  /// Returns an error if the buffer size is not equal to [`buffer_len`](ReadPlan::buffer_len).
+  pub fn fill_tx(&self, tx: &mut [u8]) -> Result<(), Error<Infallible>> {
+    if tx.len() != self.buffer_len() {
+      return Err(Error::BufferSize)
+    }
+
+    for (chunk, frame) in tx.chunks_exact_mut(4).zip(&self.frames[..self.len]) {
+      chunk.copy_from_slice(frame.as_bytes());
+    }
+
+    Ok(())
+  }
+
+  /// Decode the value from the RX buffer received while sending the frames written by [`fill_tx`](ReadPlan::fill_tx).
+  ///
+  /// The CRC of every response frame is checked. The return status is checked for every response
+  /// except the first one, which belongs to a request sent before the plan.
+  ///
+  /// Returns an error if the buffer size is not equal to [`buffer_len`](ReadPlan::buffer_len).
+  pub fn decode_rx(&self, rx: &[u8], mode: MeasurementMode) -> Result<V, Error<Infallible>> {
+    if rx.len() != self.buffer_len() {
+      return Err(Error::BufferSize)
+    }
+
+    let mut responses = Frame::slice_from_bytes(rx)?.iter().enumerate();
+    self.collect(
+      |_| {
+        let Some((i, bytes)) = responses.next() else { return Err(Error::BufferSize) };
+
+        let frame = Frame::from_bytes(bytes);
+        if i == 0 {
+          frame.check_crc()?;
+        } else {
+          frame.check()?;
+        }
+        Ok(frame)
+      },
+      mode,
+    )
+  }
+
+  /// Transfer the frame at index `i` and store the output value returned in its response in `values`.
+  ///
+  /// Returns the decoded and calibrated value after the last frame.
+  pub(crate) fn step<SPI, E>(
+    &self,
+    scl: &mut Scl3300<SPI, Normal>,
+    i: usize,
+    values: &mut [u16; MAX_OUTPUTS],
+  ) -> Result<Option<V>, Error<E>>
+  where
+    SPI: SpiDevice<u8, Error = E>,
+  {
+    let response = scl.transfer_frame(self.frames[i], None)?;
+
+    if let Some(previous) = i.checked_sub(1) {
+      if let Some(value) = values.get_mut(self.targets[previous] as usize) {
+        *value = response.data();
+      }
+    }
+
+    if i + 1 < self.len {
+      return Ok(None)
+    }
+
+    Ok(Some(V::decode(&values[..self.outputs], scl.mode.mode).calibrate(&scl.state.calibration)))
+  }
+
+  /// Send all frames using the given `transfer` function and decode the value from the responses.
+  fn collect<E>(
+    &self,
+    transfer: impl FnMut(Frame) -> Result<Frame, Error<E>>,
+    mode: MeasurementMode,
+  ) -> Result<V, Error<E>> {
+    self.collect_flagged(transfer, mode).map(|(value, _)| value)
+  }
+
+  /// Send all frames using the given `transfer` function and decode the value from the responses,
+  /// also returning a [`FlagSummary`] of all outputs whose response did not report normal operation.
+  fn collect_flagged<E>(
+    &self,
+    transfer: impl FnMut(Frame) -> Result<Frame, Error<E>>,
+    mode: MeasurementMode,
+  ) -> Result<(V, FlagSummary), Error<E>> {
+    let mut values = [0; MAX_OUTPUTS];
+    let (flagged, startup) = self.collect_values(transfer, &mut values)?;
+    Ok((V::decode(&values[..self.outputs], mode), FlagSummary { outputs: V::OUTPUTS, flagged, startup }))
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  use crate::{sim::Scl3300Sim, Acceleration, Temperature};
+  #[cfg(feature = "float")]
+  use crate::{AccelerationX, AccelerationY, Gs, InclinationZ, Serial, Status};
+
+  #[test]
+  fn test_const_plan() {
+    const PLAN: ReadPlan<(Acceleration, Temperature)> = ReadPlan::new();
+
+    let expected = [0x040000F7, 0x080000FD, 0x0C0000FB, 0x140000EF, 0xFC000073].map(u32::to_be_bytes);
+    assert_eq!(PLAN.frames[..PLAN.len].iter().map(|frame| frame.bytes).collect::<Vec<_>>(), expected);
+  }
+
+  #[test]
+  fn test_frame_budget() {
+    const PLAN: ReadPlan<(Acceleration, Temperature)> = ReadPlan::new().with_frame_budget(5);
+    assert_eq!(PLAN.frame_count(), 5);
+
+    // 32 µs per frame at 1 MHz, plus 10 µs between frames.
+    assert_eq!(PLAN.estimated_bus_time_ns(1_000_000, &Timings::new()), 5 * 42_000);
+    assert_eq!(PLAN.estimated_bus_time_ns(100_000, &Timings::new()), 5 * 330_000);
+  }
+
+  #[cfg(feature = "float")]
+  #[test]
+  fn test_single_axis_plan() {
+    const PLAN: ReadPlan<InclinationZ> = ReadPlan::new();
+    assert_eq!(PLAN.len, 2);
+    assert!(PLAN.reads_angles());
+
+    let mut sim = Scl3300Sim::new();
+    sim.set_acceleration(0.0, 0.5, 1.0);
+    sim.set_inclination(0.0, 0.0, 60.0);
+
+    let mut scl = Scl3300::new(&mut sim).start_up(MeasurementMode::Inclination).unwrap();
+    let (acc, inc) = scl.read::<(AccelerationY, InclinationZ)>().unwrap();
+    assert_eq!(acc.g(), Gs(0.5));
+    assert_eq!(inc.degrees().get().round(), 60.0);
+  }
+
+  #[cfg(feature = "float")]
+  #[test]
+  fn test_execute_lenient() {
+    let mut sim = Scl3300Sim::new();
+    sim.set_acceleration(0.0, 0.0, 1.0);
+
+    let mut scl = Scl3300::new(&mut sim).start_up(MeasurementMode::FullScale24).unwrap();
+
+    let (_, flags) = scl.read_lenient::<(Acceleration, Temperature)>().unwrap();
+    assert!(flags.is_empty());
+
+    scl.spi_mut().raise_status(Status::SAT);
+    let ((acc, _), flags) = scl.read_lenient::<(Acceleration, Temperature)>().unwrap();
+    assert_eq!(acc.z_g(), Gs(1.0));
+    assert_eq!(flags.len(), 4);
+    assert!(flags.contains(Output::AccelerationZ));
+  }
+
+  #[cfg(feature = "float")]
+  #[test]
+  fn test_execute_averaged() {
+    /// Alternates the simulated X-axis acceleration after every read.
+    struct Alternating(Scl3300Sim, usize);
+
+    impl embedded_hal::spi::ErrorType for Alternating {
+      type Error = core::convert::Infallible;
+    }
+
+    impl SpiDevice<u8> for Alternating {
+      fn transaction(&mut self, operations: &mut [embedded_hal::spi::Operation<'_, u8>]) -> Result<(), Self::Error> {
+        self.0.transaction(operations)?;
+        self.1 += 1;
+        self.0.set_acceleration(if self.1 % 4 < 2 { 0.1 } else { -0.3 }, 0.0, 1.0);
+        Ok(())
+      }
+    }
+
+    let mut scl = Scl3300::new(Alternating(Scl3300Sim::new(), 0)).start_up(MeasurementMode::FullScale12).unwrap();
+    let acc = scl.read_averaged::<AccelerationX, 4>().unwrap();
+    assert_eq!(acc.g(), Gs(-0.1));
+  }
+
+  #[cfg(feature = "float")]
+  #[test]
+  fn test_execute_averaged_status() {
+    /// Alternates the reported `STATUS` flags and the simulated X-axis acceleration after every `STATUS` read,
+    /// once enabled. The simulator itself never reports flags in a successful `STATUS` read.
+    struct Alternating(Scl3300Sim, Option<usize>);
+
+    impl embedded_hal::spi::ErrorType for Alternating {
+      type Error = core::convert::Infallible;
+    }
+
+    impl SpiDevice<u8> for Alternating {
+      fn transaction(&mut self, operations: &mut [embedded_hal::spi::Operation<'_, u8>]) -> Result<(), Self::Error> {
+        self.0.transaction(operations)?;
+
+        let Some(reads) = &mut self.1 else { return Ok(()) };
+        for operation in operations {
+          if let embedded_hal::spi::Operation::TransferInPlace(bytes) = operation {
+            let op = bytes[0];
+            if op & !0b11 == Operation::Read(Output::Status).to_frame().bytes[0] {
+              let status = if *reads % 2 == 0 { Status::PD } else { Status::SAT };
+              let [hi, lo] = status.bits().to_be_bytes();
+              bytes.copy_from_slice(&[op, hi, lo, crate::frame::crc8([op, hi, lo])]);
+
+              *reads += 1;
+              self.0.set_acceleration(if *reads % 2 == 0 { 0.1 } else { -0.3 }, 0.0, 1.0);
+            }
+          }
+        }
+
+        Ok(())
+      }
+    }
+
+    let mut sim = Scl3300Sim::new();
+    sim.set_acceleration(0.1, 0.0, 1.0);
+
+    let mut scl = Scl3300::new(Alternating(sim, None)).start_up(MeasurementMode::FullScale12).unwrap();
+    scl.spi_mut().1 = Some(0);
+
+    let plan = ReadPlan::<(AccelerationX, Status)>::new();
+    let (acc, status) = plan.execute_averaged::<_, _, 4>(&mut scl).unwrap();
+    assert_eq!(acc.g(), Gs(-0.1));
+    assert_eq!(status, Status::SAT);
+  }
+
+  #[test]
+  fn test_try_from_outputs() {
+    assert_eq!(ReadPlan::try_from_outputs(&[]).unwrap_err(), PlanError::Empty);
+    assert_eq!(
+      ReadPlan::try_from_outputs(&[Output::Status; MAX_OUTPUTS + 1]).unwrap_err(),
+      PlanError::TooManyOutputs { len: MAX_OUTPUTS + 1 }
+    );
+
+    let mut sim = Scl3300Sim::new();
+    sim.set_serial(1021704154);
+
+    let mut scl = Scl3300::new(&mut sim).start_up(MeasurementMode::FullScale12).unwrap();
+
+    let plan = ReadPlan::try_from_outputs(&[Output::Serial2, Output::WhoAmI, Output::Serial1]).unwrap();
+    let mut dest = [0; 3];
+    plan.execute_into(&mut scl, &mut dest).unwrap();
+    assert_eq!(dest, [15589, 0x00C1, 63450]);
+    assert!(matches!(plan.execute_into(&mut scl, &mut [0; 2]), Err(Error::BufferSize)));
+
+    let plan = ReadPlan::try_from_outputs(&[Output::AngleX]).unwrap();
+    plan.validate(&scl).unwrap();
+    scl.disable_angle_outputs().unwrap();
+    assert_eq!(plan.validate(&scl), Err(PlanError::AnglesDisabled));
+    assert!(matches!(plan.execute_into(&mut scl, &mut [0]), Err(Error::Plan(PlanError::AnglesDisabled))));
+  }
+
+  #[cfg(feature = "float")]
+  #[test]
+  fn test_deferred_flush() {
+    /// Counts the transactions.
+    struct Counting(Scl3300Sim, usize);
+
+    impl embedded_hal::spi::ErrorType for Counting {
+      type Error = core::convert::Infallible;
+    }
+
+    impl SpiDevice<u8> for Counting {
+      fn transaction(&mut self, operations: &mut [embedded_hal::spi::Operation<'_, u8>]) -> Result<(), Self::Error> {
+        self.1 += 1;
+        self.0.transaction(operations)
+      }
+    }
+
+    let mut sim = Scl3300Sim::new();
+    sim.set_acceleration(0.0, 0.0, 1.0);
+    sim.set_temperature(25.0);
+    sim.set_serial(1021704154);
+
+    let mut scl = Scl3300::builder(Counting(sim, 0))
+      .mode(MeasurementMode::FullScale24)
+      .strict_validation(true)
+      .deferred_flush(true)
+      .build_and_start()
+      .unwrap();
+
+    for frames in [5, 4, 4] {
+      scl.spi.1 = 0;
+      let (acc, temp) = scl.read::<(Acceleration, Temperature)>().unwrap();
+      assert_eq!(acc.z_g(), Gs(1.0));
+      assert_eq!(temp.degrees_celsius().get().round(), 25.0);
+      assert_eq!(scl.spi.1, frames);
+    }
+
+    // Plans reading bank 1 are not deferred, and consume the pending response.
+    let serial = scl.read::<Serial>().unwrap();
+    assert_eq!(serial.to_u32(), 1021704154);
+    assert_eq!(scl.spi.0.bank(), 0);
+
+    scl.spi.1 = 0;
+    let (_, temp) = scl.read::<(Acceleration, Temperature)>().unwrap();
+    assert_eq!(temp.degrees_celsius().get().round(), 25.0);
+    assert_eq!(scl.spi.1, 5);
+  }
+
+  #[cfg(feature = "float")]
+  #[test]
+  fn test_bank_switching() {
+    let mut sim = Scl3300Sim::new();
+    sim.set_serial(1021704154);
+    sim.set_acceleration(0.0, 0.0, 1.0);
+
+    let mut scl = Scl3300::new(&mut sim).start_up(MeasurementMode::FullScale24).unwrap();
+
+    let (serial, acc) = ReadPlan::<(Serial, Acceleration)>::new().execute(&mut scl).unwrap();
+    assert_eq!(serial.to_u32(), 1021704154);
+    assert_eq!(acc.z_g(), Gs(1.0));
+    assert_eq!(scl.spi.bank(), 0);
+  }
+
+  #[cfg(feature = "float")]
+  #[test]
+  fn test_bank_grouping() {
+    const PLAN: ReadPlan<(Serial, Temperature, Status)> = ReadPlan::new();
+
+    // Reads of bank 0 outputs, one switch to bank 1, two serial reads and the final switch back to bank 0.
+    assert_eq!(PLAN.len, 6);
+    assert_eq!(PLAN.frames[2].bytes, Operation::SwitchBank(Bank::One).to_frame().bytes);
+
+    let mut sim = Scl3300Sim::new();
+    sim.set_serial(1021704154);
+    sim.set_temperature(25.0);
+
+    let mut scl = Scl3300::new(&mut sim).start_up(MeasurementMode::FullScale12).unwrap();
+
+    let (serial, temp, status) = PLAN.execute(&mut scl).unwrap();
+    assert_eq!(serial.to_u32(), 1021704154);
+    assert_eq!(temp.degrees_celsius().get().round(), 25.0);
+    assert_eq!(status, Status::empty());
+    assert_eq!(scl.spi.bank(), 0);
+  }
+
+  #[cfg(feature = "float")]
+  #[test]
+  fn test_fill_tx_decode_rx() {
+    let mut sim = Scl3300Sim::new();
+    sim.set_acceleration(0.5, 0.0, -0.5);
+    sim.set_temperature(20.0);
+
+    Scl3300::new(&mut sim).start_up(MeasurementMode::Inclination).unwrap();
+
+    let plan = ReadPlan::<(Acceleration, Temperature)>::new();
+    let mut tx = [0; 20];
+    let mut rx = [0; 20];
+    assert!(matches!(plan.fill_tx(&mut tx[..16]), Err(Error::BufferSize)));
+    plan.fill_tx(&mut tx).unwrap();
+
+    for (rx, tx) in rx.chunks_exact_mut(4).zip(tx.chunks_exact(4)) {
+      sim.transfer(rx, tx).unwrap();
+    }
+
+    let (acc, temp) = plan.decode_rx(&rx, MeasurementMode::Inclination).unwrap();
+    assert_eq!((acc.x_g(), acc.z_g()), (Gs(0.5), Gs(-0.5)));
+    assert_eq!(temp.degrees_celsius().get().round(), 20.0);
+
+    // The first response belongs to a request sent before the plan, so its return status is ignored.
+    let first = Frame::with_crc([rx[0] | 0b11, rx[1], rx[2]]);
+    rx[..4].copy_from_slice(first.as_bytes());
+    assert!(plan.decode_rx(&rx, MeasurementMode::Inclination).is_ok());
+
+    rx[5] ^= 1;
+    assert!(matches!(plan.decode_rx(&rx, MeasurementMode::Inclination), Err(Error::Crc)));
+  }
+}
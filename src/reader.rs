@@ -0,0 +1,132 @@
+use embedded_hal::spi::SpiDevice;
+
+use crate::{Calibration, Error, MeasurementMode, Normal, OffFrameRead, OutputList, ReadPlan, Scl3300, MAX_OUTPUTS};
+
+/// A value borrowed by a [`Reader`] which is filled in when the reader finishes.
+trait Target {
+  fn outputs(&self) -> OutputList;
+
+  fn fill(&mut self, values: &[u16], mode: MeasurementMode, calibration: &Calibration);
+}
+
+impl<V: OffFrameRead> Target for V {
+  fn outputs(&self) -> OutputList {
+    V::OUTPUTS
+  }
+
+  fn fill(&mut self, values: &[u16], mode: MeasurementMode, calibration: &Calibration) {
+    *self = V::decode(values, mode).calibrate(calibration);
+  }
+}
+
+/// Reads multiple values into borrowed targets using a single read sequence.
+///
+/// Created using [`Scl3300::reader`]. Unlike reading a tuple using [`Scl3300::read`], the values are
+/// written into existing variables, and nothing is read until [`finish`](Reader::finish) is called.
+///
+/// ```rust
+/// # use embedded_hal::spi::SpiDevice;
+/// use scl3300::{mode::Normal, Acceleration, Error, Scl3300, Temperature};
+///
+/// fn update<SPI: SpiDevice>(
+///   scl: &mut Scl3300<SPI, Normal>,
+///   acc: &mut Acceleration,
+///   temp: &mut Temperature,
+/// ) -> Result<(), Error<SPI::Error>> {
+///   scl.reader().read(acc).read(temp).finish()
+/// }
+/// ```
+pub struct Reader<'a, 'b, SPI> {
+  scl: &'a mut Scl3300<SPI, Normal>,
+  outputs: OutputList,
+  targets: [Option<&'b mut dyn Target>; MAX_OUTPUTS],
+  len: usize,
+  overflow: bool,
+}
+
+impl<SPI> core::fmt::Debug for Reader<'_, '_, SPI> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    f.debug_struct("Reader").field("outputs", &self.outputs.as_slice()).finish()
+  }
+}
+
+impl<'a, 'b, SPI, E> Reader<'a, 'b, SPI>
+where
+  SPI: SpiDevice<u8, Error = E>,
+{
+  pub(crate) fn new(scl: &'a mut Scl3300<SPI, Normal>) -> Self {
+    Self { scl, outputs: OutputList::EMPTY, targets: [const { None }; MAX_OUTPUTS], len: 0, overflow: false }
+  }
+
+  /// Add a value to read into `target`.
+  ///
+  /// If the total number of outputs exceeds [`MAX_OUTPUTS`], [`finish`](Reader::finish) returns [`Error::BufferSize`].
+  pub fn read<V: OffFrameRead>(mut self, target: &'b mut V) -> Self {
+    if self.outputs.len() + V::OUTPUTS.len() > MAX_OUTPUTS {
+      self.overflow = true;
+    } else {
+      self.outputs = self.outputs.concat(&V::OUTPUTS);
+      self.targets[self.len] = Some(target);
+      self.len += 1;
+    }
+
+    self
+  }
+
+  /// Read all values and write them into their targets.
+  ///
+  /// If reading fails, no target is changed.
+  pub fn finish(self) -> Result<(), Error<E>> {
+    if self.overflow {
+      return Err(Error::BufferSize)
+    }
+
+    let mut values = [0; MAX_OUTPUTS];
+    ReadPlan::<()>::with_outputs(&self.outputs).execute_values(self.scl, &mut values)?;
+
+    let mode = self.scl.mode.mode;
+    let calibration = &self.scl.state.calibration;
+
+    let mut offset = 0;
+    for target in self.targets.into_iter().flatten() {
+      let len = target.outputs().len();
+      target.fill(&values[offset..(offset + len)], mode, calibration);
+      offset += len;
+    }
+
+    Ok(())
+  }
+}
+
+#[cfg(all(test, feature = "float"))]
+mod tests {
+  use super::*;
+
+  use crate::{sim::Scl3300Sim, Acceleration, Gs, Inclination, Serial, Temperature};
+
+  #[test]
+  fn test_reader() {
+    let mut sim = Scl3300Sim::new();
+    sim.set_acceleration(0.5, 0.0, 0.5);
+    sim.set_inclination(45.0, 0.0, 45.0);
+    sim.set_temperature(30.0);
+    sim.set_serial(1021704154);
+
+    let mut scl = Scl3300::new(&mut sim).start_up(MeasurementMode::Inclination).unwrap();
+
+    let mut acc = Acceleration::default();
+    let mut inc = Inclination::default();
+    let mut temp = Temperature::default();
+    let mut serial = Serial::default();
+    scl.reader().read(&mut acc).read(&mut serial).read(&mut inc).read(&mut temp).finish().unwrap();
+
+    assert_eq!(acc.x_g(), Gs(0.5));
+    assert_eq!(inc.x_degrees().get().round(), 45.0);
+    assert_eq!(temp.degrees_celsius().get().round(), 30.0);
+    assert_eq!(serial.to_u32(), 1021704154);
+
+    let mut accs = [const { Acceleration { x: 0, y: 0, z: 0, mode: MeasurementMode::Inclination } }; 11];
+    let reader = accs.iter_mut().fold(scl.reader(), |reader, acc| reader.read(acc));
+    assert!(matches!(reader.finish(), Err(Error::BufferSize)));
+  }
+}
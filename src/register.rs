@@ -0,0 +1,186 @@
+use embedded_hal::spi::SpiDevice;
+
+use crate::{Command, Error, Frame, Normal, Register, Scl3300};
+
+/// Raw register access.
+///
+/// This allows accessing registers which are not supported by the driver, e.g. undocumented registers
+/// or registers of derivative devices. Registers are addressed in the currently selected bank.
+pub trait RegisterInterface {
+  /// The error type.
+  type Error;
+
+  /// Read the register at the given address.
+  fn read_register(&mut self, address: u8) -> Result<u16, Self::Error>;
+
+  /// Write the given data to the register at the given address.
+  fn write_register(&mut self, address: u8, data: u16) -> Result<(), Self::Error>;
+
+  /// Read the `MODE` register.
+  fn read_command(&mut self) -> Result<Command, Self::Error> {
+    self.read_register(Command::ADDRESS).map(Command::from_bits)
+  }
+
+  /// Write the given command to the `MODE` register.
+  fn write_command(&mut self, command: Command) -> Result<(), Self::Error>;
+
+  /// Read the `MODE` register, modify it and write it back, returning the written command.
+  fn modify_command(&mut self, f: impl FnOnce(Command) -> Command) -> Result<Command, Self::Error>
+  where
+    Self: Sized,
+  {
+    let command = f(self.read_command()?);
+    self.write_command(command)?;
+    Ok(command)
+  }
+}
+
+/// Registers which hold state tracked by the driver and can therefore not be written directly.
+const TRACKED_REGISTERS: [Register; 3] = [Register::ANG_CTRL, Register::MODE, Register::SELBANK];
+
+/// Reject requests writing a register in [`TRACKED_REGISTERS`].
+fn check_untracked<E>(request: Frame) -> Result<(), Error<E>> {
+  let write = request.bytes[0] & 0x80 != 0;
+  let address = (request.bytes[0] >> 2) & 0x1F;
+
+  if write && TRACKED_REGISTERS.iter().any(|register| register.address == address) {
+    return Err(Error::InvalidRequest { frame: request.bytes })
+  }
+
+  Ok(())
+}
+
+/// Registers are accessed using the same frames as the driver, so responses are checked.
+/// Only the lower 5 bits of the address are used.
+///
+/// Writing the `ANG_CTRL`, `MODE` or `SELBANK` registers directly returns [`Error::InvalidRequest`],
+/// since the driver tracks their state. Commands are written using [`write_command`](RegisterInterface::write_command)
+/// instead, which changes the measurement mode like [`Scl3300::change_mode`]. Commands entering power down mode
+/// or performing a software reset are rejected, use [`Scl3300::power_down`] or [`Scl3300::reset`] instead.
+impl<SPI, E> RegisterInterface for Scl3300<SPI, Normal>
+where
+  SPI: SpiDevice<u8, Error = E>,
+{
+  type Error = Error<E>;
+
+  fn read_register(&mut self, address: u8) -> Result<u16, Self::Error> {
+    let request = Frame::request(false, address, 0);
+    self.transfer_frame(request, None)?;
+    let response = self.transfer_frame(request, None)?;
+    Ok(response.data())
+  }
+
+  fn write_register(&mut self, address: u8, data: u16) -> Result<(), Self::Error> {
+    let request = Frame::request(true, address, data);
+    check_untracked(request)?;
+
+    self.transfer_frame(request, None)?;
+    Ok(())
+  }
+
+  fn write_command(&mut self, command: Command) -> Result<(), Self::Error> {
+    if command.power_down || command.sw_reset {
+      return Err(Error::InvalidRequest { frame: command.to_frame().bytes })
+    }
+
+    self.change_mode(command.mode)?;
+    Ok(())
+  }
+}
+
+impl<SPI, E> Scl3300<SPI, Normal>
+where
+  SPI: SpiDevice<u8, Error = E>,
+{
+  /// Transfer a raw request frame and return the response, which belongs to the previous request.
+  ///
+  /// The request is rejected with [`Error::InvalidRequest`] before it is sent if its CRC does not match
+  /// or its `RS` bits are not zero, use [`protocol::with_crc`](crate::protocol::with_crc) to construct it.
+  /// As with [`RegisterInterface`], responses are checked and writes to the `ANG_CTRL`, `MODE` or `SELBANK`
+  /// registers are rejected.
+  pub fn transfer_raw(&mut self, request: [u8; 4]) -> Result<[u8; 4], Error<E>> {
+    let request = Frame { bytes: request };
+    request.check_request()?;
+    check_untracked(request)?;
+
+    Ok(self.transfer_frame(request, None)?.bytes)
+  }
+}
+
+impl<T> RegisterInterface for &mut T
+where
+  T: RegisterInterface + ?Sized,
+{
+  type Error = T::Error;
+
+  fn read_register(&mut self, address: u8) -> Result<u16, Self::Error> {
+    T::read_register(self, address)
+  }
+
+  fn write_register(&mut self, address: u8, data: u16) -> Result<(), Self::Error> {
+    T::write_register(self, address, data)
+  }
+
+  fn write_command(&mut self, command: Command) -> Result<(), Self::Error> {
+    T::write_command(self, command)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  use crate::{protocol, sim::Scl3300Sim, Acceleration, Bank, ComponentId, MeasurementMode};
+
+  #[test]
+  fn test_register_interface() {
+    let mut sim = Scl3300Sim::new();
+    let mut scl = Scl3300::new(&mut sim).start_up(MeasurementMode::FullScale12).unwrap();
+
+    assert_eq!(scl.read_register(0x10).unwrap(), ComponentId::WHOAMI.raw() as u16);
+
+    scl.write_register(0x1C, 0x1234).unwrap();
+
+    assert!(matches!(scl.write_register(0x0C, 0x0000), Err(Error::InvalidRequest { .. })));
+    assert!(scl.spi.angle_outputs_enabled() && scl.angle_outputs_enabled());
+
+    assert!(matches!(scl.write_register(0x1F, 0x0001), Err(Error::InvalidRequest { .. })));
+    assert_eq!(scl.current_bank().unwrap(), Bank::Zero);
+    assert_eq!(scl.state.bank, Bank::Zero);
+  }
+
+  #[test]
+  fn test_modify_command() {
+    let mut sim = Scl3300Sim::new();
+    let mut scl = Scl3300::new(&mut sim).start_up(MeasurementMode::FullScale12).unwrap();
+
+    assert_eq!(scl.read_command().unwrap(), Command::new(MeasurementMode::FullScale12));
+    let command = scl.modify_command(|command| Command { mode: MeasurementMode::FullScale24, ..command }).unwrap();
+    assert_eq!(command, Command::new(MeasurementMode::FullScale24));
+    assert_eq!(scl.spi.mode(), MeasurementMode::FullScale24);
+    assert_eq!(scl.read::<Acceleration>().unwrap().mode(), MeasurementMode::FullScale24);
+
+    let res = scl.modify_command(|command| command.with_power_down(true));
+    assert!(matches!(res, Err(Error::InvalidRequest { .. })));
+    assert!(!scl.spi.is_powered_down());
+    assert!(matches!(scl.write_register(Command::ADDRESS, 0x0020), Err(Error::InvalidRequest { .. })));
+  }
+
+  #[test]
+  fn test_transfer_raw() {
+    let mut sim = Scl3300Sim::new();
+    let mut scl = Scl3300::new(&mut sim).start_up(MeasurementMode::FullScale12).unwrap();
+
+    let request = protocol::with_crc([0x40, 0x00, 0x00]);
+    scl.transfer_raw(request).unwrap();
+    let response = scl.transfer_raw(request).unwrap();
+    assert_eq!(response[2], ComponentId::WHOAMI.raw());
+
+    assert!(matches!(scl.transfer_raw([0x40, 0x00, 0x00, 0x00]), Err(Error::InvalidRequest { .. })));
+    assert!(matches!(
+      scl.transfer_raw(protocol::with_crc([0x41, 0x00, 0x00])),
+      Err(Error::InvalidRequest { frame: [0x41, ..] })
+    ));
+    assert!(matches!(scl.transfer_raw(protocol::with_crc([0xB4, 0x00, 0x20])), Err(Error::InvalidRequest { .. })));
+  }
+}
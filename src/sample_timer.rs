@@ -0,0 +1,98 @@
+use embedded_hal::{delay::DelayNs, spi::SpiDevice};
+
+use crate::{Error, MeasurementMode, Normal, OffFrameRead, Scl3300};
+
+/// Paces reads at the output data rate of a [`MeasurementMode`].
+///
+/// Waiting at least one output data period between reads guarantees that every read returns a new sample.
+#[derive(Debug)]
+pub struct SampleTimer<D> {
+  delay: D,
+  period_ns: u32,
+  started: bool,
+}
+
+impl<D> SampleTimer<D>
+where
+  D: DelayNs,
+{
+  /// Create a timer pacing reads at the output data rate of the given mode.
+  pub fn new(delay: D, mode: MeasurementMode) -> Self {
+    Self::with_divisor(delay, mode, 1)
+  }
+
+  /// Create a timer pacing reads at the output data rate of the given mode divided by `divisor`.
+  ///
+  /// A `divisor` of `0` is treated as `1`.
+  pub fn with_divisor(delay: D, mode: MeasurementMode, divisor: u32) -> Self {
+    Self { delay, period_ns: mode.sample_period_ns().saturating_mul(divisor.max(1)), started: false }
+  }
+
+  /// Get the time between reads in nanoseconds.
+  pub fn period_ns(&self) -> u32 {
+    self.period_ns
+  }
+
+  /// Wait for the next sample.
+  ///
+  /// The first call returns immediately.
+  pub fn wait(&mut self) {
+    if self.started {
+      self.delay.delay_ns(self.period_ns);
+    }
+
+    self.started = true;
+  }
+
+  /// Wait for the next sample and read it.
+  pub fn read<SPI, E, V>(&mut self, scl: &mut Scl3300<SPI, Normal>) -> Result<V, Error<E>>
+  where
+    SPI: SpiDevice<u8, Error = E>,
+    V: OffFrameRead,
+  {
+    self.wait();
+    scl.read()
+  }
+
+  /// Release the delay.
+  pub fn release(self) -> D {
+    self.delay
+  }
+}
+
+#[cfg(all(test, feature = "float"))]
+mod tests {
+  use super::*;
+
+  use crate::{sim::Scl3300Sim, Acceleration, Gs};
+
+  #[derive(Debug, Default)]
+  struct Clock {
+    elapsed_ns: u64,
+  }
+
+  impl DelayNs for &mut Clock {
+    fn delay_ns(&mut self, ns: u32) {
+      self.elapsed_ns += ns as u64;
+    }
+  }
+
+  #[test]
+  fn test_sample_timer() {
+    let mut sim = Scl3300Sim::new();
+    sim.set_acceleration(0.0, 0.0, 1.0);
+    let mut clock = Clock::default();
+
+    let mut scl = Scl3300::new(&mut sim).start_up(MeasurementMode::FullScale24).unwrap();
+    let mut timer = SampleTimer::with_divisor(&mut clock, MeasurementMode::FullScale24, 4);
+    assert_eq!(timer.period_ns(), 2_000_000);
+
+    for _ in 0..3 {
+      let acc = timer.read::<_, _, Acceleration>(&mut scl).unwrap();
+      assert_eq!(acc.z_g(), Gs(1.0));
+    }
+
+    timer.release();
+    assert_eq!(clock.elapsed_ns, 2 * 2_000_000);
+  }
+}
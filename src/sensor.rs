@@ -0,0 +1,90 @@
+use embedded_hal::spi::SpiDevice;
+
+use crate::{Acceleration, Degrees, Error, Gs, Inclination, Normal, Scl3300};
+
+/// A sensor measuring inclination angles.
+///
+/// This allows application code to abstract over different inclinometers.
+pub trait InclinationSensor {
+  /// The error type returned when reading fails.
+  type Error;
+
+  /// Read the inclination angles of the X, Y and Z axes.
+  fn read_inclination(&mut self) -> Result<[Degrees; 3], Self::Error>;
+}
+
+/// A sensor measuring acceleration.
+///
+/// This allows application code to abstract over different accelerometers.
+pub trait AccelerationSensor {
+  /// The error type returned when reading fails.
+  type Error;
+
+  /// Read the acceleration of the X, Y and Z axes.
+  fn read_acceleration(&mut self) -> Result<[Gs; 3], Self::Error>;
+}
+
+impl<T: InclinationSensor + ?Sized> InclinationSensor for &mut T {
+  type Error = T::Error;
+
+  fn read_inclination(&mut self) -> Result<[Degrees; 3], Self::Error> {
+    T::read_inclination(self)
+  }
+}
+
+impl<T: AccelerationSensor + ?Sized> AccelerationSensor for &mut T {
+  type Error = T::Error;
+
+  fn read_acceleration(&mut self) -> Result<[Gs; 3], Self::Error> {
+    T::read_acceleration(self)
+  }
+}
+
+impl<SPI, E> InclinationSensor for Scl3300<SPI, Normal>
+where
+  SPI: SpiDevice<u8, Error = E>,
+{
+  type Error = Error<E>;
+
+  fn read_inclination(&mut self) -> Result<[Degrees; 3], Self::Error> {
+    let inc: Inclination = self.read()?;
+    Ok([inc.x_degrees(), inc.y_degrees(), inc.z_degrees()])
+  }
+}
+
+impl<SPI, E> AccelerationSensor for Scl3300<SPI, Normal>
+where
+  SPI: SpiDevice<u8, Error = E>,
+{
+  type Error = Error<E>;
+
+  fn read_acceleration(&mut self) -> Result<[Gs; 3], Self::Error> {
+    let acc: Acceleration = self.read()?;
+    Ok([acc.x_g(), acc.y_g(), acc.z_g()])
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  use crate::{sim::Scl3300Sim, MeasurementMode};
+
+  fn is_level<S: InclinationSensor>(mut sensor: S) -> Result<bool, S::Error> {
+    let [x, y, _] = sensor.read_inclination()?;
+    Ok(x.get().round() == 0.0 && y.get().round() == 0.0)
+  }
+
+  #[test]
+  fn test_sensor_traits() {
+    let mut sim = Scl3300Sim::new();
+    sim.set_acceleration(0.0, 0.0, 1.0);
+
+    let mut scl = Scl3300::new(&mut sim).start_up(MeasurementMode::Inclination).unwrap();
+    assert!(is_level(&mut scl).unwrap());
+    assert_eq!(scl.read_acceleration().unwrap(), [Gs(0.0), Gs(0.0), Gs(1.0)]);
+
+    scl.spi_mut().set_inclination(5.0, 0.0, 85.0);
+    assert!(!is_level(&mut scl).unwrap());
+  }
+}
@@ -0,0 +1,75 @@
+use core::{cell::RefCell, fmt};
+
+use embassy_sync::blocking_mutex::{raw::RawMutex, Mutex};
+use embedded_hal::spi::SpiDevice;
+
+use crate::{Error, Normal, OffFrameRead, Scl3300};
+
+/// An [`Scl3300`] which can be shared between tasks, e.g. a control loop and telemetry.
+///
+/// Access is serialized using a blocking mutex, so all methods take `&self`. Calling methods of the
+/// same `SharedScl3300` from within [`lock`](SharedScl3300::lock) panics.
+pub struct SharedScl3300<M: RawMutex, SPI> {
+  inner: Mutex<M, RefCell<Scl3300<SPI, Normal>>>,
+}
+
+impl<M: RawMutex, SPI> SharedScl3300<M, SPI> {
+  /// Wrap a started inclinometer.
+  pub const fn new(scl: Scl3300<SPI, Normal>) -> Self {
+    Self { inner: Mutex::new(RefCell::new(scl)) }
+  }
+
+  /// Release the wrapped inclinometer.
+  pub fn into_inner(self) -> Scl3300<SPI, Normal> {
+    self.inner.into_inner().into_inner()
+  }
+
+  /// Get exclusive access to the inclinometer for the duration of `f`.
+  pub fn lock<R>(&self, f: impl FnOnce(&mut Scl3300<SPI, Normal>) -> R) -> R {
+    self.inner.lock(|scl| f(&mut scl.borrow_mut()))
+  }
+}
+
+impl<M: RawMutex, SPI> fmt::Debug for SharedScl3300<M, SPI> {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    f.debug_struct("SharedScl3300").finish_non_exhaustive()
+  }
+}
+
+impl<M, SPI, E> SharedScl3300<M, SPI>
+where
+  M: RawMutex,
+  SPI: SpiDevice<u8, Error = E>,
+{
+  /// Read a value, see [`Scl3300::read`].
+  pub fn read<V: OffFrameRead>(&self) -> Result<V, Error<E>> {
+    self.lock(|scl| scl.read())
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  use embassy_sync::blocking_mutex::raw::CriticalSectionRawMutex;
+
+  use crate::{sim::Scl3300Sim, MeasurementMode, Temperature};
+
+  #[test]
+  fn test_shared_read() {
+    let scl = Scl3300::new(Scl3300Sim::new()).start_up(MeasurementMode::Inclination).unwrap();
+    let shared = SharedScl3300::<CriticalSectionRawMutex, _>::new(scl);
+
+    std::thread::scope(|s| {
+      for _ in 0..4 {
+        s.spawn(|| {
+          for _ in 0..8 {
+            shared.read::<Temperature>().unwrap();
+          }
+        });
+      }
+    });
+
+    assert_eq!(shared.into_inner().spi().mode(), MeasurementMode::Inclination);
+  }
+}
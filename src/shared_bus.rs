@@ -0,0 +1,134 @@
+use core::cell::RefCell;
+
+use critical_section::Mutex;
+use embedded_hal::{delay::DelayNs, digital::OutputPin, spi::SpiBus};
+use embedded_hal_bus::spi::{CriticalSectionDevice, RefCellDevice};
+
+use crate::Scl3300;
+
+impl<'a, BUS, CS, D> Scl3300<RefCellDevice<'a, BUS, CS, D>>
+where
+  BUS: SpiBus,
+  CS: OutputPin,
+  D: DelayNs,
+{
+  /// Create a new `Scl3300` on an SPI bus shared within a single thread, e.g. with an external flash or display.
+  ///
+  /// Returns an error if the chip select pin cannot be set high.
+  pub fn on_shared_bus(bus: &'a RefCell<BUS>, cs: CS, delay: D) -> Result<Self, CS::Error> {
+    Ok(Scl3300::new(RefCellDevice::new(bus, cs, delay)?))
+  }
+}
+
+impl<'a, BUS, CS, D> Scl3300<CriticalSectionDevice<'a, BUS, CS, D>>
+where
+  BUS: SpiBus,
+  CS: OutputPin,
+  D: DelayNs,
+{
+  /// Create a new `Scl3300` on an SPI bus shared between interrupts or cores using a critical section.
+  ///
+  /// Returns an error if the chip select pin cannot be set high.
+  pub fn on_critical_section_bus(bus: &'a Mutex<RefCell<BUS>>, cs: CS, delay: D) -> Result<Self, CS::Error> {
+    Ok(Scl3300::new(CriticalSectionDevice::new(bus, cs, delay)?))
+  }
+}
+
+#[cfg(all(test, feature = "float"))]
+mod tests {
+  use core::{cell::Cell, convert::Infallible};
+
+  use embedded_hal::{
+    digital::ErrorType as PinErrorType,
+    spi::{ErrorType, Operation, SpiDevice},
+  };
+
+  use super::*;
+  use crate::{sim::Scl3300Sim, Acceleration, Gs, MeasurementMode};
+
+  /// An [`SpiBus`] forwarding frames to the simulator, which receives elapsed time from [`Delay`].
+  struct Bus<'a> {
+    sim: Scl3300Sim,
+    elapsed_ns: &'a Cell<u32>,
+  }
+
+  impl ErrorType for Bus<'_> {
+    type Error = Infallible;
+  }
+
+  impl SpiBus for Bus<'_> {
+    fn read(&mut self, words: &mut [u8]) -> Result<(), Self::Error> {
+      self.sim.transaction(&mut [Operation::DelayNs(self.elapsed_ns.take()), Operation::Read(words)])
+    }
+
+    fn write(&mut self, words: &[u8]) -> Result<(), Self::Error> {
+      self.sim.transaction(&mut [Operation::DelayNs(self.elapsed_ns.take()), Operation::Write(words)])
+    }
+
+    fn transfer(&mut self, read: &mut [u8], write: &[u8]) -> Result<(), Self::Error> {
+      self.sim.transaction(&mut [Operation::DelayNs(self.elapsed_ns.take()), Operation::Transfer(read, write)])
+    }
+
+    fn transfer_in_place(&mut self, words: &mut [u8]) -> Result<(), Self::Error> {
+      self.sim.transaction(&mut [Operation::DelayNs(self.elapsed_ns.take()), Operation::TransferInPlace(words)])
+    }
+
+    fn flush(&mut self) -> Result<(), Self::Error> {
+      Ok(())
+    }
+  }
+
+  struct Delay<'a>(&'a Cell<u32>);
+
+  impl DelayNs for Delay<'_> {
+    fn delay_ns(&mut self, ns: u32) {
+      self.0.set(self.0.get().saturating_add(ns));
+    }
+  }
+
+  struct Pin;
+
+  impl PinErrorType for Pin {
+    type Error = Infallible;
+  }
+
+  impl OutputPin for Pin {
+    fn set_low(&mut self) -> Result<(), Self::Error> {
+      Ok(())
+    }
+
+    fn set_high(&mut self) -> Result<(), Self::Error> {
+      Ok(())
+    }
+  }
+
+  fn sim() -> Scl3300Sim {
+    let mut sim = Scl3300Sim::new();
+    sim.set_acceleration(0.0, 0.5, 1.0);
+    sim
+  }
+
+  #[test]
+  fn test_on_shared_bus() {
+    let elapsed_ns = Cell::new(0);
+    let bus = RefCell::new(Bus { sim: sim(), elapsed_ns: &elapsed_ns });
+
+    let scl = Scl3300::on_shared_bus(&bus, Pin, Delay(&elapsed_ns)).unwrap();
+    let mut scl = scl.start_up(MeasurementMode::FullScale12).unwrap();
+
+    let acc: Acceleration = scl.read().unwrap();
+    assert_eq!((acc.y_g(), acc.z_g()), (Gs(0.5), Gs(1.0)));
+  }
+
+  #[test]
+  fn test_on_critical_section_bus() {
+    let elapsed_ns = Cell::new(0);
+    let bus = Mutex::new(RefCell::new(Bus { sim: sim(), elapsed_ns: &elapsed_ns }));
+
+    let scl = Scl3300::on_critical_section_bus(&bus, Pin, Delay(&elapsed_ns)).unwrap();
+    let mut scl = scl.start_up(MeasurementMode::FullScale12).unwrap();
+
+    let acc: Acceleration = scl.read().unwrap();
+    assert_eq!((acc.y_g(), acc.z_g()), (Gs(0.5), Gs(1.0)));
+  }
+}
@@ -0,0 +1,410 @@
+//! A software model of the SCL3300 for host-side testing.
+//!
+//! [`Scl3300Sim`] implements [`SpiDevice`] and answers requests the same way the real
+//! inclinometer does, including off-frame responses, register banks, CRC checking
+//! and start-up timing. Measurement outputs are synthesized from configurable values.
+//!
+//! ```rust
+//! # fn main() -> Result<(), scl3300::Error<core::convert::Infallible>> {
+//! use scl3300::{sim::Scl3300Sim, Acceleration, MeasurementMode, Scl3300};
+//!
+//! let mut sim = Scl3300Sim::new();
+//! sim.set_acceleration(0.0, 0.5, 0.8);
+//!
+//! let mut inclinometer = Scl3300::new(&mut sim).start_up(MeasurementMode::Inclination)?;
+//! let acc: Acceleration = inclinometer.read()?;
+//! # #[cfg(feature = "float")]
+//! assert_eq!(acc.y_g().get(), 0.5);
+//! # Ok(())
+//! # }
+//! ```
+
+use core::convert::Infallible;
+
+use embedded_hal::spi::{ErrorType, Operation as SpiOperation, SpiDevice};
+
+use crate::{frame::crc8, output::Status, MeasurementMode};
+
+const ACC_X: u8 = 0x01;
+const ACC_Y: u8 = 0x02;
+const ACC_Z: u8 = 0x03;
+const STO: u8 = 0x04;
+const TEMP: u8 = 0x05;
+const STATUS: u8 = 0x06;
+const ERR_FLAG1: u8 = 0x07;
+const ERR_FLAG2: u8 = 0x08;
+const ANG_X: u8 = 0x09;
+const ANG_Y: u8 = 0x0A;
+const ANG_Z: u8 = 0x0B;
+const ANG_CTRL: u8 = 0x0C;
+const MODE: u8 = 0x0D;
+const WHOAMI: u8 = 0x10;
+const SERIAL1: u8 = 0x19;
+const SERIAL2: u8 = 0x1A;
+const SELBANK: u8 = 0x1F;
+
+const MODE_PD: u16 = 0b0000_0100;
+const MODE_SW_RESET: u16 = 0b0010_0000;
+const ERR_FLAG2_PD: u16 = 0b00000100000000;
+
+const WRITE: u8 = 0x80;
+const RS_MASK: u8 = 0b11;
+const RS_STARTUP: u8 = 0b00;
+const RS_NORMAL: u8 = 0b01;
+const RS_ERROR: u8 = 0b11;
+
+/// The raw inclination value corresponding to 90°.
+const ANG_FACTOR: f32 = (1 << 14) as f32;
+
+/// A simulated SCL3300 inclinometer.
+#[derive(Debug, Clone)]
+pub struct Scl3300Sim {
+  acceleration: [f32; 3],
+  inclination: [f32; 3],
+  temperature: f32,
+  self_test: i16,
+  serial: u32,
+  component_id: u8,
+  mode: MeasurementMode,
+  angle_control: u16,
+  angle_control_stuck: bool,
+  bank: u8,
+  status: u16,
+  error1: u16,
+  error2: u16,
+  powered_down: bool,
+  settling_ns: u64,
+  start_up_delay_ns: u64,
+  response: [u8; 4],
+}
+
+impl Default for Scl3300Sim {
+  fn default() -> Self {
+    Self::new()
+  }
+}
+
+impl Scl3300Sim {
+  /// Create a new simulated inclinometer lying flat at room temperature.
+  ///
+  /// The simulated device starts in the same state as after power-on.
+  pub const fn new() -> Self {
+    let mode = MeasurementMode::new();
+
+    Self {
+      acceleration: [0.0, 0.0, 1.0],
+      inclination: [0.0, 0.0, 90.0],
+      temperature: 25.0,
+      self_test: 0,
+      serial: 0,
+      component_id: 0xC1,
+      mode,
+      angle_control: 0,
+      angle_control_stuck: false,
+      bank: 0,
+      status: Status::PWR.bits(),
+      error1: 0,
+      error2: 0,
+      powered_down: false,
+      settling_ns: mode.start_up_wait_time_ns().get() as u64,
+      start_up_delay_ns: 0,
+      response: [RS_ERROR, 0, 0, crc8([RS_ERROR, 0, 0])],
+    }
+  }
+
+  /// Set the simulated acceleration in g.
+  pub fn set_acceleration(&mut self, x: f32, y: f32, z: f32) {
+    self.acceleration = [x, y, z];
+  }
+
+  /// Set the simulated inclination angles in degrees.
+  pub fn set_inclination(&mut self, x: f32, y: f32, z: f32) {
+    self.inclination = [x, y, z];
+  }
+
+  /// Set the simulated temperature in °C.
+  pub fn set_temperature(&mut self, temperature: f32) {
+    self.temperature = temperature;
+  }
+
+  /// Set the raw self-test output.
+  pub fn set_self_test(&mut self, sto: i16) {
+    self.self_test = sto;
+  }
+
+  /// Set the serial number.
+  pub fn set_serial(&mut self, serial: u32) {
+    self.serial = serial;
+  }
+
+  /// Set the simulated component ID.
+  pub fn set_component_id(&mut self, id: u8) {
+    self.component_id = id;
+  }
+
+  /// Simulate a failure where writes to the `ANG_CTRL` register have no effect.
+  pub fn set_angle_control_stuck(&mut self, stuck: bool) {
+    self.angle_control_stuck = stuck;
+  }
+
+  /// Delay finishing start-up after a reset or mode change by the given time, e.g. to simulate a slow supply ramp.
+  pub fn set_start_up_delay_ns(&mut self, delay_ns: u32) {
+    self.start_up_delay_ns = delay_ns as u64;
+  }
+
+  /// Raise the given `STATUS` flags.
+  ///
+  /// The flags are reported in the return status of every response until `STATUS` is read.
+  pub fn raise_status(&mut self, status: Status) {
+    self.status |= status.bits();
+  }
+
+  /// Get the measurement mode the simulated device is currently in.
+  pub fn mode(&self) -> MeasurementMode {
+    self.mode
+  }
+
+  /// Check whether the simulated device is in power down mode.
+  pub fn is_powered_down(&self) -> bool {
+    self.powered_down
+  }
+
+  /// Check whether angle outputs are enabled.
+  pub fn angle_outputs_enabled(&self) -> bool {
+    self.angle_control == 0x1F
+  }
+
+  /// Get the currently selected register bank.
+  pub fn bank(&self) -> u8 {
+    self.bank
+  }
+
+  /// Set the currently selected register bank, e.g. to simulate another bus master switching banks.
+  pub fn set_bank(&mut self, bank: u8) {
+    self.bank = bank & 1;
+  }
+
+  fn advance(&mut self, ns: u32) {
+    self.settling_ns = self.settling_ns.saturating_sub(ns as u64);
+  }
+
+  fn reset(&mut self) {
+    self.mode = MeasurementMode::new();
+    self.angle_control = 0;
+    self.bank = 0;
+    self.status = Status::PWR.bits();
+    self.error1 = 0;
+    self.error2 = 0;
+    self.powered_down = false;
+    self.settling_ns = self.mode.start_up_wait_time_ns().get() as u64 + self.start_up_delay_ns;
+  }
+
+  fn change_mode(&mut self, mode: MeasurementMode) {
+    self.mode = mode;
+    self.powered_down = false;
+    self.error2 &= !ERR_FLAG2_PD;
+    self.status |= Status::MODE_CHANGE.bits();
+    self.settling_ns = mode.start_up_wait_time_ns().get() as u64 + self.start_up_delay_ns;
+  }
+
+  fn return_status(&self) -> u8 {
+    if self.settling_ns > 0 {
+      RS_STARTUP
+    } else if self.status != 0 {
+      RS_ERROR
+    } else {
+      RS_NORMAL
+    }
+  }
+
+  fn read_register(&mut self, address: u8) -> u16 {
+    let sensitivity = self.mode.acceleration_sensitivity() as f32;
+
+    match (self.bank, address) {
+      (_, SELBANK) => self.bank as u16,
+      (0, ACC_X) => to_raw(self.acceleration[0] * sensitivity),
+      (0, ACC_Y) => to_raw(self.acceleration[1] * sensitivity),
+      (0, ACC_Z) => to_raw(self.acceleration[2] * sensitivity),
+      (0, STO) => self.self_test as u16,
+      (0, TEMP) => to_raw((self.temperature + 273.0) * 18.9),
+      (0, STATUS) => core::mem::take(&mut self.status),
+      (0, ERR_FLAG1) => core::mem::take(&mut self.error1),
+      (0, ERR_FLAG2) => {
+        let error2 = self.error2;
+        self.error2 &= ERR_FLAG2_PD;
+        error2
+      },
+      (0, ANG_X | ANG_Y | ANG_Z) if !self.angle_outputs_enabled() => 0,
+      (0, ANG_X) => to_raw(self.inclination[0] / 90.0 * ANG_FACTOR),
+      (0, ANG_Y) => to_raw(self.inclination[1] / 90.0 * ANG_FACTOR),
+      (0, ANG_Z) => to_raw(self.inclination[2] / 90.0 * ANG_FACTOR),
+      (0, ANG_CTRL) => self.angle_control,
+      (0, MODE) => self.mode as u16 | if self.powered_down { MODE_PD } else { 0 },
+      (0, WHOAMI) => self.component_id as u16,
+      (1, SERIAL1) => self.serial as u16,
+      (1, SERIAL2) => (self.serial >> 16) as u16,
+      _ => 0,
+    }
+  }
+
+  fn write_register(&mut self, address: u8, data: u16) {
+    match (self.bank, address) {
+      (_, SELBANK) => self.bank = (data & 1) as u8,
+      (0, MODE) if data & MODE_PD != 0 => {
+        self.powered_down = true;
+        self.error2 |= ERR_FLAG2_PD;
+        self.status |= Status::PD.bits();
+      },
+      (0, MODE) => self.change_mode(match data & 0b11 {
+        0 => MeasurementMode::FullScale12,
+        1 => MeasurementMode::FullScale24,
+        2 => MeasurementMode::Inclination,
+        _ => MeasurementMode::InclinationLowNoise,
+      }),
+      (0, ANG_CTRL) if self.angle_control_stuck => (),
+      (0, ANG_CTRL) => self.angle_control = data,
+      _ => (),
+    }
+  }
+
+  /// Process a single request frame and return the response to the previous one.
+  fn exchange(&mut self, request: [u8; 4]) -> [u8; 4] {
+    let [op, data_hi, data_lo, crc] = request;
+    let address = (op >> 2) & 0x1F;
+    let data = u16::from_be_bytes([data_hi, data_lo]);
+
+    let (op, data) = if crc8([op, data_hi, data_lo]) != crc {
+      (op & !RS_MASK | RS_ERROR, 0)
+    } else if op & WRITE == 0 {
+      let rs = self.return_status();
+      (op & !RS_MASK | rs, self.read_register(address))
+    } else if address == MODE && data & MODE_SW_RESET != 0 {
+      self.reset();
+      (RS_ERROR, 0)
+    } else {
+      self.write_register(address, data);
+      (op & !RS_MASK | self.return_status(), data)
+    };
+
+    let [data_hi, data_lo] = data.to_be_bytes();
+    core::mem::replace(&mut self.response, [op, data_hi, data_lo, crc8([op, data_hi, data_lo])])
+  }
+
+  fn exchange_in_place(&mut self, words: &mut [u8]) {
+    for chunk in words.chunks_exact_mut(4) {
+      let request = [chunk[0], chunk[1], chunk[2], chunk[3]];
+      chunk.copy_from_slice(&self.exchange(request));
+    }
+  }
+}
+
+/// Round a scaled value to the nearest raw register value.
+fn to_raw(value: f32) -> u16 {
+  let rounded = if value < 0.0 { value - 0.5 } else { value + 0.5 };
+  (rounded as i32).clamp(i16::MIN as i32, u16::MAX as i32) as u16
+}
+
+impl ErrorType for Scl3300Sim {
+  type Error = Infallible;
+}
+
+impl SpiDevice<u8> for Scl3300Sim {
+  fn transaction(&mut self, operations: &mut [SpiOperation<'_, u8>]) -> Result<(), Self::Error> {
+    for operation in operations {
+      match operation {
+        SpiOperation::Read(words) => {
+          words.fill(0);
+          self.exchange_in_place(words);
+        },
+        SpiOperation::Write(words) => {
+          for chunk in words.chunks_exact(4) {
+            self.exchange([chunk[0], chunk[1], chunk[2], chunk[3]]);
+          }
+        },
+        SpiOperation::Transfer(read, write) => {
+          for (read, write) in read.chunks_exact_mut(4).zip(write.chunks_exact(4)) {
+            read.copy_from_slice(&self.exchange([write[0], write[1], write[2], write[3]]));
+          }
+        },
+        SpiOperation::TransferInPlace(words) => self.exchange_in_place(words),
+        SpiOperation::DelayNs(ns) => self.advance(*ns),
+      }
+    }
+
+    Ok(())
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  use crate::{Acceleration, Error, Scl3300};
+  #[cfg(feature = "float")]
+  use crate::{ComponentId, Gs, Inclination, Serial, Temperature};
+
+  #[cfg(feature = "float")]
+  #[test]
+  fn test_start_up_and_read() {
+    let mut sim = Scl3300Sim::new();
+    sim.set_acceleration(0.25, -0.5, 0.75);
+    sim.set_inclination(15.0, 330.0, 75.0);
+    sim.set_temperature(30.0);
+    sim.set_serial(1021704154);
+
+    let mut scl = Scl3300::new(&mut sim).start_up(MeasurementMode::Inclination).unwrap();
+
+    let (acc, inc, temp, serial, id): (Acceleration, Inclination, Temperature, Serial, ComponentId) =
+      scl.read().unwrap();
+    assert_eq!((acc.x_g(), acc.y_g(), acc.z_g()), (Gs(0.25), Gs(-0.5), Gs(0.75)));
+    assert_eq!(
+      (inc.x_degrees().get().round(), inc.y_degrees().get().round(), inc.z_degrees().get().round()),
+      (15.0, 330.0, 75.0)
+    );
+    assert_eq!(temp.degrees_celsius().get().round(), 30.0);
+    assert_eq!(serial.to_string(), "1021704154B33");
+    assert!(id.is_correct());
+
+    scl.power_down().unwrap();
+    assert!(sim.is_powered_down());
+    assert_eq!(sim.bank(), 0);
+
+    let _scl = Scl3300::new(&mut sim).start_up(MeasurementMode::FullScale24).unwrap();
+    assert!(!sim.is_powered_down());
+    assert_eq!(sim.mode(), MeasurementMode::FullScale24);
+  }
+
+  #[test]
+  fn test_status_flags() {
+    let mut sim = Scl3300Sim::new();
+
+    let mut scl = Scl3300::new(&mut sim).start_up(MeasurementMode::FullScale12).unwrap();
+    assert!(scl.read::<Acceleration>().is_ok());
+
+    scl.spi.raise_status(Status::SAT);
+    assert!(matches!(scl.read::<Acceleration>(), Err(Error::ReturnStatus { rs: 0b11, .. })));
+  }
+
+  #[test]
+  fn test_startup_in_progress() {
+    let mut sim = Scl3300Sim::new();
+
+    let reset = [0xB4, 0x00, 0x20, 0x98];
+    let read_status = [0x18, 0x00, 0x00, 0xE5];
+    sim.exchange(reset);
+    sim.exchange(read_status);
+    assert_eq!(sim.exchange(read_status)[0] & 0b11, RS_STARTUP);
+
+    sim.advance(MeasurementMode::FullScale12.start_up_wait_time_ns().get());
+    sim.exchange(read_status);
+    assert_eq!(sim.exchange(read_status), [0x19, 0x00, 0x00, 0x6A]);
+  }
+
+  #[test]
+  fn test_crc_error() {
+    let mut sim = Scl3300Sim::new();
+
+    sim.exchange([0x40, 0x00, 0x00, 0x00]);
+    assert_eq!(sim.exchange([0x40, 0x00, 0x00, 0x91])[0] & 0b11, RS_ERROR);
+  }
+}
@@ -0,0 +1,124 @@
+use std::{fmt, io, path::Path, vec::Vec};
+
+use embedded_hal::spi::{self, ErrorKind, ErrorType, Operation, SpiDevice};
+use spidev::{SpiModeFlags, Spidev, SpidevOptions, SpidevTransfer};
+
+use crate::Scl3300;
+
+/// The maximum SPI clock frequency used for the SCL3300.
+const MAX_SPEED_HZ: u32 = 4_000_000;
+
+/// An error returned by a [`SpidevDevice`].
+#[derive(Debug)]
+pub struct SpidevError(pub io::Error);
+
+impl fmt::Display for SpidevError {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    self.0.fmt(f)
+  }
+}
+
+impl std::error::Error for SpidevError {
+  fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+    Some(&self.0)
+  }
+}
+
+impl spi::Error for SpidevError {
+  fn kind(&self) -> ErrorKind {
+    ErrorKind::Other
+  }
+}
+
+/// An [`SpiDevice`] backed by a Linux `spidev` device.
+///
+/// Chip select is handled by the kernel and stays asserted for the whole transaction.
+#[derive(Debug)]
+pub struct SpidevDevice {
+  spidev: Spidev,
+}
+
+impl SpidevDevice {
+  /// Open the `spidev` device at the given `path` and configure it for the SCL3300,
+  /// i.e. SPI mode 0, 8 bits per word, MSB first, 4 MHz.
+  pub fn open(path: impl AsRef<Path>) -> io::Result<Self> {
+    let mut spidev = Spidev::open(path)?;
+    spidev.configure(
+      &SpidevOptions::new()
+        .mode(SpiModeFlags::SPI_MODE_0)
+        .bits_per_word(8)
+        .lsb_first(false)
+        .max_speed_hz(MAX_SPEED_HZ)
+        .build(),
+    )?;
+    Ok(Self { spidev })
+  }
+
+  /// Get the underlying `spidev` device.
+  pub fn spidev(&mut self) -> &mut Spidev {
+    &mut self.spidev
+  }
+}
+
+impl ErrorType for SpidevDevice {
+  type Error = SpidevError;
+}
+
+impl SpiDevice<u8> for SpidevDevice {
+  fn transaction(&mut self, operations: &mut [Operation<'_, u8>]) -> Result<(), Self::Error> {
+    // `spidev` needs separate buffers, so copy the data for in-place transfers.
+    let in_place: Vec<Vec<u8>> = operations
+      .iter()
+      .filter_map(|operation| match operation {
+        Operation::TransferInPlace(words) => Some(words.to_vec()),
+        _ => None,
+      })
+      .collect();
+    let mut in_place = in_place.iter();
+
+    let mut transfers = Vec::with_capacity(operations.len());
+    for operation in operations.iter_mut() {
+      match operation {
+        Operation::Read(words) => transfers.push(SpidevTransfer::read(words)),
+        Operation::Write(words) => transfers.push(SpidevTransfer::write(words)),
+        Operation::Transfer(read, write) => {
+          let len = read.len().min(write.len());
+          let (read, read_rest) = read.split_at_mut(len);
+          let (write, write_rest) = write.split_at(len);
+
+          transfers.push(SpidevTransfer::read_write(write, read));
+          if !read_rest.is_empty() {
+            transfers.push(SpidevTransfer::read(read_rest));
+          }
+          if !write_rest.is_empty() {
+            transfers.push(SpidevTransfer::write(write_rest));
+          }
+        },
+        Operation::TransferInPlace(words) => {
+          if let Some(write) = in_place.next() {
+            transfers.push(SpidevTransfer::read_write(write, words));
+          }
+        },
+        Operation::DelayNs(ns) => {
+          let mut us = ns.div_ceil(1000);
+          while us > 0 {
+            let delay = us.min(u16::MAX as u32);
+            transfers.push(SpidevTransfer::delay(delay as u16));
+            us -= delay;
+          }
+        },
+      }
+    }
+
+    self.spidev.transfer_multiple(&mut transfers).map_err(SpidevError)
+  }
+}
+
+impl Scl3300<SpidevDevice> {
+  /// Create a new `Scl3300` using the `spidev` device at the given `path`, e.g. `/dev/spidev0.0`.
+  ///
+  /// The device is configured with the correct SPI mode and clock frequency for the SCL3300.
+  pub fn open_spidev(path: impl AsRef<Path>) -> io::Result<Self> {
+    Ok(Self::new(SpidevDevice::open(path)?))
+  }
+}
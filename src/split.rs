@@ -0,0 +1,123 @@
+use embedded_hal::{
+  delay::DelayNs,
+  spi::{ErrorType, Operation, SpiDevice},
+};
+
+use crate::Scl3300;
+
+/// An [`SpiDevice`] adapter for hosts which cannot delay within a transaction,
+/// e.g. a bit-banged SPI bus with a software chip select.
+///
+/// Every operation of a transaction is issued as a separate transaction on the inner device,
+/// and delays are performed using the given `delay` in between. Since the driver only ever
+/// sends a single frame followed by a delay per transaction, this preserves the required
+/// pause between frames.
+#[derive(Debug)]
+pub struct SplitTransactions<SPI, D> {
+  spi: SPI,
+  delay: D,
+}
+
+impl<SPI, D> SplitTransactions<SPI, D> {
+  /// Create a new adapter using the given `spi` device and `delay`.
+  pub const fn new(spi: SPI, delay: D) -> Self {
+    Self { spi, delay }
+  }
+
+  /// Get a reference to the inner SPI device.
+  pub fn spi(&self) -> &SPI {
+    &self.spi
+  }
+
+  /// Get a mutable reference to the inner SPI device.
+  pub fn spi_mut(&mut self) -> &mut SPI {
+    &mut self.spi
+  }
+
+  /// Release the inner SPI device and the delay.
+  pub fn release(self) -> (SPI, D) {
+    (self.spi, self.delay)
+  }
+}
+
+impl<SPI, D> ErrorType for SplitTransactions<SPI, D>
+where
+  SPI: ErrorType,
+{
+  type Error = SPI::Error;
+}
+
+impl<SPI, D> SpiDevice<u8> for SplitTransactions<SPI, D>
+where
+  SPI: SpiDevice<u8>,
+  D: DelayNs,
+{
+  fn transaction(&mut self, operations: &mut [Operation<'_, u8>]) -> Result<(), Self::Error> {
+    for operation in operations {
+      match operation {
+        Operation::Read(words) => self.spi.read(words)?,
+        Operation::Write(words) => self.spi.write(words)?,
+        Operation::Transfer(read, write) => self.spi.transfer(read, write)?,
+        Operation::TransferInPlace(words) => self.spi.transfer_in_place(words)?,
+        Operation::DelayNs(ns) => self.delay.delay_ns(*ns),
+      }
+    }
+
+    Ok(())
+  }
+}
+
+impl<SPI, D> Scl3300<SplitTransactions<SPI, D>> {
+  /// Create a new `Scl3300` which issues every frame as a separate SPI transaction
+  /// and uses the given `delay` for the pauses between frames.
+  ///
+  /// See [`SplitTransactions`] for details.
+  pub const fn new_split(spi: SPI, delay: D) -> Self {
+    Scl3300::new(SplitTransactions::new(spi, delay))
+  }
+}
+
+#[cfg(all(test, feature = "float"))]
+mod tests {
+  use core::cell::RefCell;
+
+  use super::*;
+
+  use crate::{sim::Scl3300Sim, Inclination, MeasurementMode};
+
+  /// An SPI device which does not support delays within a transaction.
+  struct NoDelaySpi<'a>(&'a RefCell<Scl3300Sim>);
+
+  impl ErrorType for NoDelaySpi<'_> {
+    type Error = embedded_hal::spi::ErrorKind;
+  }
+
+  impl SpiDevice<u8> for NoDelaySpi<'_> {
+    fn transaction(&mut self, operations: &mut [Operation<'_, u8>]) -> Result<(), Self::Error> {
+      if operations.iter().any(|op| matches!(op, Operation::DelayNs(_))) {
+        return Err(embedded_hal::spi::ErrorKind::Other)
+      }
+
+      self.0.borrow_mut().transaction(operations).map_err(|err| match err {})
+    }
+  }
+
+  struct SimDelay<'a>(&'a RefCell<Scl3300Sim>);
+
+  impl DelayNs for SimDelay<'_> {
+    fn delay_ns(&mut self, ns: u32) {
+      self.0.borrow_mut().transaction(&mut [Operation::DelayNs(ns)]).unwrap();
+    }
+  }
+
+  #[test]
+  fn test_split_transactions() {
+    let sim = RefCell::new(Scl3300Sim::new());
+    sim.borrow_mut().set_inclination(30.0, 0.0, 60.0);
+
+    assert!(Scl3300::new(NoDelaySpi(&sim)).start_up(MeasurementMode::Inclination).is_err());
+
+    let mut scl = Scl3300::new_split(NoDelaySpi(&sim), SimDelay(&sim)).start_up(MeasurementMode::Inclination).unwrap();
+    assert_eq!(scl.read::<Inclination>().unwrap().x_degrees().get().round(), 30.0);
+  }
+}
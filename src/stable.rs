@@ -0,0 +1,74 @@
+use embedded_hal::spi::SpiDevice;
+
+use crate::{degrees_to_raw, Degrees, Error, Inclination, Normal, Scl3300};
+
+impl<SPI, E> Scl3300<SPI, Normal>
+where
+  SPI: SpiDevice<u8, Error = E>,
+{
+  /// Read the inclination until two consecutive samples differ by at most `tolerance` on every axis.
+  ///
+  /// Differences wrap around at 0°/360°, see [`Inclination::delta`]. Returns the last sample once it
+  /// is stable, or `None` if it did not settle within `timeout_reads` reads.
+  pub fn wait_until_stable(&mut self, tolerance: Degrees, timeout_reads: u32) -> Result<Option<Inclination>, Error<E>> {
+    let tolerance = degrees_to_raw(Degrees(tolerance.get().abs()));
+
+    if timeout_reads == 0 {
+      return Ok(None)
+    }
+
+    let mut previous: Inclination = self.read()?;
+    for _ in 1..timeout_reads {
+      let current: Inclination = self.read()?;
+      if current.delta(&previous).max_abs_delta_raw().1 <= tolerance {
+        return Ok(Some(current))
+      }
+      previous = current;
+    }
+
+    Ok(None)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  use embedded_hal::spi::{ErrorType, Operation};
+
+  use crate::{sim::Scl3300Sim, MeasurementMode};
+
+  /// Rotates the simulated X-axis by the given step in degrees after every transaction.
+  struct Rotating {
+    sim: Scl3300Sim,
+    x: f32,
+    step: f32,
+  }
+
+  impl ErrorType for Rotating {
+    type Error = <Scl3300Sim as ErrorType>::Error;
+  }
+
+  impl SpiDevice for Rotating {
+    fn transaction(&mut self, operations: &mut [Operation<'_, u8>]) -> Result<(), Self::Error> {
+      self.sim.transaction(operations)?;
+      self.x = (self.x + self.step) % 360.0;
+      self.step /= 2.0;
+      self.sim.set_inclination(self.x, 0.0, 0.0);
+      Ok(())
+    }
+  }
+
+  #[test]
+  fn test_wait_until_stable() {
+    let sim = Rotating { sim: Scl3300Sim::new(), x: 350.0, step: 0.0 };
+    let mut scl = Scl3300::new(sim).start_up(MeasurementMode::Inclination).unwrap();
+
+    scl.spi_mut().step = 8.0;
+    assert_eq!(scl.wait_until_stable(Degrees(0.5), 3).unwrap(), None);
+
+    let inclination = scl.wait_until_stable(Degrees(0.5), 10).unwrap().unwrap();
+    assert!((inclination.x_degrees().get() - 5.9).abs() < 0.2, "{:?}", inclination.x_degrees());
+    assert_eq!(scl.wait_until_stable(Degrees(0.5), 0).unwrap(), None);
+  }
+}
@@ -0,0 +1,154 @@
+use crate::MeasurementMode;
+
+/// How thoroughly the inclinometer is checked during start-up, trading boot time against diagnostic coverage.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum CheckDepth {
+  /// Do not check the return status after start-up or that angle outputs are enabled.
+  Minimal,
+  /// Check the return status after start-up and that angle outputs are enabled.
+  #[default]
+  Standard,
+  /// Additionally check the self-test output and the error flags, and read the component ID and serial number.
+  ///
+  /// The component ID is verified regardless of [`StartupConfig::verify_whoami`].
+  Extended,
+}
+
+/// Configuration for [`Scl3300::start_up`](crate::Scl3300::start_up).
+///
+/// A [`MeasurementMode`] can be converted into a default configuration for this mode.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct StartupConfig {
+  /// The measurement mode to start in.
+  pub mode: MeasurementMode,
+  /// Whether to verify the `WHOAMI` register after start-up.
+  pub verify_whoami: bool,
+  /// Whether to check that every response echoes the previous request.
+  ///
+  /// This catches missed clock edges, which otherwise result in silently wrong data,
+  /// by returning [`Error::ProtocolDesync`](crate::Error::ProtocolDesync).
+  pub strict_validation: bool,
+  /// How thoroughly the inclinometer is checked.
+  pub check_depth: CheckDepth,
+  /// How many times the return status is polled while start-up is still in progress, or `None` to use
+  /// [`MeasurementMode::start_up_attempts`].
+  pub start_up_attempts: Option<u8>,
+}
+
+impl StartupConfig {
+  /// Create a default configuration for the given measurement mode.
+  pub const fn new(mode: MeasurementMode) -> Self {
+    Self {
+      mode,
+      verify_whoami: true,
+      strict_validation: false,
+      check_depth: CheckDepth::Standard,
+      start_up_attempts: None,
+    }
+  }
+
+  /// Set whether to verify the `WHOAMI` register after start-up.
+  pub const fn with_verify_whoami(mut self, verify_whoami: bool) -> Self {
+    self.verify_whoami = verify_whoami;
+    self
+  }
+
+  /// Set whether to check that every response echoes the previous request.
+  pub const fn with_strict_validation(mut self, strict_validation: bool) -> Self {
+    self.strict_validation = strict_validation;
+    self
+  }
+
+  /// Set how thoroughly the inclinometer is checked.
+  pub const fn with_check_depth(mut self, check_depth: CheckDepth) -> Self {
+    self.check_depth = check_depth;
+    self
+  }
+
+  /// Set how many times the return status is polled while start-up is still in progress, e.g. for boards
+  /// with slow supply ramps.
+  ///
+  /// If start-up has not finished afterwards, [`Error::NeverLeftStartup`](crate::Error::NeverLeftStartup)
+  /// is returned. This is not used with [`CheckDepth::Minimal`].
+  pub const fn with_start_up_attempts(mut self, attempts: u8) -> Self {
+    self.start_up_attempts = Some(attempts);
+    self
+  }
+
+  /// Get how many times the return status is polled while start-up is still in progress.
+  pub const fn start_up_attempts(&self) -> u8 {
+    match self.start_up_attempts {
+      Some(attempts) => attempts,
+      None => self.mode.start_up_attempts(),
+    }
+  }
+}
+
+impl Default for StartupConfig {
+  fn default() -> Self {
+    Self::new(MeasurementMode::default())
+  }
+}
+
+impl From<MeasurementMode> for StartupConfig {
+  fn from(mode: MeasurementMode) -> Self {
+    Self::new(mode)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  use crate::{sim::Scl3300Sim, Error, Scl3300};
+
+  #[test]
+  fn test_wrong_device() {
+    let mut sim = Scl3300Sim::new();
+    sim.set_component_id(0x42);
+
+    let res = Scl3300::new(&mut sim).start_up(MeasurementMode::Inclination);
+    assert!(matches!(res, Err(Error::WrongDevice { found: 0x42 })));
+
+    let config = StartupConfig::new(MeasurementMode::Inclination).with_verify_whoami(false);
+    assert!(Scl3300::new(&mut sim).start_up(config).is_ok());
+  }
+
+  #[test]
+  fn test_slow_start_up() {
+    let mut sim = Scl3300Sim::new();
+    sim.set_start_up_delay_ns(50_000_000);
+    assert!(Scl3300::new(&mut sim).start_up(MeasurementMode::Inclination).is_ok());
+
+    let config = StartupConfig::new(MeasurementMode::Inclination).with_start_up_attempts(10);
+    let res = Scl3300::new(&mut sim).start_up(config);
+    assert!(matches!(res, Err(Error::NeverLeftStartup { attempts: 10 })));
+
+    let config = StartupConfig::new(MeasurementMode::FullScale12).with_start_up_attempts(60);
+    let res = Scl3300::new(&mut sim).start_up(config.with_check_depth(CheckDepth::Minimal));
+    assert!(matches!(res, Err(Error::Startup)));
+    assert!(Scl3300::new(&mut sim).start_up(config).is_ok());
+  }
+
+  #[test]
+  fn test_check_depth() {
+    let config = StartupConfig::new(MeasurementMode::Inclination);
+
+    let mut sim = Scl3300Sim::new();
+    sim.set_angle_control_stuck(true);
+    assert!(Scl3300::new(&mut sim).start_up(config.with_check_depth(CheckDepth::Minimal)).is_ok());
+
+    let mut sim = Scl3300Sim::new();
+    assert!(Scl3300::new(&mut sim).start_up(config.with_check_depth(CheckDepth::Extended)).is_ok());
+
+    sim.set_self_test(5000);
+    let res = Scl3300::new(&mut sim).start_up(config.with_check_depth(CheckDepth::Extended));
+    assert!(matches!(res, Err(Error::SelfTestFailed { self_test }) if self_test.raw() == 5000));
+    assert!(Scl3300::new(&mut sim).start_up(config).is_ok());
+
+    sim.set_self_test(0);
+    sim.set_component_id(0x42);
+    let res = Scl3300::new(&mut sim).start_up(config.with_verify_whoami(false).with_check_depth(CheckDepth::Extended));
+    assert!(matches!(res, Err(Error::WrongDevice { found: 0x42 })));
+  }
+}
@@ -0,0 +1,73 @@
+#[cfg(feature = "observer")]
+use crate::observer::ObserverRef;
+#[cfg(feature = "test-support")]
+use crate::FaultInjector;
+#[cfg(feature = "stats")]
+use crate::Stats;
+use crate::{Bank, Calibration, MeasurementMode, StartupConfig, Timings};
+
+/// Driver state carried across [`Scl3300`](crate::Scl3300) operation modes.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct DriverState {
+  /// Whether responses are checked to correspond to the previous request.
+  pub(crate) strict: bool,
+  /// The configuration of the last start-up, which is reused when waking up.
+  pub(crate) startup_config: StartupConfig,
+  /// The `RW` and `ADDR` bits of the previous request, which are echoed in the next response.
+  pub(crate) expected_echo: Option<u8>,
+  /// The register bank the inclinometer is assumed to be in.
+  pub(crate) bank: Bank,
+  /// How many times start-up and reads are retried after a transient error.
+  pub(crate) retries: u8,
+  /// After how many reads the `STATUS` register is checked, `0` if disabled.
+  pub(crate) status_check_interval: u8,
+  /// Whether the final bank switch of a read is replaced by the first frame of the next read.
+  pub(crate) deferred_flush: bool,
+  /// The last read request whose response has not been received yet, if its flush was deferred.
+  pub(crate) pending_read: Option<[u8; 4]>,
+  /// Whether the `STATUS` register is read automatically after an error return status.
+  pub(crate) read_status_on_error: bool,
+  /// The number of reads since the `STATUS` register was last checked.
+  pub(crate) reads_since_status_check: u8,
+  /// The declared SPI bus frequency in Hz, if any.
+  pub(crate) bus_frequency_hz: Option<u32>,
+  /// The timings used when communicating with the inclinometer.
+  pub(crate) timings: Timings,
+  /// The calibration applied to read values.
+  pub(crate) calibration: Calibration,
+  /// The observer notified about every frame.
+  #[cfg(feature = "observer")]
+  pub(crate) observer: Option<ObserverRef>,
+  /// Counters for monitoring the SPI link.
+  #[cfg(feature = "stats")]
+  pub(crate) stats: Stats,
+  /// The fault injector applied to every frame.
+  #[cfg(feature = "test-support")]
+  pub(crate) fault_injector: Option<FaultInjector>,
+}
+
+impl DriverState {
+  pub(crate) const fn new() -> Self {
+    Self {
+      strict: false,
+      startup_config: StartupConfig::new(MeasurementMode::new()),
+      expected_echo: None,
+      bank: Bank::Zero,
+      retries: 0,
+      status_check_interval: 0,
+      deferred_flush: false,
+      pending_read: None,
+      read_status_on_error: false,
+      reads_since_status_check: 0,
+      bus_frequency_hz: None,
+      timings: Timings::new(),
+      calibration: Calibration::new(),
+      #[cfg(feature = "observer")]
+      observer: None,
+      #[cfg(feature = "stats")]
+      stats: Stats::new(),
+      #[cfg(feature = "test-support")]
+      fault_injector: None,
+    }
+  }
+}
@@ -0,0 +1,181 @@
+use crate::{Axes, Axis};
+
+/// Round to the nearest raw value.
+fn round_raw(value: f32) -> u16 {
+  let rounded = if value < 0.0 { value - 0.5 } else { value + 0.5 };
+  rounded as i16 as u16
+}
+
+/// Accumulates per-axis statistics of [`Acceleration`](crate::Acceleration) or [`Inclination`](crate::Inclination)
+/// measurements using Welford's algorithm.
+///
+/// Raw values are treated as signed, so inclination angles range from -180° to 180°.
+#[derive(Debug, Clone)]
+pub struct Statistics<T> {
+  count: u32,
+  mean: [f32; 3],
+  m2: [f32; 3],
+  min: [i16; 3],
+  max: [i16; 3],
+  last: Option<T>,
+}
+
+impl<T> Default for Statistics<T> {
+  fn default() -> Self {
+    Self::new()
+  }
+}
+
+impl<T> Statistics<T> {
+  /// Create a new, empty accumulator.
+  pub const fn new() -> Self {
+    Self { count: 0, mean: [0.0; 3], m2: [0.0; 3], min: [i16::MAX; 3], max: [i16::MIN; 3], last: None }
+  }
+
+  /// Get the number of accumulated measurements.
+  pub fn count(&self) -> u32 {
+    self.count
+  }
+
+  /// Reset the accumulator.
+  pub fn reset(&mut self) {
+    *self = Self::new();
+  }
+}
+
+impl<T> Statistics<T>
+where
+  T: Axes + Clone,
+{
+  /// Add a measurement.
+  pub fn update(&mut self, value: &T) {
+    self.count = self.count.saturating_add(1);
+    let count = self.count as f32;
+
+    for (i, raw) in value.raw_axes().into_iter().enumerate() {
+      let raw = raw as i16;
+      let x = raw as f32;
+
+      let delta = x - self.mean[i];
+      self.mean[i] += delta / count;
+      self.m2[i] += delta * (x - self.mean[i]);
+
+      self.min[i] = self.min[i].min(raw);
+      self.max[i] = self.max[i].max(raw);
+    }
+
+    self.last = Some(value.clone());
+  }
+
+  /// Get a summary of the accumulated measurements, if any.
+  pub fn summary(&self) -> Option<StatisticsSummary<T>> {
+    let last = self.last.as_ref()?;
+
+    Some(StatisticsSummary {
+      count: self.count,
+      min: last.with_raw_axes(self.min.map(|min| min as u16)),
+      max: last.with_raw_axes(self.max.map(|max| max as u16)),
+      mean: self.mean,
+      variance: self.m2.map(|m2| m2 / self.count as f32),
+      template: last.clone(),
+    })
+  }
+}
+
+/// A summary of the statistics accumulated by [`Statistics`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct StatisticsSummary<T> {
+  count: u32,
+  min: T,
+  max: T,
+  mean: [f32; 3],
+  variance: [f32; 3],
+  template: T,
+}
+
+impl<T> StatisticsSummary<T>
+where
+  T: Axes,
+{
+  /// Get the number of measurements.
+  pub fn count(&self) -> u32 {
+    self.count
+  }
+
+  /// Get the per-axis minimum.
+  pub fn min(&self) -> &T {
+    &self.min
+  }
+
+  /// Get the per-axis maximum.
+  pub fn max(&self) -> &T {
+    &self.max
+  }
+
+  /// Get the per-axis mean, rounded to the nearest raw value.
+  pub fn mean(&self) -> T {
+    self.template.with_raw_axes(self.mean.map(round_raw))
+  }
+
+  /// Get the raw mean on the given axis.
+  pub fn mean_raw(&self, axis: Axis) -> f32 {
+    self.mean[axis as usize]
+  }
+
+  /// Get the raw population variance on the given axis.
+  pub fn variance_raw(&self, axis: Axis) -> f32 {
+    self.variance[axis as usize]
+  }
+
+  /// Get the raw population standard deviation on the given axis.
+  #[cfg(feature = "libm")]
+  pub fn stddev_raw(&self, axis: Axis) -> f32 {
+    libm::sqrtf(self.variance_raw(axis))
+  }
+
+  /// Get the per-axis population standard deviation, rounded to the nearest raw value.
+  #[cfg(feature = "libm")]
+  pub fn stddev(&self) -> T {
+    self.template.with_raw_axes(self.variance.map(|variance| round_raw(libm::sqrtf(variance))))
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  use crate::{Acceleration, Degrees, Gs, Inclination, MeasurementMode};
+
+  #[test]
+  fn test_acceleration_statistics() {
+    let mut stats = Statistics::new();
+    assert_eq!(stats.summary(), None);
+
+    for x in [2000i16, 4000, 4000, 4000, 5000, 5000, 7000, 9000] {
+      stats.update(&Acceleration { x: x as u16, y: 0, z: 6000, mode: MeasurementMode::FullScale12 });
+    }
+
+    let summary = stats.summary().unwrap();
+    assert_eq!(summary.count(), 8);
+    assert_eq!(summary.min().x_raw(), 2000);
+    assert_eq!(summary.max().x_raw(), 9000);
+    assert_eq!(summary.mean_raw(Axis::X), 5000.0);
+    assert_eq!(summary.mean().z_g(), Gs(1.0));
+    assert_eq!(summary.variance_raw(Axis::X), 4_000_000.0);
+    #[cfg(feature = "libm")]
+    assert_eq!(summary.stddev().x_raw(), 2000);
+  }
+
+  #[test]
+  fn test_inclination_statistics() {
+    let mut stats = Statistics::new();
+
+    for x in [-100i16, 100] {
+      stats.update(&Inclination { x: x as u16, y: 0, z: 0x4000, ..Default::default() });
+    }
+
+    let summary = stats.summary().unwrap();
+    assert_eq!(summary.mean().x_degrees(), Degrees(0.0));
+    assert_eq!(summary.min().x_raw() as i16, -100);
+  }
+}
@@ -0,0 +1,80 @@
+use crate::Error;
+
+/// Counters for monitoring the health of the SPI link.
+///
+/// See [`Scl3300::stats`](crate::Scl3300::stats).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Stats {
+  /// The number of frames sent.
+  pub frames: u32,
+  /// The number of responses with a CRC mismatch.
+  pub crc_errors: u32,
+  /// The number of responses with an error return status.
+  pub return_status_errors: u32,
+  /// The number of retries after transient errors.
+  pub retries: u32,
+  /// The number of register bank switches.
+  pub bank_switches: u32,
+}
+
+impl Stats {
+  pub(crate) const fn new() -> Self {
+    Self { frames: 0, crc_errors: 0, return_status_errors: 0, retries: 0, bank_switches: 0 }
+  }
+
+  /// Count the error of a checked response frame, if any.
+  pub(crate) fn record<T, E>(&mut self, result: &Result<T, Error<E>>) {
+    match result {
+      Err(Error::Crc) => self.crc_errors = self.crc_errors.wrapping_add(1),
+      Err(Error::ReturnStatus { .. }) => self.return_status_errors = self.return_status_errors.wrapping_add(1),
+      _ => (),
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  use crate::{sim::Scl3300Sim, MeasurementMode, Scl3300, Serial, Status, Temperature};
+
+  #[test]
+  fn test_stats() {
+    let mut sim = Scl3300Sim::new();
+
+    let mut scl = Scl3300::new(&mut sim).start_up(MeasurementMode::Inclination).unwrap();
+    scl.reset_stats();
+
+    scl.read::<Temperature>().unwrap();
+    scl.read::<Serial>().unwrap();
+    scl.spi_mut().raise_status(Status::SAT);
+    assert!(scl.read::<Temperature>().is_err());
+
+    let stats = scl.stats();
+    assert_eq!(stats.frames, 2 + 5 + 1);
+    assert_eq!(stats.bank_switches, 2);
+    assert_eq!(stats.return_status_errors, 1);
+    assert_eq!(stats.crc_errors, 0);
+
+    scl.reset_stats();
+    assert_eq!(scl.stats(), Stats::default());
+  }
+
+  #[cfg(feature = "test-support")]
+  #[test]
+  fn test_stats_retries() {
+    use crate::FaultInjector;
+
+    let mut sim = Scl3300Sim::new();
+
+    let mut scl = Scl3300::builder(&mut sim).mode(MeasurementMode::Inclination).retries(1).build_and_start().unwrap();
+    scl.reset_stats();
+    scl.set_fault_injector(Some(FaultInjector::new(1).with_crc_errors(1)));
+    assert!(scl.read::<Temperature>().is_err());
+
+    let stats = scl.stats();
+    assert_eq!(stats.crc_errors, 2);
+    assert_eq!(stats.retries, 1);
+  }
+}
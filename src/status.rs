@@ -0,0 +1,229 @@
+use crate::Status;
+
+/// Flags which changed between two [`Status`] reads.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct StatusChanges {
+  /// Flags which are newly set.
+  pub set: Status,
+  /// Flags which are newly cleared.
+  pub cleared: Status,
+}
+
+impl StatusChanges {
+  /// Check whether no flags changed.
+  pub const fn is_empty(&self) -> bool {
+    self.set.is_empty() && self.cleared.is_empty()
+  }
+}
+
+impl Status {
+  /// Flags indicating that measurements are not reliable.
+  ///
+  /// Mode change and power down flags are not included, since they are also set by
+  /// the driver's own operations.
+  pub const CRITICAL: Self = Self::DIGI1
+    .union(Self::DIGI2)
+    .union(Self::CLK)
+    .union(Self::SAT)
+    .union(Self::TEM_SAT)
+    .union(Self::PWR)
+    .union(Self::MEM)
+    .union(Self::PIN_CONTINUITY);
+
+  /// Get the flags which changed compared to the `previous` status.
+  pub const fn diff(&self, previous: &Status) -> StatusChanges {
+    StatusChanges { set: self.difference(*previous), cleared: previous.difference(*self) }
+  }
+}
+
+/// Tracks the `STATUS` register between reads and reports only transitions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct StatusMonitor {
+  last: Option<Status>,
+}
+
+impl StatusMonitor {
+  /// Create a new monitor.
+  pub const fn new() -> Self {
+    Self { last: None }
+  }
+
+  /// Get the last status passed to [`update`](StatusMonitor::update).
+  pub const fn last(&self) -> Option<Status> {
+    self.last
+  }
+
+  /// Process a new status.
+  ///
+  /// Returns the changes compared to the last status, if any. The first status is compared
+  /// against an empty status, so all set flags are reported.
+  pub fn update(&mut self, status: Status) -> Option<StatusChanges> {
+    let changes = status.diff(&self.last.unwrap_or(Status::empty()));
+    self.last = Some(status);
+
+    if changes.is_empty() {
+      None
+    } else {
+      Some(changes)
+    }
+  }
+
+  /// Forget the last status.
+  pub fn reset(&mut self) {
+    self.last = None;
+  }
+}
+
+/// An event decoded from the `STATUS` register by a [`StatusDecoder`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Event {
+  /// The inclinometer started up or its supply voltage failed, so its configuration may be lost (`PWR`).
+  PoweredUp,
+  /// The measurement mode was changed without being expected (`MODE_CHANGE`).
+  ModeChangedExternally,
+  /// The inclinometer entered power down mode without being expected (`PD`).
+  PoweredDown,
+  /// A digital block error occurred (`DIGI1` or `DIGI2`).
+  DigitalError,
+  /// A clock error occurred (`CLK`).
+  ClockError,
+  /// The signal path saturated (`SAT`).
+  Saturated,
+  /// The temperature signal path saturated (`TEM_SAT`).
+  TemperatureSaturated,
+  /// An error in the non-volatile memory occurred (`MEM`).
+  MemoryError,
+  /// A component internal connection error occurred (`PIN_CONTINUITY`).
+  PinContinuityError,
+}
+
+impl Event {
+  const FLAGS: [(Status, Event); 9] = [
+    (Status::PWR, Event::PoweredUp),
+    (Status::MODE_CHANGE, Event::ModeChangedExternally),
+    (Status::PD, Event::PoweredDown),
+    (Status::DIGI1.union(Status::DIGI2), Event::DigitalError),
+    (Status::CLK, Event::ClockError),
+    (Status::SAT, Event::Saturated),
+    (Status::TEM_SAT, Event::TemperatureSaturated),
+    (Status::MEM, Event::MemoryError),
+    (Status::PIN_CONTINUITY, Event::PinContinuityError),
+  ];
+
+  /// Get the flags this event is decoded from.
+  pub const fn flags(&self) -> Status {
+    let mut i = 0;
+    while i < Self::FLAGS.len() {
+      if Self::FLAGS[i].1 as u8 == *self as u8 {
+        return Self::FLAGS[i].0
+      }
+      i += 1;
+    }
+    unreachable!()
+  }
+}
+
+/// Decodes [`Event`]s from consecutive `STATUS` reads.
+///
+/// Since `STATUS` is cleared on read, `PWR` and `MODE_CHANGE` are reported every time they are set, while
+/// flags indicating a persistent condition are only reported when they become set. Flags caused by the
+/// application itself, e.g. `MODE_CHANGE` after changing the measurement mode, can be suppressed using
+/// [`expect`](StatusDecoder::expect).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct StatusDecoder {
+  monitor: StatusMonitor,
+  expected: Status,
+}
+
+impl StatusDecoder {
+  /// Create a new decoder.
+  pub const fn new() -> Self {
+    Self { monitor: StatusMonitor::new(), expected: Status::empty() }
+  }
+
+  /// Expect the given flags to be set in the next status, so they are not reported as events.
+  pub fn expect(&mut self, flags: Status) {
+    self.expected |= flags;
+  }
+
+  /// Process a new status and return the decoded events.
+  pub fn update(&mut self, status: Status) -> Events {
+    let latched = status & (Status::PWR | Status::MODE_CHANGE);
+    let set = self.monitor.update(status).map_or(Status::empty(), |changes| changes.set);
+
+    let flags = (latched | set).difference(self.expected);
+    self.expected = Status::empty();
+
+    Events { flags, i: 0 }
+  }
+
+  /// Forget the last status and any expected flags.
+  pub fn reset(&mut self) {
+    self.monitor.reset();
+    self.expected = Status::empty();
+  }
+}
+
+impl Default for StatusDecoder {
+  fn default() -> Self {
+    Self::new()
+  }
+}
+
+/// An iterator over the events decoded by [`StatusDecoder::update`].
+#[derive(Debug, Clone)]
+pub struct Events {
+  flags: Status,
+  i: usize,
+}
+
+impl Iterator for Events {
+  type Item = Event;
+
+  fn next(&mut self) -> Option<Event> {
+    while let Some(&(flags, event)) = Event::FLAGS.get(self.i) {
+      self.i += 1;
+      if self.flags.intersects(flags) {
+        return Some(event)
+      }
+    }
+
+    None
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_diff() {
+    let changes = (Status::SAT | Status::PD).diff(&(Status::PWR | Status::PD));
+    assert_eq!(changes, StatusChanges { set: Status::SAT, cleared: Status::PWR });
+  }
+
+  #[test]
+  fn test_monitor() {
+    let mut monitor = StatusMonitor::new();
+
+    assert_eq!(monitor.update(Status::PWR), Some(StatusChanges { set: Status::PWR, cleared: Status::empty() }));
+    assert_eq!(monitor.update(Status::PWR), None);
+    assert_eq!(monitor.update(Status::empty()), Some(StatusChanges { set: Status::empty(), cleared: Status::PWR }));
+    assert_eq!(monitor.last(), Some(Status::empty()));
+  }
+
+  #[test]
+  fn test_decoder() {
+    let mut decoder = StatusDecoder::new();
+
+    assert!(decoder.update(Status::PWR).eq([Event::PoweredUp]));
+    assert!(decoder.update(Status::PWR | Status::SAT).eq([Event::PoweredUp, Event::Saturated]));
+    assert!(decoder.update(Status::SAT | Status::DIGI2).eq([Event::DigitalError]));
+
+    decoder.expect(Status::MODE_CHANGE);
+    assert_eq!(decoder.update(Status::MODE_CHANGE).next(), None);
+    assert!(decoder.update(Status::MODE_CHANGE).eq([Event::ModeChangedExternally]));
+
+    assert_eq!(Event::DigitalError.flags(), Status::DIGI1 | Status::DIGI2);
+  }
+}
@@ -0,0 +1,140 @@
+use core::{
+  future::Future,
+  marker::PhantomData,
+  pin::Pin,
+  task::{Context, Poll},
+};
+
+use embedded_hal::spi::SpiDevice;
+use embedded_hal_async::delay::DelayNs;
+use futures_core::Stream;
+
+use crate::{Error, Normal, OffFrameRead, Scl3300};
+
+/// The output of a single sample future: the borrowed inclinometer and delay, and the read result.
+type Sample<'a, SPI, D, V> =
+  (&'a mut Scl3300<SPI, Normal>, &'a mut D, Result<V, Error<<SPI as embedded_hal::spi::ErrorType>::Error>>);
+
+async fn sample<'a, SPI, D, V>(
+  scl: &'a mut Scl3300<SPI, Normal>,
+  delay: &'a mut D,
+  delay_ns: u32,
+) -> Sample<'a, SPI, D, V>
+where
+  SPI: SpiDevice<u8>,
+  D: DelayNs,
+  V: OffFrameRead,
+{
+  if delay_ns > 0 {
+    delay.delay_ns(delay_ns).await;
+  }
+
+  let result = scl.read();
+  (scl, delay, result)
+}
+
+pin_project_lite::pin_project! {
+  /// A [`Stream`] of measurements taken at a fixed sample period.
+  ///
+  /// Created by [`Scl3300::measurements`]. SPI transfers are performed blocking,
+  /// only the wait between samples is asynchronous.
+  ///
+  /// The period is measured from the end of the previous read, so the actual sample period is longer by the
+  /// time a read takes and samples drift relative to a wall clock.
+  pub struct MeasurementStream<'a, SPI, D, V, F> {
+    #[pin]
+    future: F,
+    next: fn(&'a mut Scl3300<SPI, Normal>, &'a mut D, u32) -> F,
+    period_ns: u32,
+    _value: PhantomData<fn() -> V>,
+  }
+}
+
+impl<SPI, D, V, F> core::fmt::Debug for MeasurementStream<'_, SPI, D, V, F> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    f.debug_struct("MeasurementStream").field("period_ns", &self.period_ns).finish()
+  }
+}
+
+impl<'a, SPI, D, V, F> Stream for MeasurementStream<'a, SPI, D, V, F>
+where
+  SPI: SpiDevice<u8>,
+  F: Future<Output = Sample<'a, SPI, D, V>>,
+{
+  type Item = Result<V, Error<SPI::Error>>;
+
+  fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+    let mut this = self.project();
+
+    match this.future.as_mut().poll(cx) {
+      Poll::Ready((scl, delay, result)) => {
+        this.future.set((this.next)(scl, delay, *this.period_ns));
+        Poll::Ready(Some(result))
+      },
+      Poll::Pending => Poll::Pending,
+    }
+  }
+}
+
+impl<SPI, E> Scl3300<SPI, Normal>
+where
+  SPI: SpiDevice<u8, Error = E>,
+{
+  /// Get a [`Stream`] which reads a value every `period_ns` nanoseconds using the given asynchronous `delay`.
+  ///
+  /// The first value is read immediately. Every following read waits for `period_ns` after the previous read
+  /// finished, see [`MeasurementStream`].
+  pub fn measurements<'a, V, D>(
+    &'a mut self,
+    delay: &'a mut D,
+    period_ns: u32,
+  ) -> MeasurementStream<'a, SPI, D, V, impl Future<Output = Sample<'a, SPI, D, V>> + 'a>
+  where
+    V: OffFrameRead + 'a,
+    D: DelayNs + 'a,
+  {
+    let next = sample::<SPI, D, V>;
+    MeasurementStream { future: next(self, delay, 0), next, period_ns, _value: PhantomData }
+  }
+}
+
+#[cfg(all(test, feature = "float"))]
+mod tests {
+  use super::*;
+
+  use crate::{sim::Scl3300Sim, Acceleration, Gs, MeasurementMode};
+
+  #[derive(Debug, Default)]
+  struct Clock {
+    elapsed_ns: u64,
+  }
+
+  impl DelayNs for Clock {
+    async fn delay_ns(&mut self, ns: u32) {
+      self.elapsed_ns += ns as u64;
+    }
+  }
+
+  #[test]
+  fn test_measurements() {
+    let mut sim = Scl3300Sim::new();
+    sim.set_acceleration(0.0, 0.5, 0.5);
+
+    let mut scl = Scl3300::new(&mut sim).start_up(MeasurementMode::FullScale12).unwrap();
+    let mut clock = Clock::default();
+
+    let mut cx = Context::from_waker(core::task::Waker::noop());
+    {
+      let mut stream = core::pin::pin!(scl.measurements::<Acceleration, _>(&mut clock, 1_000_000));
+
+      for _ in 0..3 {
+        match stream.as_mut().poll_next(&mut cx) {
+          Poll::Ready(Some(Ok(acc))) => assert_eq!(acc.y_g(), Gs(0.5)),
+          res => panic!("unexpected result: {res:?}"),
+        }
+      }
+    }
+
+    assert_eq!(clock.elapsed_ns, 2_000_000);
+  }
+}
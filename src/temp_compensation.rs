@@ -0,0 +1,84 @@
+use embedded_hal::spi::SpiDevice;
+
+use crate::{output::degrees_to_raw, Axes, Axis, Celsius, Degrees, Error, Inclination, Normal, Scl3300, Temperature};
+
+/// Temperature compensation for inclination angles.
+///
+/// The correction for every axis is a polynomial of the temperature difference `ΔT` to the reference temperature:
+/// `linear · ΔT + quadratic · ΔT²`. The correction is subtracted from the measured angle.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TempCompensation {
+  /// The reference temperature, at which no correction is applied.
+  pub reference: Celsius,
+  /// The linear coefficients in °/°C for the X, Y and Z axes.
+  pub linear: [f32; 3],
+  /// The quadratic coefficients in °/°C² for the X, Y and Z axes.
+  pub quadratic: [f32; 3],
+}
+
+impl TempCompensation {
+  /// Create a compensation with the given reference temperature which does not change any values.
+  pub const fn new(reference: Celsius) -> Self {
+    Self { reference, linear: [0.0; 3], quadratic: [0.0; 3] }
+  }
+
+  /// Set the coefficients for the given axis.
+  pub const fn with_axis(mut self, axis: Axis, linear: f32, quadratic: f32) -> Self {
+    self.linear[axis as usize] = linear;
+    self.quadratic[axis as usize] = quadratic;
+    self
+  }
+
+  /// Get the correction for the given axis at the given temperature.
+  pub fn correction(&self, axis: Axis, temperature: Celsius) -> Degrees {
+    let dt = (temperature - self.reference).get();
+    Degrees(self.linear[axis as usize] * dt + self.quadratic[axis as usize] * dt * dt)
+  }
+
+  /// Apply the compensation to the given inclination, measured at the given temperature.
+  pub fn apply(&self, inclination: &Inclination, temperature: &Temperature) -> Inclination {
+    let temperature = temperature.degrees_celsius();
+    let raw = inclination.raw_axes();
+
+    inclination.with_raw_axes(core::array::from_fn(|i| {
+      raw[i].wrapping_sub(degrees_to_raw(self.correction(Axis::ALL[i], temperature)))
+    }))
+  }
+}
+
+impl<SPI, E> Scl3300<SPI, Normal>
+where
+  SPI: SpiDevice<u8, Error = E>,
+{
+  /// Read the inclination and temperature, and apply the given temperature compensation to the inclination.
+  pub fn read_compensated(&mut self, compensation: &TempCompensation) -> Result<(Inclination, Temperature), Error<E>> {
+    let (inclination, temperature): (Inclination, Temperature) = self.read()?;
+    Ok((compensation.apply(&inclination, &temperature), temperature))
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  use crate::{sim::Scl3300Sim, MeasurementMode};
+
+  #[test]
+  fn test_temp_compensation() {
+    let compensation =
+      TempCompensation::new(Celsius(25.0)).with_axis(Axis::X, 0.01, 0.001).with_axis(Axis::Y, -0.02, 0.0);
+    assert_eq!(compensation.correction(Axis::X, Celsius(35.0)), Degrees(0.2));
+    assert_eq!(compensation.correction(Axis::Z, Celsius(35.0)), Degrees(0.0));
+
+    let mut sim = Scl3300Sim::new();
+    sim.set_inclination(10.0, 0.0, 80.0);
+    sim.set_temperature(45.0);
+
+    let mut scl = Scl3300::new(&mut sim).start_up(MeasurementMode::Inclination).unwrap();
+    let (inc, temp) = scl.read_compensated(&compensation).unwrap();
+    assert_eq!(temp.degrees_celsius().get().round(), 45.0);
+    assert_eq!((inc.x_degrees().get() * 10.0).round(), 94.0);
+    assert_eq!((inc.y_degrees().get() * 10.0).round(), 4.0);
+    assert_eq!(inc.z_degrees().get().round(), 80.0);
+  }
+}
@@ -0,0 +1,128 @@
+use embedded_hal::spi::SpiDevice;
+
+use crate::{Error, Normal, OffFrameRead, ReadPlan, Scl3300, MAX_OUTPUTS};
+
+/// Reads a value one frame at a time, driven by a periodic timer.
+///
+/// Call [`on_tick`](TickAcquisition::on_tick) from a timer interrupt or task. Every call transfers a single
+/// frame, so reads never block the caller for longer than one frame, and a value is returned every
+/// [`frames_per_read`](TickAcquisition::frames_per_read) ticks.
+///
+/// If the tick period is at least the inter-frame time, the driver's own delay between frames can be
+/// removed by setting [`Timings::inter_frame_ns`](crate::Timings::inter_frame_ns) to `0`.
+///
+/// Unlike [`Scl3300::read`], reads are not retried and the `STATUS` register is not checked periodically.
+#[derive(Debug, Clone)]
+pub struct TickAcquisition<V> {
+  plan: ReadPlan<V>,
+  next: usize,
+  values: [u16; MAX_OUTPUTS],
+}
+
+impl<V: OffFrameRead> Default for TickAcquisition<V> {
+  fn default() -> Self {
+    Self::new()
+  }
+}
+
+impl<V: OffFrameRead> TickAcquisition<V> {
+  /// Create a new acquisition.
+  pub const fn new() -> Self {
+    Self { plan: ReadPlan::new(), next: 0, values: [0; MAX_OUTPUTS] }
+  }
+
+  /// Get the number of ticks needed for one read.
+  pub const fn frames_per_read(&self) -> usize {
+    self.plan.buffer_len() / 4
+  }
+
+  /// Abort the current read, so the next tick starts a new one.
+  pub fn reset(&mut self) {
+    self.next = 0;
+  }
+
+  /// Transfer the next frame, returning the value once a read is complete.
+  ///
+  /// After an error, the current read is aborted and the next tick starts a new one. If the aborted read
+  /// switched to bank 1, the first tick of the new read also switches back to bank 0.
+  pub fn on_tick<SPI, E>(&mut self, scl: &mut Scl3300<SPI, Normal>) -> Result<Option<V>, Error<E>>
+  where
+    SPI: SpiDevice<u8, Error = E>,
+  {
+    if self.next == 0 {
+      self.plan.validate(scl)?;
+
+      scl.restore_bank_zero()?;
+    }
+
+    match self.plan.step(scl, self.next, &mut self.values) {
+      Ok(None) => {
+        self.next += 1;
+        Ok(None)
+      },
+      result => {
+        self.next = 0;
+        result
+      },
+    }
+  }
+
+  /// Transfer the next frame, calling `f` with the value once a read is complete.
+  pub fn on_tick_with<SPI, E>(&mut self, scl: &mut Scl3300<SPI, Normal>, f: impl FnOnce(V)) -> Result<(), Error<E>>
+  where
+    SPI: SpiDevice<u8, Error = E>,
+  {
+    if let Some(value) = self.on_tick(scl)? {
+      f(value);
+    }
+
+    Ok(())
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  use crate::{sim::Scl3300Sim, Acceleration, MeasurementMode, Serial, Status, Temperature};
+
+  #[test]
+  fn test_tick_acquisition() {
+    let mut sim = Scl3300Sim::new();
+    sim.set_serial(0x1234_5678);
+
+    let mut scl = Scl3300::new(&mut sim).start_up(MeasurementMode::Inclination).unwrap();
+    let mut acquisition = TickAcquisition::<(Temperature, Serial)>::new();
+    assert_eq!(acquisition.frames_per_read(), 5);
+
+    let expected = scl.read::<(Temperature, Serial)>().unwrap();
+    for _ in 0..2 {
+      for _ in 0..4 {
+        assert_eq!(acquisition.on_tick(&mut scl).unwrap(), None);
+      }
+      assert_eq!(acquisition.on_tick(&mut scl).unwrap(), Some(expected));
+    }
+
+    // Abort the read after switching to bank 1.
+    acquisition.on_tick(&mut scl).unwrap();
+    acquisition.on_tick(&mut scl).unwrap();
+    assert_eq!(scl.spi().bank(), 1);
+    acquisition.reset();
+    for _ in 0..4 {
+      assert_eq!(acquisition.on_tick(&mut scl).unwrap(), None);
+    }
+    assert_eq!(acquisition.on_tick(&mut scl).unwrap(), Some(expected));
+
+    let mut acquisition = TickAcquisition::<Acceleration>::new();
+    scl.spi_mut().raise_status(Status::SAT);
+    acquisition.on_tick(&mut scl).unwrap();
+    assert!(acquisition.on_tick(&mut scl).is_err());
+    scl.read_lenient::<Status>().unwrap();
+
+    let mut count = 0;
+    for _ in 0..4 {
+      acquisition.on_tick_with(&mut scl, |_| count += 1).unwrap();
+    }
+    assert_eq!(count, 1);
+  }
+}
@@ -0,0 +1,111 @@
+use crate::{Axes, Axis, Inclination};
+
+/// Convert degrees to an absolute raw inclination value.
+fn degrees_to_raw(degrees: f32) -> u16 {
+  (degrees.abs() * Inclination::FACTOR / 90.0) as u16
+}
+
+/// An event emitted by a [`TiltAlarm`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TiltEvent {
+  /// The inclination exceeded the threshold on the given axis.
+  Triggered(Axis),
+  /// The inclination is back within the thresholds on all axes.
+  Cleared,
+}
+
+/// A tilt switch with per-axis thresholds and hysteresis.
+///
+/// Angles are treated as ranging from -180° to 180°, so an inclination of 359° is considered a tilt of 1°.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TiltAlarm {
+  thresholds: [Option<u16>; 3],
+  hysteresis: u16,
+  triggered: bool,
+}
+
+impl TiltAlarm {
+  /// Create a new tilt alarm with the given hysteresis in degrees.
+  ///
+  /// The alarm has no thresholds by default, use [`with_threshold`](TiltAlarm::with_threshold) to add them.
+  pub fn new(hysteresis: f32) -> Self {
+    Self { thresholds: [None; 3], hysteresis: degrees_to_raw(hysteresis), triggered: false }
+  }
+
+  /// Set the threshold in degrees for the given axis.
+  pub fn with_threshold(mut self, axis: Axis, threshold: f32) -> Self {
+    self.thresholds[axis as usize] = Some(degrees_to_raw(threshold));
+    self
+  }
+
+  /// Check whether the alarm is currently triggered.
+  pub fn is_triggered(&self) -> bool {
+    self.triggered
+  }
+
+  /// Process a new inclination measurement.
+  ///
+  /// Returns an event if the alarm state changed.
+  pub fn update(&mut self, inclination: &Inclination) -> Option<TiltEvent> {
+    let tilt = inclination.raw_axes().map(|raw| (raw as i16).unsigned_abs());
+
+    let mut axes = Axis::ALL.into_iter().zip(self.thresholds).zip(tilt);
+
+    if self.triggered {
+      let cleared = axes.all(|((_, threshold), tilt)| match threshold {
+        Some(threshold) => tilt < threshold.saturating_sub(self.hysteresis),
+        None => true,
+      });
+
+      if cleared {
+        self.triggered = false;
+        return Some(TiltEvent::Cleared)
+      }
+    } else {
+      let exceeded = axes.find_map(|((axis, threshold), tilt)| match threshold {
+        Some(threshold) if tilt > threshold => Some(axis),
+        _ => None,
+      });
+
+      if let Some(axis) = exceeded {
+        self.triggered = true;
+        return Some(TiltEvent::Triggered(axis))
+      }
+    }
+
+    None
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn inclination(x: f32, y: f32) -> Inclination {
+    let raw = |degrees: f32| (degrees * Inclination::FACTOR / 90.0) as i16 as u16;
+    Inclination { x: raw(x), y: raw(y), z: 0x4000, ..Default::default() }
+  }
+
+  #[test]
+  fn test_tilt_alarm() {
+    let mut alarm = TiltAlarm::new(1.0).with_threshold(Axis::X, 5.0).with_threshold(Axis::Y, 10.0);
+
+    assert_eq!(alarm.update(&inclination(4.0, 9.0)), None);
+    assert_eq!(alarm.update(&inclination(4.0, -10.5)), Some(TiltEvent::Triggered(Axis::Y)));
+    assert_eq!(alarm.update(&inclination(-6.0, 9.5)), None);
+    assert!(alarm.is_triggered());
+    assert_eq!(alarm.update(&inclination(-4.5, 8.5)), None);
+    assert_eq!(alarm.update(&inclination(-3.5, 8.5)), Some(TiltEvent::Cleared));
+    assert_eq!(alarm.update(&inclination(4.5, 8.5)), None);
+  }
+
+  #[test]
+  fn test_tilt_alarm_wrap_around() {
+    let mut alarm = TiltAlarm::new(0.0).with_threshold(Axis::X, 5.0);
+
+    let inc = Inclination { x: degrees_to_raw(359.0), y: 0, z: 0, ..Default::default() };
+    assert_eq!(alarm.update(&inc), None);
+    let inc = Inclination { x: degrees_to_raw(354.0), y: 0, z: 0, ..Default::default() };
+    assert_eq!(alarm.update(&inc), Some(TiltEvent::Triggered(Axis::X)));
+  }
+}
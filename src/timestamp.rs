@@ -0,0 +1,44 @@
+/// A monotonic clock.
+///
+/// This is implemented for all closures returning an instant, so any time library can be used, e.g.
+/// `|| embassy_time::Instant::now()`.
+pub trait Now {
+  /// The instant type returned by this clock.
+  type Instant;
+
+  /// Get the current instant.
+  fn now(&self) -> Self::Instant;
+}
+
+impl<F, I> Now for F
+where
+  F: Fn() -> I,
+{
+  type Instant = I;
+
+  #[inline]
+  fn now(&self) -> I {
+    self()
+  }
+}
+
+/// A value with the time of its acquisition.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Timestamped<T, I> {
+  /// The value.
+  pub value: T,
+  /// The instant at which the read of the value was started.
+  pub timestamp: I,
+}
+
+impl<T, I> Timestamped<T, I> {
+  /// Create a new timestamped value.
+  pub const fn new(value: T, timestamp: I) -> Self {
+    Self { value, timestamp }
+  }
+
+  /// Convert the value while keeping the timestamp.
+  pub fn map<U>(self, f: impl FnOnce(T) -> U) -> Timestamped<U, I> {
+    Timestamped { value: f(self.value), timestamp: self.timestamp }
+  }
+}
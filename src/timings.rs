@@ -0,0 +1,178 @@
+use core::num::NonZeroU32;
+
+#[cfg(feature = "fugit")]
+use fugit::{Duration, NanosDurationU32};
+
+use crate::MeasurementMode;
+
+/// Timings used when communicating with the inclinometer.
+///
+/// The defaults are the values from the datasheet. Longer timings can be needed e.g. with slow level shifters,
+/// shorter timings can be used e.g. on test benches.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Timings {
+  /// The minimum time between two frames in nanoseconds.
+  ///
+  /// This is the time chip select stays high between frames (`tLH` in the datasheet). It is needed
+  /// after every frame regardless of the SPI clock frequency, so it is never elided.
+  pub inter_frame_ns: u32,
+  /// The time to wait after a software reset in nanoseconds.
+  pub reset_ns: u32,
+  /// The time to wait after waking up from power down mode in nanoseconds.
+  pub wake_up_ns: u32,
+  /// The time for the outputs to settle after a mode change in nanoseconds, indexed by [`MeasurementMode`].
+  pub settling_ns: [u32; 4],
+}
+
+impl Default for Timings {
+  fn default() -> Self {
+    Self::new()
+  }
+}
+
+impl Timings {
+  /// Create timings with the datasheet defaults.
+  pub const fn new() -> Self {
+    Self {
+      inter_frame_ns: 10_000,
+      reset_ns: 1_000_000,
+      wake_up_ns: 1_000_000,
+      settling_ns: [
+        MeasurementMode::FullScale12.start_up_wait_time_ns().get(),
+        MeasurementMode::FullScale24.start_up_wait_time_ns().get(),
+        MeasurementMode::Inclination.start_up_wait_time_ns().get(),
+        MeasurementMode::InclinationLowNoise.start_up_wait_time_ns().get(),
+      ],
+    }
+  }
+
+  /// Get the settling time for the given mode in nanoseconds.
+  pub const fn settling_ns(&self, mode: MeasurementMode) -> u32 {
+    self.settling_ns[mode as usize]
+  }
+
+  /// Set the settling time for the given mode in nanoseconds.
+  pub const fn with_settling_ns(mut self, mode: MeasurementMode, ns: u32) -> Self {
+    self.settling_ns[mode as usize] = ns;
+    self
+  }
+
+  /// Set the minimum time between two frames.
+  ///
+  /// Panics if the duration exceeds `u32::MAX` nanoseconds, i.e. about 4.29 s.
+  #[cfg(feature = "fugit")]
+  pub const fn with_inter_frame<const NOM: u32, const DENOM: u32>(
+    mut self,
+    duration: Duration<u32, NOM, DENOM>,
+  ) -> Self {
+    self.inter_frame_ns = duration_to_ns(duration);
+    self
+  }
+
+  /// Set the time to wait after a software reset.
+  ///
+  /// Panics if the duration exceeds `u32::MAX` nanoseconds, i.e. about 4.29 s.
+  #[cfg(feature = "fugit")]
+  pub const fn with_reset<const NOM: u32, const DENOM: u32>(mut self, duration: Duration<u32, NOM, DENOM>) -> Self {
+    self.reset_ns = duration_to_ns(duration);
+    self
+  }
+
+  /// Set the time to wait after waking up from power down mode.
+  ///
+  /// Panics if the duration exceeds `u32::MAX` nanoseconds, i.e. about 4.29 s.
+  #[cfg(feature = "fugit")]
+  pub const fn with_wake_up<const NOM: u32, const DENOM: u32>(mut self, duration: Duration<u32, NOM, DENOM>) -> Self {
+    self.wake_up_ns = duration_to_ns(duration);
+    self
+  }
+
+  /// Get the settling time for the given mode.
+  #[cfg(feature = "fugit")]
+  pub const fn settling(&self, mode: MeasurementMode) -> NanosDurationU32 {
+    NanosDurationU32::from_ticks(self.settling_ns(mode))
+  }
+
+  /// Set the settling time for the given mode.
+  ///
+  /// Panics if the duration exceeds `u32::MAX` nanoseconds, i.e. about 4.29 s.
+  #[cfg(feature = "fugit")]
+  pub const fn with_settling<const NOM: u32, const DENOM: u32>(
+    self,
+    mode: MeasurementMode,
+    duration: Duration<u32, NOM, DENOM>,
+  ) -> Self {
+    self.with_settling_ns(mode, duration_to_ns(duration))
+  }
+
+  /// Get the time in nanoseconds needed to transfer a frame at the given bus frequency.
+  pub const fn frame_transfer_ns(bus_frequency_hz: u32) -> u32 {
+    if bus_frequency_hz == 0 {
+      return u32::MAX
+    }
+
+    (32 * 1_000_000_000 / bus_frequency_hz as u64) as u32
+  }
+
+  /// Get the delay after a frame, at least the inter-frame delay.
+  ///
+  /// The inter-frame delay is the minimum time the chip select stays high between frames, so it is
+  /// needed regardless of how long transferring a frame takes.
+  pub(crate) const fn delay_ns(&self, wait_ns: Option<NonZeroU32>) -> u32 {
+    match wait_ns {
+      Some(wait_ns) if wait_ns.get() > self.inter_frame_ns => wait_ns.get(),
+      _ => self.inter_frame_ns,
+    }
+  }
+}
+
+/// Convert a duration to nanoseconds.
+///
+/// Panics if the duration exceeds `u32::MAX` nanoseconds instead of silently overflowing.
+#[cfg(feature = "fugit")]
+pub(crate) const fn duration_to_ns<const NOM: u32, const DENOM: u32>(duration: Duration<u32, NOM, DENOM>) -> u32 {
+  match duration.const_try_into::<1, 1_000_000_000>() {
+    Some(duration) => duration.ticks(),
+    None => panic!("duration exceeds u32::MAX nanoseconds"),
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_delay() {
+    let timings = Timings::new();
+    assert_eq!(Timings::frame_transfer_ns(1_000_000), 32_000);
+
+    assert_eq!(timings.delay_ns(None), 10_000);
+    assert_eq!(timings.delay_ns(NonZeroU32::new(5_000)), 10_000);
+    assert_eq!(timings.delay_ns(NonZeroU32::new(1_000_000)), 1_000_000);
+  }
+
+  #[cfg(feature = "fugit")]
+  #[test]
+  fn test_durations() {
+    use fugit::{MicrosDurationU32, MillisDurationU32};
+
+    let timings = Timings::new()
+      .with_inter_frame(MicrosDurationU32::micros(20))
+      .with_reset(MillisDurationU32::millis(2))
+      .with_wake_up(NanosDurationU32::from_ticks(1_500_000))
+      .with_settling(MeasurementMode::Inclination, MillisDurationU32::millis(50));
+
+    assert_eq!(timings.inter_frame_ns, 20_000);
+    assert_eq!(timings.reset_ns, 2_000_000);
+    assert_eq!(timings.wake_up_ns, 1_500_000);
+    assert_eq!(timings.settling(MeasurementMode::Inclination), MillisDurationU32::millis(50));
+  }
+
+  #[cfg(feature = "fugit")]
+  #[test]
+  #[should_panic = "duration exceeds u32::MAX nanoseconds"]
+  fn test_duration_overflow() {
+    let _ = Timings::new().with_reset(fugit::SecsDurationU32::secs(5));
+  }
+}
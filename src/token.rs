@@ -0,0 +1,55 @@
+use embedded_hal::spi::SpiDevice;
+
+use crate::{DriverState, Error, Normal, PowerDown, Scl3300};
+
+/// The state of a powered down [`Scl3300`] whose SPI bus was released.
+///
+/// Created using [`Scl3300::power_down_release`], use [`resume`](Scl3300Token::resume) to rebuild the driver.
+#[derive(Debug)]
+pub struct Scl3300Token {
+  mode: PowerDown,
+  state: DriverState,
+}
+
+impl Scl3300Token {
+  /// Rebuild the powered down driver with the given SPI instance.
+  pub fn resume<SPI>(self, spi: SPI) -> Scl3300<SPI, PowerDown> {
+    Scl3300 { spi, mode: self.mode, state: self.state }
+  }
+}
+
+impl<SPI, E> Scl3300<SPI, Normal>
+where
+  SPI: SpiDevice<u8, Error = E>,
+{
+  /// Put the inclinometer into power down mode and release the SPI bus.
+  ///
+  /// The returned [`Scl3300Token`] keeps the driver state, so the bus can be used by other devices
+  /// while the inclinometer sleeps.
+  pub fn power_down_release(self) -> Result<(SPI, Scl3300Token), Error<E>> {
+    let Scl3300 { spi, mode, state } = self.power_down()?;
+    Ok((spi, Scl3300Token { mode, state }))
+  }
+}
+
+#[cfg(all(test, feature = "float"))]
+mod tests {
+  use super::*;
+
+  use crate::{sim::Scl3300Sim, Acceleration, Gs, MeasurementMode};
+
+  #[test]
+  fn test_power_down_release() {
+    let mut sim = Scl3300Sim::new();
+    sim.set_acceleration(0.0, 0.0, 1.0);
+
+    let scl = Scl3300::builder(&mut sim).mode(MeasurementMode::FullScale24).retries(1).build_and_start().unwrap();
+    let (spi, token) = scl.power_down_release().unwrap();
+    assert!(spi.is_powered_down());
+
+    let mut scl = token.resume(spi).wake_up_same_mode().unwrap();
+    assert_eq!(scl.state.retries, 1);
+    assert_eq!(scl.mode.mode, MeasurementMode::FullScale24);
+    assert_eq!(scl.read::<Acceleration>().unwrap().z_g(), Gs(1.0));
+  }
+}
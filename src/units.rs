@@ -0,0 +1,141 @@
+//! Unit types returned by conversion methods.
+
+use core::{
+  fmt,
+  ops::{Add, AddAssign, Div, Mul, Neg, Sub, SubAssign},
+};
+
+macro_rules! unit {
+  ($(#[$attr:meta])* $name:ident, $suffix:literal) => {
+    $(#[$attr])*
+    #[derive(Debug, Default, Clone, Copy, PartialEq, PartialOrd)]
+    pub struct $name(pub f32);
+
+    impl $name {
+      /// Get the value as a bare `f32`.
+      #[inline(always)]
+      pub const fn get(self) -> f32 {
+        self.0
+      }
+    }
+
+    impl From<$name> for f32 {
+      #[inline(always)]
+      fn from(value: $name) -> Self {
+        value.0
+      }
+    }
+
+    impl Add for $name {
+      type Output = Self;
+
+      #[inline]
+      fn add(self, rhs: Self) -> Self {
+        Self(self.0 + rhs.0)
+      }
+    }
+
+    impl AddAssign for $name {
+      #[inline]
+      fn add_assign(&mut self, rhs: Self) {
+        self.0 += rhs.0;
+      }
+    }
+
+    impl Sub for $name {
+      type Output = Self;
+
+      #[inline]
+      fn sub(self, rhs: Self) -> Self {
+        Self(self.0 - rhs.0)
+      }
+    }
+
+    impl SubAssign for $name {
+      #[inline]
+      fn sub_assign(&mut self, rhs: Self) {
+        self.0 -= rhs.0;
+      }
+    }
+
+    impl Neg for $name {
+      type Output = Self;
+
+      #[inline]
+      fn neg(self) -> Self {
+        Self(-self.0)
+      }
+    }
+
+    impl Mul<f32> for $name {
+      type Output = Self;
+
+      #[inline]
+      fn mul(self, rhs: f32) -> Self {
+        Self(self.0 * rhs)
+      }
+    }
+
+    impl Div<f32> for $name {
+      type Output = Self;
+
+      #[inline]
+      fn div(self, rhs: f32) -> Self {
+        Self(self.0 / rhs)
+      }
+    }
+
+    impl Div for $name {
+      type Output = f32;
+
+      #[inline]
+      fn div(self, rhs: Self) -> f32 {
+        self.0 / rhs.0
+      }
+    }
+
+    impl fmt::Display for $name {
+      fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.0, f)?;
+        f.write_str($suffix)
+      }
+    }
+  };
+}
+
+unit!(
+  /// Acceleration in g-force.
+  Gs,
+  "g"
+);
+
+unit!(
+  /// An angle in degrees.
+  Degrees,
+  "°"
+);
+
+unit!(
+  /// A temperature in degrees Celsius.
+  Celsius,
+  "°C"
+);
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_arithmetic() {
+    assert_eq!(Gs(0.5) + Gs(0.25), Gs(0.75));
+    assert_eq!(-(Degrees(90.0) - Degrees(45.0)) * 2.0, Degrees(-90.0));
+    assert_eq!(Celsius(30.0) / Celsius(20.0), 1.5);
+  }
+
+  #[test]
+  fn test_display() {
+    assert_eq!(Gs(0.5).to_string(), "0.5g");
+    assert_eq!(format!("{:.1}", Degrees(12.34)), "12.3°");
+    assert_eq!(Celsius(-4.0).to_string(), "-4°C");
+  }
+}
@@ -0,0 +1,212 @@
+#[cfg(feature = "float")]
+use crate::Gs;
+use crate::{Acceleration, Axes, Axis, MeasurementMode};
+
+/// Integer square root, rounded down.
+fn isqrt(value: u64) -> u64 {
+  if value < 2 {
+    return value
+  }
+
+  let mut x = value;
+  let mut y = x.div_ceil(2);
+  while y < x {
+    x = y;
+    y = (x + value / x) / 2;
+  }
+
+  x
+}
+
+/// Accumulates vibration metrics from [`Acceleration`] measurements.
+///
+/// All values are accumulated in raw units, so no floating point arithmetic is needed per sample.
+#[derive(Debug, Clone)]
+pub struct VibrationStats {
+  count: u32,
+  sum: [i64; 3],
+  sum_of_squares: [u64; 3],
+  min: [i16; 3],
+  max: [i16; 3],
+  mode: MeasurementMode,
+}
+
+impl Default for VibrationStats {
+  fn default() -> Self {
+    Self::new()
+  }
+}
+
+impl VibrationStats {
+  /// Create a new, empty accumulator.
+  pub const fn new() -> Self {
+    Self {
+      count: 0,
+      sum: [0; 3],
+      sum_of_squares: [0; 3],
+      min: [i16::MAX; 3],
+      max: [i16::MIN; 3],
+      mode: MeasurementMode::new(),
+    }
+  }
+
+  /// Add an acceleration measurement.
+  pub fn update(&mut self, acceleration: &Acceleration) {
+    self.mode = acceleration.mode;
+    self.count = self.count.saturating_add(1);
+
+    for (i, raw) in acceleration.raw_axes().into_iter().enumerate() {
+      let value = raw as i16;
+      self.sum[i] += value as i64;
+      self.sum_of_squares[i] += (value as i32 * value as i32) as u64;
+      self.min[i] = self.min[i].min(value);
+      self.max[i] = self.max[i].max(value);
+    }
+  }
+
+  /// Get the number of accumulated measurements.
+  pub fn count(&self) -> u32 {
+    self.count
+  }
+
+  /// Get a summary of the accumulated measurements, if any.
+  pub fn summary(&self) -> Option<VibrationSummary> {
+    if self.count == 0 {
+      return None
+    }
+
+    let count = self.count as i64;
+    let mean = self.sum.map(|sum| (sum / count) as i16);
+    let mean_square = self.sum_of_squares.map(|sum| sum / count as u64);
+
+    Some(VibrationSummary {
+      count: self.count,
+      mean,
+      rms: mean_square.map(|ms| isqrt(ms) as u16),
+      ac_rms: core::array::from_fn(|i| {
+        let mean = mean[i] as i32;
+        isqrt(mean_square[i].saturating_sub((mean * mean) as u64)) as u16
+      }),
+      peak: core::array::from_fn(|i| self.min[i].unsigned_abs().max(self.max[i].unsigned_abs())),
+      peak_to_peak: core::array::from_fn(|i| self.max[i].abs_diff(self.min[i])),
+      mode: self.mode,
+    })
+  }
+
+  /// Reset the accumulator.
+  pub fn reset(&mut self) {
+    *self = Self::new();
+  }
+}
+
+/// A summary of vibration metrics accumulated by [`VibrationStats`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct VibrationSummary {
+  count: u32,
+  mean: [i16; 3],
+  rms: [u16; 3],
+  ac_rms: [u16; 3],
+  peak: [u16; 3],
+  peak_to_peak: [u16; 3],
+  mode: MeasurementMode,
+}
+
+impl VibrationSummary {
+  #[cfg(feature = "float")]
+  fn raw_to_g(&self, raw: f32) -> Gs {
+    Gs(raw / self.mode.acceleration_sensitivity() as f32)
+  }
+
+  /// Get the number of measurements.
+  pub fn count(&self) -> u32 {
+    self.count
+  }
+
+  /// Get the raw mean value on the given axis.
+  pub fn mean_raw(&self, axis: Axis) -> i16 {
+    self.mean[axis as usize]
+  }
+
+  /// Get the raw RMS value on the given axis.
+  pub fn rms_raw(&self, axis: Axis) -> u16 {
+    self.rms[axis as usize]
+  }
+
+  /// Get the raw RMS value on the given axis with the mean removed.
+  pub fn ac_rms_raw(&self, axis: Axis) -> u16 {
+    self.ac_rms[axis as usize]
+  }
+
+  /// Get the raw peak value on the given axis.
+  pub fn peak_raw(&self, axis: Axis) -> u16 {
+    self.peak[axis as usize]
+  }
+
+  /// Get the raw peak-to-peak value on the given axis.
+  pub fn peak_to_peak_raw(&self, axis: Axis) -> u16 {
+    self.peak_to_peak[axis as usize]
+  }
+
+  /// Get the mean g-force on the given axis.
+  #[cfg(feature = "float")]
+  pub fn mean_g(&self, axis: Axis) -> Gs {
+    self.raw_to_g(self.mean_raw(axis) as f32)
+  }
+
+  /// Get the RMS g-force on the given axis.
+  #[cfg(feature = "float")]
+  pub fn rms_g(&self, axis: Axis) -> Gs {
+    self.raw_to_g(self.rms_raw(axis) as f32)
+  }
+
+  /// Get the RMS g-force on the given axis with the mean removed.
+  #[cfg(feature = "float")]
+  pub fn ac_rms_g(&self, axis: Axis) -> Gs {
+    self.raw_to_g(self.ac_rms_raw(axis) as f32)
+  }
+
+  /// Get the peak g-force on the given axis.
+  #[cfg(feature = "float")]
+  pub fn peak_g(&self, axis: Axis) -> Gs {
+    self.raw_to_g(self.peak_raw(axis) as f32)
+  }
+
+  /// Get the peak-to-peak g-force on the given axis.
+  #[cfg(feature = "float")]
+  pub fn peak_to_peak_g(&self, axis: Axis) -> Gs {
+    self.raw_to_g(self.peak_to_peak_raw(axis) as f32)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[cfg(feature = "float")]
+  #[test]
+  fn test_vibration_stats() {
+    let mut stats = VibrationStats::new();
+    assert_eq!(stats.summary(), None);
+
+    for x in [300i16, -300, 300, -300] {
+      stats.update(&Acceleration { x: x as u16, y: 0, z: 6000, mode: MeasurementMode::FullScale12 });
+    }
+
+    let summary = stats.summary().unwrap();
+    assert_eq!(summary.count(), 4);
+    assert_eq!(summary.mean_raw(Axis::X), 0);
+    assert_eq!(summary.rms_raw(Axis::X), 300);
+    assert_eq!(summary.peak_to_peak_raw(Axis::X), 600);
+    assert_eq!(summary.peak_g(Axis::X), Gs(0.05));
+    assert_eq!(summary.rms_g(Axis::Z), Gs(1.0));
+    assert_eq!(summary.ac_rms_raw(Axis::Z), 0);
+  }
+
+  #[test]
+  fn test_isqrt() {
+    for value in [0, 1, 2, 3, 4, 99, 100, 101, u32::MAX as u64, u64::MAX] {
+      let root = isqrt(value) as u128;
+      assert!(root * root <= value as u128 && (root + 1) * (root + 1) > value as u128);
+    }
+  }
+}